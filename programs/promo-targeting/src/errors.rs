@@ -58,4 +58,298 @@ pub enum PromoError {
     NoCouponsLeft,
     #[msg("Campaign has already expired")]
     CampaignExpired,
+    #[msg("Matching funds were already applied to this campaign")]
+    MatchAlreadyApplied,
+    #[msg("Insufficient matching pool balance")]
+    InsufficientMatchingPoolBalance,
+    #[msg("Matching pool account is required for this vault")]
+    MatchingPoolRequired,
+    #[msg("Segment has too many wallets")]
+    TooManySegmentWallets,
+    #[msg("Segment account is required for this campaign")]
+    SegmentRequired,
+    #[msg("Segment does not match the campaign")]
+    InvalidSegment,
+    #[msg("Campaign configuration is locked after the first mint")]
+    CampaignConfigLocked,
+    #[msg("Invalid redemption receipt")]
+    InvalidReceipt,
+    #[msg("Too many excluded wallets")]
+    TooManyExcludedWallets,
+    #[msg("Wallet is excluded from this campaign")]
+    WalletExcluded,
+    #[msg("Proof of a prerequisite campaign coupon is required")]
+    PrerequisiteProofRequired,
+    #[msg("Invalid prerequisite campaign proof")]
+    InvalidPrerequisiteProof,
+    #[msg("Location attestation is required for this campaign")]
+    LocationAttestationRequired,
+    #[msg("Invalid location attestation")]
+    InvalidLocationAttestation,
+    #[msg("Location attestation is too old")]
+    LocationAttestationExpired,
+    #[msg("Invalid redemption schedule")]
+    InvalidSchedule,
+    #[msg("Coupon cannot be redeemed at this time")]
+    OutsideRedemptionSchedule,
+    #[msg("Campaign is reserved for first-time customers")]
+    FirstPurchaseOnly,
+    #[msg("Invalid customer history account")]
+    InvalidCustomerHistory,
+    #[msg("Listing is reserved for a different buyer")]
+    BuyerNotReserved,
+    #[msg("Invalid swap amount")]
+    InvalidSwapAmount,
+    #[msg("Invalid batch accounts")]
+    InvalidBatch,
+    #[msg("Total price exceeds the buyer ceiling")]
+    BatchPriceCeilingExceeded,
+    #[msg("Withholding rule is required for this campaign")]
+    WithholdingRuleRequired,
+    #[msg("Invalid withholding rule or compliance account")]
+    InvalidWithholdingRule,
+    #[msg("Emergency timelock has not elapsed yet")]
+    TimelockNotElapsed,
+    #[msg("Invalid emergency withdrawal amount")]
+    InvalidEmergencyAmount,
+    #[msg("Signer is not the pending admin")]
+    NotPendingAdmin,
+    #[msg("New admin is not a governance authority of the given governance")]
+    InvalidGovernanceAuthority,
+    #[msg("Config field is finalized and can no longer be changed")]
+    ConfigFieldFinalized,
+    #[msg("Invalid finalize field mask")]
+    InvalidFinalizeFields,
+    #[msg("Coupon is on hold for a pending checkout")]
+    CouponOnHold,
+    #[msg("Invalid coupon hold expiry")]
+    InvalidHoldExpiry,
+    #[msg("User reached the cumulative discount cap for this campaign")]
+    UserDiscountCapReached,
+    #[msg("Daily budget exhausted, redemptions resume tomorrow")]
+    DailyBudgetExhausted,
+    #[msg("Amount exceeds the vault balance not committed to outstanding coupons")]
+    NoVaultExcess,
+    #[msg("Excess cannot be withdrawn from a vault topped up by the matching pool")]
+    MatchedVaultExcessLocked,
+    #[msg("Payout account does not match the campaign payout wallet")]
+    InvalidPayoutWallet,
+    #[msg("Signer is not the pending campaign authority")]
+    NotPendingMerchant,
+    #[msg("Signer is neither the campaign merchant nor a registered device key")]
+    DeviceKeyRequired,
+    #[msg("Device key has been revoked")]
+    DeviceKeyRevoked,
+    #[msg("Device key reached its daily minting quota")]
+    DeviceQuotaExceeded,
+    #[msg("Invalid device daily quota")]
+    InvalidDeviceQuota,
+    #[msg("Redemption requires the campaign verifier signature")]
+    VerifierSignatureRequired,
+    #[msg("Invalid achievement or achievement progress account")]
+    InvalidAchievement,
+    #[msg("Recipient has not unlocked the required achievement")]
+    AchievementRequired,
+    #[msg("Invalid lottery parameters")]
+    InvalidLottery,
+    #[msg("Signer is not the lottery randomness oracle")]
+    NotRandomnessOracle,
+    #[msg("All lottery winners have been drawn")]
+    LotteryComplete,
+    #[msg("Ticket does not match the drawn coupon")]
+    InvalidLotteryWinner,
+    #[msg("Lottery still has winners to draw")]
+    LotteryActive,
+    #[msg("Invalid redemption condition")]
+    InvalidRedemptionCondition,
+    #[msg("Oracle feed for the redemption condition is required")]
+    OracleFeedRequired,
+    #[msg("Redemption condition is not met yet")]
+    RedemptionConditionNotMet,
+    #[msg("Reward token accounts are required for this campaign")]
+    RewardAccountsRequired,
+    #[msg("Invalid reward token account")]
+    InvalidRewardAccount,
+    #[msg("Gas rebate exceeds the maximum allowed")]
+    InvalidGasRebate,
+    #[msg("Gifted coupon cannot be redeemed yet")]
+    GiftNotUnlocked,
+    #[msg("Invalid rounding mode")]
+    InvalidRoundingMode,
+    #[msg("Export sequence must increase and cannot exceed redemptions")]
+    InvalidExportSequence,
+    #[msg("Signer is not the checkpoint exporter")]
+    NotExporter,
+    #[msg("A registered QR nonce of this campaign is required")]
+    QrNonceRequired,
+    #[msg("QR nonce was already used")]
+    QrNonceConsumed,
+    #[msg("Invalid vault account")]
+    InvalidVaultAccount,
+    #[msg("Platform treasury does not match the one configured in GlobalConfig")]
+    InvalidPlatformTreasury,
+    #[msg("A collection NFT token account and its metadata are required for this campaign")]
+    CollectionNftRequired,
+    #[msg("Invalid collection NFT metadata account")]
+    InvalidCollectionMetadata,
+    #[msg("Recipient does not hold an NFT of the required collection")]
+    NotCollectionMember,
+    #[msg("Only collection-gated campaigns can be claimed directly")]
+    CollectionClaimOnly,
+    #[msg("Token gate needs both a mint and a non-zero minimum amount")]
+    InvalidTokenGate,
+    #[msg("A token account of the campaign gate mint is required")]
+    GateTokenAccountRequired,
+    #[msg("Recipient does not hold enough of the campaign gate token")]
+    InsufficientGateBalance,
+    #[msg("Wallet coupon counter account is required for this campaign")]
+    WalletCouponCountRequired,
+    #[msg("Wallet already received the maximum number of coupons for this campaign")]
+    WalletCouponLimitReached,
+    #[msg("A region attestation signed by the campaign attestor is required")]
+    RegionAttestationRequired,
+    #[msg("Coupons of this campaign are soulbound and cannot change hands")]
+    CouponNotTransferable,
+    #[msg("Loyalty tier cannot be combined with a first-purchase-only campaign")]
+    InvalidLoyaltyTier,
+    #[msg("Recipient has not redeemed enough coupons at this merchant")]
+    LoyaltyTierNotReached,
+    #[msg("Missing or invalid merchant voucher signature")]
+    InvalidVoucher,
+    #[msg("Wallet is blocked from this campaign")]
+    WalletBlocked,
+    #[msg("Blocklist entry does not belong to this campaign")]
+    InvalidBlockedWallet,
+    #[msg("Invalid compressed NFT ownership proof")]
+    InvalidCnftProof,
+    #[msg("Only compressed-NFT-gated campaigns can be claimed with a cNFT proof")]
+    CnftClaimOnly,
+    #[msg("Recipient must own a domain under the campaign parent domain")]
+    SnsDomainRequired,
+    #[msg("Invalid eligibility program")]
+    InvalidEligibilityProgram,
+    #[msg("The campaign eligibility program account is required")]
+    EligibilityProgramRequired,
+    #[msg("A valid KYC / age attestation is required for this campaign")]
+    KycAttestationRequired,
+    #[msg("Claim deadline must be within the campaign lifetime")]
+    InvalidClaimDeadline,
+    #[msg("The claim window for this recipient has closed")]
+    ClaimDeadlinePassed,
+    #[msg("Claim ticket does not match the provided accounts")]
+    InvalidClaimTicket,
+    #[msg("Recipient is not whitelisted for this campaign")]
+    NotWhitelisted,
+    #[msg("Whitelist entry does not belong to this campaign")]
+    InvalidWhitelistEntry,
+    #[msg("Category claim counter account is required")]
+    CategoryClaimCountRequired,
+    #[msg("Wallet reached the per-epoch coupon cap for this category")]
+    CategoryClaimCapReached,
+    #[msg("Too many claims in this slot, retry shortly")]
+    ClaimRateLimited,
+    #[msg("An active stake account or an old enough registration of the recipient is required for this campaign")]
+    StakeAccountRequired,
+    #[msg("Recipient stake is too small or too recent for this campaign")]
+    InsufficientStake,
+    #[msg("Targeting template does not belong to this merchant")]
+    InvalidTargetingTemplate,
+    #[msg("Campaign has been cancelled")]
+    CampaignCancelled,
+    #[msg("New expiration must be later than the current one")]
+    InvalidExpirationExtension,
+    #[msg("Campaign is still a draft")]
+    CampaignDraft,
+    #[msg("Campaign is not a draft")]
+    CampaignNotDraft,
+    #[msg("Campaign retention period has not elapsed yet")]
+    RetentionPeriodNotElapsed,
+    #[msg("Campaign vault must be closed first")]
+    VaultStillOpen,
+    #[msg("Invalid discount phases (too many, unordered or invalid bps)")]
+    InvalidDiscountPhases,
+    #[msg("Coupon was issued in a previous campaign period")]
+    CouponFromPreviousPeriod,
+    #[msg("Campaign can only be renewed after its period ended, and not once cancelled")]
+    CampaignNotRenewable,
+    #[msg("Manager already registered, not registered, or too many managers")]
+    InvalidManager,
+    #[msg("Metadata URI is too long")]
+    UriTooLong,
+    #[msg("Daily redemption limit of the campaign reached")]
+    DailyRedemptionLimitReached,
+    #[msg("Category code is not registered or is deprecated")]
+    UnknownCategory,
+    #[msg("Category registry is full")]
+    CategoryRegistryFull,
+    #[msg("Category code is already registered")]
+    CategoryAlreadyRegistered,
+    #[msg("Invalid campaign account")]
+    InvalidCampaignAccount,
+    #[msg("Too many product codes")]
+    TooManyProductCodes,
+    #[msg("Budget taper must be between 0 and 10000 bps")]
+    InvalidBudgetTaper,
+    #[msg("Campaign is frozen by the platform")]
+    CampaignFrozen,
+    #[msg("Snapshot epoch must be the current epoch")]
+    InvalidSnapshotEpoch,
+    #[msg("Fixed discount amount must be greater than zero")]
+    InvalidDiscountKind,
+    #[msg("Coupon cannot be combined with other coupons")]
+    CouponNotStackable,
+    #[msg("Combined discount cap of the stacked coupons reached")]
+    StackedDiscountCapReached,
+    #[msg("Invalid stacking policy")]
+    InvalidStackingPolicy,
+    #[msg("Coupon memo or URI too long")]
+    InvalidCouponMetadata,
+    #[msg("Coupon value exceeds the max discount")]
+    InvalidCouponValue,
+    #[msg("Value-bearing coupons are redeemed with redeem_partial")]
+    PartialRedemptionRequired,
+    #[msg("Campaign coupons carry no redeemable value")]
+    NotValueCoupon,
+    #[msg("Invalid partial redemption amount")]
+    InvalidRedemptionAmount,
+    #[msg("Campaign redemption requirements are not supported by redeem_partial")]
+    PartialRedemptionNotSupported,
+    #[msg("Reservation expiry must be a future slot within the max reservation length")]
+    InvalidReservationExpiry,
+    #[msg("Coupon is reserved by another checkout")]
+    CouponReservedElsewhere,
+    #[msg("Signer does not hold the coupon reservation")]
+    NotReservingTerminal,
+    #[msg("Coupon reached the campaign transfer limit")]
+    TransferLimitReached,
+    #[msg("Coupon changed hands too recently")]
+    TransferCooldownActive,
+    #[msg("Signer is not the approved recipient of the coupon")]
+    NotPendingOwner,
+    #[msg("Coupon is frozen by the merchant")]
+    CouponFrozen,
+    #[msg("Campaign still has coupons outstanding; expire them first")]
+    CouponsOutstanding,
+    #[msg("Self-service claims must use the campaign's next coupon index")]
+    CouponIndexNotNext,
+    #[msg("Invalid coupon account")]
+    InvalidCouponAccount,
+    #[msg("Revealed secret does not match the draw commitment")]
+    InvalidDrawReveal,
+    #[msg("Draw commitment slot hash is not available")]
+    DrawSlotHashUnavailable,
+    #[msg("Stake account already qualified a wallet for this campaign")]
+    StakeAlreadyClaimed,
+    #[msg("Recipient wallet registration is too recent for this campaign")]
+    WalletRegistrationTooRecent,
+    #[msg("Emergency withdrawals can only go to the payout wallet or the matching pool")]
+    InvalidEmergencyDestination,
+    #[msg("Governance program is not the SPL Governance program")]
+    InvalidGovernanceProgram,
+    #[msg("Gift unlock must not be later than the campaign expiration")]
+    InvalidGiftUnlock,
+    #[msg("Campaign id belongs to a closed campaign and can't be reused")]
+    CampaignIdRetired,
+    #[msg("Draw commitment can still be revealed")]
+    DrawNotExpired,
 }
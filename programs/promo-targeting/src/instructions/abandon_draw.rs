@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Drop a draw commitment that wasn't revealed in time.
+///
+/// Once `DrawCommitment::REVEAL_WINDOW_SLOTS` passed since the commitment,
+/// its slot hash is gone from `SlotHashes` and `draw_winner` can no longer
+/// succeed (secret withheld, or the winning ticket never presented). The
+/// merchant or the randomness oracle closes it, rent back to the oracle,
+/// and the oracle can commit the same draw again.
+pub fn abandon_draw(ctx: Context<AbandonDraw>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    require!(
+        authority == ctx.accounts.campaign.merchant
+            || authority == ctx.accounts.lottery.randomness_oracle,
+        PromoError::NotMerchant
+    );
+
+    let draw_commitment = &ctx.accounts.draw_commitment;
+    require!(
+        Clock::get()?.slot
+            > draw_commitment
+                .slot
+                .saturating_add(DrawCommitment::REVEAL_WINDOW_SLOTS),
+        PromoError::DrawNotExpired
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AbandonDraw<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [
+            b"lottery",
+            campaign.key().as_ref(),
+        ],
+        bump = lottery.bump,
+        has_one = campaign,
+        has_one = randomness_oracle @ PromoError::NotRandomnessOracle
+    )]
+    pub lottery: Account<'info, Lottery>,
+
+    /// Expired commitment, closed back to the oracle.
+    #[account(
+        mut,
+        seeds = [
+            b"draw_commitment",
+            lottery.key().as_ref(),
+        ],
+        bump,
+        has_one = lottery,
+        close = randomness_oracle
+    )]
+    pub draw_commitment: Account<'info, DrawCommitment>,
+
+    /// CHECK: Randomness oracle of the lottery, receiving the commitment rent.
+    #[account(mut)]
+    pub randomness_oracle: UncheckedAccount<'info>,
+
+    /// Campaign merchant or randomness oracle.
+    pub authority: Signer<'info>,
+}
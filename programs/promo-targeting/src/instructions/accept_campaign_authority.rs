@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Pending authority accepts the handoff and becomes the campaign merchant.
+///
+/// - `Campaign.merchant` and `Vault.merchant` move to the new authority.
+/// - The previous merchant's `payout_wallet` and `managers` are cleared:
+///   refunds go to the new merchant until it sets its own payout wallet.
+/// - The campaign PDA address does not change: it stays derived from
+///   `Campaign.creator`.
+pub fn accept_campaign_authority(ctx: Context<AcceptCampaignAuthority>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let vault = &mut ctx.accounts.vault;
+    let new_merchant = ctx.accounts.new_merchant.key();
+
+    require!(
+        campaign.pending_merchant != Pubkey::default(),
+        PromoError::NotPendingMerchant
+    );
+    require_keys_eq!(
+        campaign.pending_merchant,
+        new_merchant,
+        PromoError::NotPendingMerchant
+    );
+
+    let previous_merchant = campaign.merchant;
+    campaign.merchant = new_merchant;
+    campaign.pending_merchant = Pubkey::default();
+    campaign.payout_wallet = Pubkey::default();
+    campaign.managers.clear();
+    vault.merchant = new_merchant;
+
+    emit!(CampaignAuthorityTransferred {
+        campaign: campaign.key(),
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        previous_merchant,
+        new_merchant,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when a campaign changes authority.
+#[event]
+pub struct CampaignAuthorityTransferred {
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub previous_merchant: Pubkey,
+    pub new_merchant: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct AcceptCampaignAuthority<'info> {
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub new_merchant: Signer<'info>,
+}
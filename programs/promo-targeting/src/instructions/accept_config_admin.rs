@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Pending admin accepts the handoff and becomes `GlobalConfig.admin`.
+///
+/// For a DAO handoff this instruction is executed by a governance proposal,
+/// which signs for the governance PDA through CPI.
+pub fn accept_config_admin(ctx: Context<AcceptConfigAdmin>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(
+        config.pending_admin != Pubkey::default(),
+        PromoError::NotPendingAdmin
+    );
+    require_keys_eq!(
+        config.pending_admin,
+        ctx.accounts.new_admin.key(),
+        PromoError::NotPendingAdmin
+    );
+
+    config.admin = config.pending_admin;
+    config.pending_admin = Pubkey::default();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptConfigAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub new_admin: Signer<'info>,
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+use super::buy_listed_coupon::ResaleWithheld;
+
+/// Counterparty accepts a swap offer: payment and coupon move atomically.
+///
+/// - `offer.amount` lamports move from the counterparty to the seller, within
+///   the campaign's current resale cap.
+/// - Swaps of coupons from a withholding jurisdiction are withheld like
+///   marketplace sales.
+/// - Coupon ownership moves to the counterparty, the offer is closed and its
+///   rent returns to the seller.
+pub fn accept_coupon_swap(ctx: Context<AcceptCouponSwap>) -> Result<()> {
+    let offer = &ctx.accounts.offer;
+    let coupon = &mut ctx.accounts.coupon;
+    let seller = &ctx.accounts.seller;
+    let counterparty = &ctx.accounts.counterparty;
+
+    // The seller must still hold an unused, unlisted coupon of the current
+    // period that is not on hold
+    require_keys_eq!(coupon.owner, seller.key(), PromoError::NotCouponOwner);
+    require!(!coupon.used, PromoError::CouponAlreadyUsed);
+    require!(!coupon.listed, PromoError::CouponListed);
+    require!(
+        coupon.period == ctx.accounts.campaign.period,
+        PromoError::CouponFromPreviousPeriod
+    );
+    require!(
+        !coupon.is_held(&Clock::get()?),
+        PromoError::CouponOnHold
+    );
+    require!(
+        ctx.accounts.campaign.transferable,
+        PromoError::CouponNotTransferable
+    );
+    require!(!ctx.accounts.campaign.frozen, PromoError::CampaignFrozen);
+
+    // Offers are capped at proposal, but the merchant may have lowered the
+    // resale cap since
+    require_resale_price_allowed(&ctx.accounts.campaign, offer.amount)?;
+
+    // Resale withholding (per-jurisdiction rule set by the admin)
+    let compliance = &ctx.accounts.compliance_account;
+    let withheld = withholding_amount(
+        &ctx.accounts.campaign,
+        ctx.accounts.withholding_rule.as_deref(),
+        compliance.as_ref().map(|account| account.key),
+        offer.amount,
+    )?;
+    if let Some(compliance) = compliance.as_ref().filter(|_| withheld > 0) {
+        let cpi_accounts = system_program::Transfer {
+            from: counterparty.to_account_info(),
+            to: compliance.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, withheld)?;
+
+        emit!(ResaleWithheld {
+            campaign: ctx.accounts.campaign.key(),
+            partner: ctx.accounts.campaign.partner,
+            partner_share_bps: ctx.accounts.campaign.partner_share_bps,
+            coupon: coupon.key(),
+            seller: seller.key(),
+            buyer: counterparty.key(),
+            jurisdiction_code: ctx.accounts.campaign.jurisdiction_code,
+            sale_price: offer.amount,
+            withheld,
+        });
+    }
+
+    // Lamport leg
+    let cpi_accounts = system_program::Transfer {
+        from: counterparty.to_account_info(),
+        to: seller.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, offer.amount - withheld)?;
+
+    // Coupon leg
+    record_coupon_transfer(&ctx.accounts.campaign, coupon)?;
+    coupon.owner = counterparty.key();
+    coupon.listed = false;
+    coupon.sale_price_lamports = 0;
+    coupon.reserved_buyer = Pubkey::default();
+    coupon.held_until = 0;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptCouponSwap<'info> {
+    /// Offer being accepted. Closed on success, rent goes back to the seller.
+    #[account(
+        mut,
+        seeds = [
+            b"swap",
+            coupon.key().as_ref(),
+        ],
+        bump = offer.bump,
+        has_one = coupon,
+        has_one = seller,
+        has_one = counterparty,
+        close = seller
+    )]
+    pub offer: Account<'info, SwapOffer>,
+
+    #[account(
+        mut,
+        has_one = campaign @ PromoError::InvalidCouponCampaign
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    /// Campaign of the coupon (current period, resale withholding jurisdiction).
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Seller recorded in the offer (validated by `has_one`); receives payment and rent.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// Counterparty paying and receiving the coupon.
+    #[account(mut)]
+    pub counterparty: Signer<'info>,
+
+    /// Withholding rule of `campaign.jurisdiction_code` (required only if set).
+    pub withholding_rule: Option<Account<'info, WithholdingRule>>,
+
+    /// CHECK: Compliance account receiving withheld lamports; must match
+    /// `withholding_rule.compliance_account` (checked in the handler).
+    #[account(mut)]
+    pub compliance_account: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
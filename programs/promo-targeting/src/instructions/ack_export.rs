@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Exporter acknowledges that redemptions up to `sequence` were exported.
+///
+/// `sequence` refers to `RedemptionReceipt.sequence` / `CouponRedeemed.sequence`;
+/// it must move forward and cannot exceed `campaign.used_coupons`. After an
+/// outage the pipeline resumes from `last_sequence + 1`.
+pub fn ack_export(ctx: Context<AckExport>, sequence: u32) -> Result<()> {
+    let checkpoint = &mut ctx.accounts.checkpoint;
+
+    require!(
+        sequence > checkpoint.last_sequence && sequence <= ctx.accounts.campaign.used_coupons,
+        PromoError::InvalidExportSequence
+    );
+
+    checkpoint.last_sequence = sequence;
+    checkpoint.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AckExport<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"export",
+            campaign.key().as_ref(),
+        ],
+        bump = checkpoint.bump,
+        has_one = campaign,
+        has_one = exporter @ PromoError::NotExporter
+    )]
+    pub checkpoint: Account<'info, ExportCheckpoint>,
+
+    pub exporter: Signer<'info>,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant activates a campaign created as a draft.
+///
+/// Drafts let dashboards pre-stage a promotion (targeting, gates, budget)
+/// and have it reviewed; coupons can only be minted or claimed once the
+/// campaign is activated.
+pub fn activate_campaign(ctx: Context<ActivateCampaign>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.draft, PromoError::CampaignNotDraft);
+    require!(campaign.cancelled_at == 0, PromoError::CampaignCancelled);
+
+    campaign.draft = false;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ActivateCampaign<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
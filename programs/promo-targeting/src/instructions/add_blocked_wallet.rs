@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant blocks a wallet from receiving coupons of the campaign.
+///
+/// Unlike `excluded_wallets` (a short list fixed before the first mint), the
+/// blocklist is unbounded and can be updated at any time. Blocking does not
+/// affect coupons the wallet already holds.
+pub fn add_blocked_wallet(ctx: Context<AddBlockedWallet>, wallet: Pubkey) -> Result<()> {
+    let blocked = &mut ctx.accounts.blocked_wallet;
+
+    blocked.campaign = ctx.accounts.campaign.key();
+    blocked.wallet = wallet;
+    blocked.blocked_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct AddBlockedWallet<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Blocklist entry. One PDA per (campaign, wallet).
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + BlockedWallet::SIZE,
+        seeds = [
+            b"blocked",
+            campaign.key().as_ref(),
+            wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub blocked_wallet: Account<'info, BlockedWallet>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant delegates campaign operations to a manager key (e.g. an agency).
+///
+/// Managers can mint coupons (`mint_coupon`) and expire them
+/// (`expire_coupon`), but can't withdraw or close funds nor change the
+/// campaign configuration. At most `Campaign::MAX_MANAGERS` per campaign.
+pub fn add_manager(ctx: Context<AddManager>, manager: Pubkey) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(
+        manager != Pubkey::default()
+            && manager != campaign.merchant
+            && !campaign.managers.contains(&manager)
+            && campaign.managers.len() < Campaign::MAX_MANAGERS,
+        PromoError::InvalidManager
+    );
+
+    campaign.managers.push(manager);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddManager<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
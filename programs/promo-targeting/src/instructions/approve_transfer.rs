@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Owner approves `new_owner` to take the coupon with `claim_transfer`
+/// (first step of a two-step P2P transfer).
+///
+/// - The coupon stays with the owner until the recipient claims it, so a
+///   mistyped address never receives it.
+/// - Approving another recipient replaces the pending one;
+///   `Pubkey::default()` cancels the approval.
+/// - Same preconditions as `transfer_coupon`; they are checked again on claim.
+pub fn approve_transfer(ctx: Context<ApproveTransfer>, new_owner: Pubkey) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let coupon = &mut ctx.accounts.coupon;
+
+    if new_owner != Pubkey::default() {
+        let clock = Clock::get()?;
+        require!(!coupon.used, PromoError::CouponAlreadyUsed);
+        require!(!coupon.listed, PromoError::CouponListed);
+        require!(!coupon.is_held(&clock), PromoError::CouponOnHold);
+        require!(campaign.transferable, PromoError::CouponNotTransferable);
+        require!(new_owner != coupon.owner, PromoError::InvalidBuyer);
+        require_transfer_allowed(campaign, coupon, clock.unix_timestamp)?;
+    }
+
+    coupon.pending_owner = new_owner;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApproveTransfer<'info> {
+    /// Campaign of the coupon (transferability and transfer limits).
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        has_one = campaign @ PromoError::InvalidCouponCampaign,
+        constraint = coupon.owner == owner.key() @ PromoError::NotCouponOwner
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant writes the archive summary of an expired campaign.
+///
+/// Must run before `close_campaign_vault`, since the budget breakdown is read
+/// from the vault. The archive is written once and never modified; the
+/// receipt set can be checked against `receipts_hash` by replaying
+/// `sha256(hash || receipt)` over the receipts in `sequence` order.
+pub fn archive_campaign(ctx: Context<ArchiveCampaign>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let vault = &ctx.accounts.vault;
+    let archive = &mut ctx.accounts.archive;
+
+    let clock = Clock::get()?;
+    require!(
+        campaign.is_expired(clock.unix_timestamp),
+        PromoError::CampaignNotExpired
+    );
+
+    archive.campaign = campaign.key();
+    archive.merchant = campaign.merchant;
+    archive.campaign_id = campaign.campaign_id;
+    archive.total_coupons = campaign.total_coupons;
+    archive.minted_coupons = campaign.minted_coupons;
+    archive.used_coupons = campaign.used_coupons;
+    archive.total_purchase_amount = campaign.total_purchase_amount;
+    archive.total_discount_lamports = campaign.total_discount_lamports;
+    archive.total_deposit = vault.total_deposit;
+    archive.total_mint_spent = vault.total_mint_spent;
+    archive.total_service_spent = vault.total_service_spent;
+    archive.matched_lamports = vault.matched_lamports;
+    archive.receipts_hash = campaign.receipts_hash;
+    archive.archived_at = clock.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ArchiveCampaign<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Archive PDA. One PDA per campaign, written once.
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + CampaignArchive::SIZE,
+        seeds = [
+            b"archive",
+            campaign.key().as_ref(),
+        ],
+        bump
+    )]
+    pub archive: Account<'info, CampaignArchive>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
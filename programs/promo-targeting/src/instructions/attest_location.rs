@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::states::*;
+
+/// Location oracle attests that `user` is currently in `region_code`.
+///
+/// The attestation PDA is per (oracle, user) and is overwritten by each
+/// new attestation; campaigns decide how old an attestation may be.
+pub fn attest_location(ctx: Context<AttestLocation>, region_code: u16) -> Result<()> {
+    let attestation = &mut ctx.accounts.attestation;
+
+    attestation.oracle = ctx.accounts.oracle.key();
+    attestation.user = ctx.accounts.user.key();
+    attestation.region_code = region_code;
+    attestation.attested_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AttestLocation<'info> {
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        space = 8 + LocationAttestation::SIZE,
+        seeds = [
+            b"location",
+            oracle.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub attestation: Account<'info, LocationAttestation>,
+
+    /// Oracle signing (and paying for) the attestation.
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+
+    /// CHECK: Wallet being attested. We only read its public key.
+    pub user: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
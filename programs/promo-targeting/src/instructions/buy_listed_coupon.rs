@@ -3,12 +3,21 @@ use anchor_lang::system_program;
 
 use crate::errors::*;
 use crate::states::*;
+use crate::utils::*;
 
 /// Buy a listed coupon.
     ///
     /// - Buyer pays SOL (lamports) directly to the seller.
     /// - Ownership of the coupon is updated.
     /// - Listing is cleared.
+    /// - Private listings can only be bought by `coupon.reserved_buyer`.
+    /// - In withholding jurisdictions, the withheld share of the price goes to
+    ///   the compliance account.
+    /// - The marketplace fee (`GlobalConfig.marketplace_fee_bps`, unless paused
+    ///   by `set_fee_switches`) goes to the platform treasury; the seller
+    ///   receives the rest.
+    /// - The sale is appended to the campaign `TradeHistory` (created by the
+    ///   first buyer).
     ///
     /// Safety:
     /// - Enforces that `coupon.sale_price_lamports` is still within
@@ -20,57 +29,108 @@ use crate::states::*;
         let buyer = &ctx.accounts.buyer;
         let system_program = &ctx.accounts.system_program;
 
-        // Coupon must belong to this campaign (safety)
-        require_keys_eq!(
-            coupon.campaign,
-            campaign.key(),
-            PromoError::InvalidCouponCampaign
-        );
-
-        // Must be listed
-        require!(coupon.listed, PromoError::CouponNotListed);
-
-        // Seller must be current owner
-        require_keys_eq!(coupon.owner, seller.key(), PromoError::NotCouponOwner);
-
-        // Cannot buy your own coupon
-        require!(buyer.key() != seller.key(), PromoError::InvalidBuyer);
-
-        // Validate sale price is within allowed bounds
-        let sale_price = coupon.sale_price_lamports;
-        require!(sale_price > 0, PromoError::InvalidResalePrice);
-
-        require!(
-            sale_price <= campaign.max_discount_lamports,
-            PromoError::InvalidResalePrice
-        );
-
-        let max_allowed = campaign
-            .max_discount_lamports
-            .checked_mul(campaign.resale_bps as u64)
-            .ok_or(PromoError::Overflow)?
-            / 10_000;
-        require!(sale_price <= max_allowed, PromoError::InvalidResalePrice);
-
-        // Transfer lamports from buyer to seller using the System Program
+        // Validate listing, parties and sale price bounds
+        let sale_price = validate_listing_purchase(
+            &campaign.key(),
+            campaign,
+            coupon,
+            &seller.key(),
+            &buyer.key(),
+        )?;
+
+        // Resale withholding (per-jurisdiction rule set by the admin)
+        let compliance = &ctx.accounts.compliance_account;
+        let withheld = withholding_amount(
+            campaign,
+            ctx.accounts.withholding_rule.as_deref(),
+            compliance.as_ref().map(|account| account.key),
+            sale_price,
+        )?;
+        if let Some(compliance) = compliance.as_ref().filter(|_| withheld > 0) {
+            let cpi_accounts = system_program::Transfer {
+                from: buyer.to_account_info(),
+                to: compliance.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+            system_program::transfer(cpi_ctx, withheld)?;
+
+            emit!(ResaleWithheld {
+                campaign: campaign.key(),
+                partner: campaign.partner,
+                partner_share_bps: campaign.partner_share_bps,
+                coupon: coupon.key(),
+                seller: seller.key(),
+                buyer: buyer.key(),
+                jurisdiction_code: campaign.jurisdiction_code,
+                sale_price,
+                withheld,
+            });
+        }
+
+        // Marketplace fee, out of what is left for the seller
+        let fee = marketplace_fee(&ctx.accounts.config, sale_price)?.min(sale_price - withheld);
+        if fee > 0 {
+            let cpi_accounts = system_program::Transfer {
+                from: buyer.to_account_info(),
+                to: ctx.accounts.platform_treasury.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+            system_program::transfer(cpi_ctx, fee)?;
+        }
+
+        // Transfer the remaining lamports from buyer to seller using the System Program
         let cpi_accounts = system_program::Transfer {
             from: buyer.to_account_info(),
             to: seller.to_account_info(),
         };
         let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
-        system_program::transfer(cpi_ctx, sale_price)?;
+        system_program::transfer(cpi_ctx, sale_price - withheld - fee)?;
 
         // Update coupon ownership and clear listing
+        record_coupon_transfer(campaign, coupon)?;
         coupon.owner = buyer.key();
         coupon.listed = false;
         coupon.sale_price_lamports = 0;
+        coupon.reserved_buyer = Pubkey::default();
+        coupon.held_until = 0;
+
+        // Record the sale in the campaign trade history
+        let history = &mut ctx.accounts.trade_history;
+        history.campaign = campaign.key();
+        history.record(
+            sale_price,
+            Clock::get()?.unix_timestamp,
+            &buyer.key(),
+            &seller.key(),
+        );
 
         Ok(())
     }
 
+/// Event emitted whenever part of a secondary sale is withheld for compliance.
+#[event]
+pub struct ResaleWithheld {
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub coupon: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub jurisdiction_code: u16,
+    pub sale_price: u64,
+    pub withheld: u64,
+}
+
     /// Buy a previously listed coupon using SOL.
     #[derive(Accounts)]
     pub struct BuyListedCoupon<'info> {
+    /// Global config – marketplace fee.
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Box<Account<'info, GlobalConfig>>,
+
     #[account(mut)]
     pub campaign: Account<'info, Campaign>,
 
@@ -94,5 +154,39 @@ use crate::states::*;
     pub buyer: Signer<'info>,
 
 
+    /// Recent-sales ring buffer of the campaign.
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + TradeHistory::SIZE,
+        seeds = [
+            b"trades",
+            campaign.key().as_ref(),
+        ],
+        bump
+    )]
+    pub trade_history: Box<Account<'info, TradeHistory>>,
+
+
+    /// Withholding rule of `campaign.jurisdiction_code` (required only if set).
+    pub withholding_rule: Option<Account<'info, WithholdingRule>>,
+
+
+    /// CHECK: Compliance account receiving withheld lamports; must match
+    /// `withholding_rule.compliance_account` (checked in the handler).
+    #[account(mut)]
+    pub compliance_account: Option<UncheckedAccount<'info>>,
+
+
+    /// CHECK: Platform treasury receiving the marketplace fee.
+    #[account(
+        mut,
+        constraint = config.platform_treasury != Pubkey::default()
+            && platform_treasury.key() == config.platform_treasury
+            @ PromoError::InvalidPlatformTreasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+
     pub system_program: Program<'info, System>,
     }
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+use super::buy_listed_coupon::ResaleWithheld;
+
+/// Buy several listed coupons (possibly from different sellers) atomically.
+///
+/// `remaining_accounts` is a flat list of `(campaign, coupon, seller)` triples:
+/// - `campaign`: the coupon campaign (read-only)
+/// - `coupon`: the listed coupon (writable)
+/// - `seller`: the current coupon owner receiving lamports (writable)
+///
+/// Every listing is validated and charged (withholding, marketplace fee)
+/// exactly like `buy_listed_coupon`, and the whole transaction fails if the
+/// sum of prices exceeds `max_total_price`.
+/// Resale withholding uses the single `withholding_rule` passed to the
+/// instruction, so a batch can only span one withholding jurisdiction.
+/// Likewise, sales are recorded in the optional `trade_history` only for
+/// listings of its campaign.
+pub fn buy_listed_coupons_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyListedCouponsBatch<'info>>,
+    max_total_price: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let buyer = &ctx.accounts.buyer;
+    let system_program = &ctx.accounts.system_program;
+    let withholding_rule = ctx.accounts.withholding_rule.as_deref();
+    let compliance = &ctx.accounts.compliance_account;
+    let remaining = ctx.remaining_accounts;
+    let mut trade_history = ctx.accounts.trade_history.as_mut();
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        !remaining.is_empty() && remaining.chunks_exact(3).remainder().is_empty(),
+        PromoError::InvalidBatch
+    );
+
+    let mut total_price: u64 = 0;
+
+    for accounts in remaining.chunks(3) {
+        let campaign = Account::<Campaign>::try_from(&accounts[0])?;
+        let mut coupon = Account::<Coupon>::try_from(&accounts[1])?;
+        let seller = &accounts[2];
+
+        let sale_price = validate_listing_purchase(
+            &campaign.key(),
+            &campaign,
+            &coupon,
+            seller.key,
+            &buyer.key(),
+        )?;
+
+        total_price = total_price
+            .checked_add(sale_price)
+            .ok_or(PromoError::Overflow)?;
+        require!(
+            total_price <= max_total_price,
+            PromoError::BatchPriceCeilingExceeded
+        );
+
+        // Resale withholding (per-jurisdiction rule set by the admin)
+        let withheld = withholding_amount(
+            &campaign,
+            withholding_rule,
+            compliance.as_ref().map(|account| account.key),
+            sale_price,
+        )?;
+        if let Some(compliance) = compliance.as_ref().filter(|_| withheld > 0) {
+            let cpi_accounts = system_program::Transfer {
+                from: buyer.to_account_info(),
+                to: compliance.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+            system_program::transfer(cpi_ctx, withheld)?;
+
+            emit!(ResaleWithheld {
+                campaign: campaign.key(),
+                partner: campaign.partner,
+                partner_share_bps: campaign.partner_share_bps,
+                coupon: coupon.key(),
+                seller: *seller.key,
+                buyer: buyer.key(),
+                jurisdiction_code: campaign.jurisdiction_code,
+                sale_price,
+                withheld,
+            });
+        }
+
+        // Marketplace fee, out of what is left for the seller
+        let fee = marketplace_fee(config, sale_price)?.min(sale_price - withheld);
+        if fee > 0 {
+            let cpi_accounts = system_program::Transfer {
+                from: buyer.to_account_info(),
+                to: ctx.accounts.platform_treasury.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+            system_program::transfer(cpi_ctx, fee)?;
+        }
+
+        // Transfer the remaining lamports from buyer to seller using the System Program
+        let cpi_accounts = system_program::Transfer {
+            from: buyer.to_account_info(),
+            to: seller.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, sale_price - withheld - fee)?;
+
+        // Update coupon ownership and clear listing
+        record_coupon_transfer(&campaign, &mut coupon)?;
+        coupon.owner = buyer.key();
+        coupon.listed = false;
+        coupon.sale_price_lamports = 0;
+        coupon.reserved_buyer = Pubkey::default();
+        coupon.held_until = 0;
+        coupon.exit(&crate::ID)?;
+
+        if let Some(history) = trade_history
+            .as_mut()
+            .filter(|history| history.campaign == campaign.key())
+        {
+            history.record(sale_price, now, &buyer.key(), seller.key);
+        }
+    }
+
+    Ok(())
+}
+
+/// Buy a batch of listed coupons; listings are passed via `remaining_accounts`.
+#[derive(Accounts)]
+pub struct BuyListedCouponsBatch<'info> {
+    /// Global config – marketplace fee.
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Buyer paying SOL and receiving every coupon of the batch.
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    /// Existing trade history of one of the batch campaigns (optional).
+    #[account(mut)]
+    pub trade_history: Option<Account<'info, TradeHistory>>,
+
+    /// Withholding rule applied to every listing of a withholding jurisdiction.
+    pub withholding_rule: Option<Account<'info, WithholdingRule>>,
+
+    /// CHECK: Compliance account receiving withheld lamports; must match
+    /// `withholding_rule.compliance_account` (checked in the handler).
+    #[account(mut)]
+    pub compliance_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Platform treasury receiving the marketplace fees.
+    #[account(
+        mut,
+        constraint = config.platform_treasury != Pubkey::default()
+            && platform_treasury.key() == config.platform_treasury
+            @ PromoError::InvalidPlatformTreasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
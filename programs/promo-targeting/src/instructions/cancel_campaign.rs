@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant cancels the campaign before its expiration.
+///
+/// The campaign counts as expired from now on: no more coupons are minted or
+/// claimed, outstanding coupons can't be redeemed, and the merchant can
+/// reclaim the remaining budget right away with `close_campaign_vault`
+/// (and clean up coupons with `expire_coupon`). The campaign account itself
+/// stays alive for analytics.
+pub fn cancel_campaign(ctx: Context<CancelCampaign>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.cancelled_at == 0, PromoError::CampaignCancelled);
+
+    let now = Clock::get()?.unix_timestamp;
+    campaign.cancelled_at = now;
+
+    emit!(CampaignCancelled {
+        campaign: campaign.key(),
+        merchant: campaign.merchant,
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        minted_coupons: campaign.minted_coupons,
+        used_coupons: campaign.used_coupons,
+        cancelled_at: now,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when a merchant cancels a campaign early.
+#[event]
+pub struct CampaignCancelled {
+    pub campaign: Pubkey,
+    pub merchant: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub minted_coupons: u32,
+    pub used_coupons: u32,
+    pub cancelled_at: i64,
+}
+
+#[derive(Accounts)]
+pub struct CancelCampaign<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Withdraw an open swap offer. The offer rent returns to the seller.
+///
+/// - The seller can always withdraw their offer.
+/// - Once the coupon changed hands (transfer, sale, another swap), its
+///   current owner can clear the stale offer to propose a new one; `coupon`
+///   is required then.
+pub fn cancel_coupon_swap(ctx: Context<CancelCouponSwap>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+
+    if authority != ctx.accounts.offer.seller {
+        let coupon = ctx
+            .accounts
+            .coupon
+            .as_ref()
+            .ok_or(PromoError::NotCouponOwner)?;
+        require_keys_eq!(coupon.owner, authority, PromoError::NotCouponOwner);
+    }
+
+    // The actual close is handled by `close = seller` in the accounts struct.
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CancelCouponSwap<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"swap",
+            offer.coupon.as_ref(),
+        ],
+        bump = offer.bump,
+        has_one = seller,
+        close = seller
+    )]
+    pub offer: Account<'info, SwapOffer>,
+
+    /// Offered coupon (required only when `authority` is not the seller).
+    #[account(address = offer.coupon @ PromoError::InvalidCouponAccount)]
+    pub coupon: Option<Account<'info, Coupon>>,
+
+    /// CHECK: Seller recorded in the offer (validated by `has_one`); receives the rent.
+    #[account(mut)]
+    pub seller: UncheckedAccount<'info>,
+
+    /// Seller, or current owner of the coupon.
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin withdraws a pending emergency proposal. Its rent returns to the admin.
+pub fn cancel_emergency_withdrawal(ctx: Context<CancelEmergencyWithdrawal>) -> Result<()> {
+    emit!(EmergencyWithdrawalCancelled {
+        vault: ctx.accounts.proposal.vault,
+    });
+
+    // The actual close is handled by `close = admin` in the accounts struct.
+    Ok(())
+}
+
+/// Event emitted when a pending emergency withdrawal is cancelled.
+#[event]
+pub struct EmergencyWithdrawalCancelled {
+    pub vault: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct CancelEmergencyWithdrawal<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        close = admin
+    )]
+    pub proposal: Account<'info, EmergencyProposal>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
@@ -1,7 +1,6 @@
 use anchor_lang::prelude::*;
 
 use crate::states::*;
-use crate::errors::*;
 
 #[derive(Accounts)]
 pub struct CheckTreasuryBalance<'info> {
@@ -0,0 +1,370 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Holder of a Bubblegum compressed NFT claims a coupon of a cNFT-gated
+/// campaign for themselves, proving ownership with a Merkle proof.
+///
+/// - Only campaigns with `required_cnft_collection` set can be claimed.
+/// - The asset leaf is rebuilt from `leaf` and the claimant, and verified by
+///   CPI to Account Compression (`verify_cnft_collection`).
+/// - `remaining_accounts`: the first `receipt_count` accounts are category
+///   receipts (see `mint_coupon`), the rest is the Merkle proof path.
+/// - Every other eligibility rule of `mint_coupon` applies to the claimant.
+/// - Each asset can claim once per campaign period (`NftClaim` PDA keyed by
+///   the asset id).
+#[allow(clippy::too_many_arguments)]
+pub fn claim_coupon_with_cnft<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimCouponWithCnft<'info>>,
+    campaign_id: u64,
+    coupon_index: u64,
+    segment_proof: Vec<[u8; 32]>,
+    receipt_count: u8,
+    root: [u8; 32],
+    nonce: u64,
+    delegate: Pubkey,
+    creator_hash: [u8; 32],
+    metadata_args: Vec<u8>,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let claimant = ctx.accounts.claimant.key();
+
+    require!(
+        campaign.campaign_id == campaign_id,
+        PromoError::InvalidCampaignId
+    );
+
+    require_next_coupon_index(campaign, coupon_index)?;
+
+    // Anti-bot throttling of self-service claims
+    throttle_claim(campaign)?;
+    require!(
+        campaign.required_cnft_collection != Pubkey::default(),
+        PromoError::CnftClaimOnly
+    );
+
+    let receipt_count = receipt_count as usize;
+    require!(
+        receipt_count <= ctx.remaining_accounts.len(),
+        PromoError::InvalidCnftProof
+    );
+    let (category_receipts, proof_nodes) = ctx.remaining_accounts.split_at(receipt_count);
+
+    let asset_id = verify_cnft_collection(
+        &ctx.accounts.compression_program,
+        &ctx.accounts.merkle_tree,
+        proof_nodes,
+        &CnftLeaf {
+            root,
+            nonce,
+            delegate,
+            creator_hash,
+            metadata_args: &metadata_args,
+        },
+        &claimant,
+        &campaign.required_cnft_collection,
+    )?;
+    require_keys_eq!(asset_id, ctx.accounts.asset_id.key(), PromoError::InvalidCnftProof);
+
+    check_recipient_eligibility(
+        campaign,
+        &claimant,
+        &EligibilityProofs {
+            blocked_wallet: &ctx.accounts.blocked_wallet,
+            segment: ctx.accounts.segment.as_ref(),
+            segment_proof: &segment_proof,
+            category_receipts,
+            achievement: ctx.accounts.claimant_achievement.as_ref(),
+            prerequisite_proof: ctx
+                .accounts
+                .prerequisite_proof
+                .as_ref()
+                .map(|proof| proof.as_ref()),
+            history: ctx
+                .accounts
+                .claimant_history
+                .as_ref()
+                .map(|history| history.as_ref()),
+            nft_token_account: None,
+            nft_metadata: None,
+            gate_token_account: ctx.accounts.gate_token_account.as_deref(),
+            instructions_sysvar: ctx
+                .accounts
+                .instructions_sysvar
+                .as_ref()
+                .map(|sysvar| sysvar.as_ref()),
+            sns_domain: ctx
+                .accounts
+                .sns_domain
+                .as_ref()
+                .map(|domain| domain.as_ref()),
+            eligibility_program: ctx
+                .accounts
+                .eligibility_program
+                .as_ref()
+                .map(|program| program.as_ref()),
+            eligibility_state: ctx
+                .accounts
+                .eligibility_state
+                .as_ref()
+                .map(|state| state.as_ref()),
+            kyc_attestation: ctx
+                .accounts
+                .kyc_attestation
+                .as_ref()
+                .map(|attestation| attestation.as_ref()),
+            whitelist_entry: ctx.accounts.whitelist_entry.as_deref(),
+            stake_account: ctx
+                .accounts
+                .stake_account
+                .as_ref()
+                .map(|stake| stake.as_ref()),
+            wallet_registration: ctx.accounts.wallet_registration.as_deref(),
+            cnft_verified: true,
+        },
+    )?;
+
+    record_stake_claim(
+        campaign,
+        ctx.accounts.stake_account.as_ref().map(|stake| stake.as_ref()),
+        ctx.accounts.stake_claim.as_ref().map(|claim| claim.as_ref()),
+        &ctx.accounts.claimant,
+        &ctx.accounts.system_program,
+    )?;
+
+    record_wallet_coupon(
+        campaign,
+        ctx.accounts.wallet_coupon_count.as_mut(),
+        &claimant,
+    )?;
+
+    record_category_claim(
+        &ctx.accounts.config,
+        campaign,
+        ctx.accounts.category_claim_count.as_mut(),
+        &claimant,
+    )?;
+
+    issue_coupon(
+        campaign,
+        &mut ctx.accounts.vault,
+        &mut ctx.accounts.coupon,
+        coupon_index,
+        claimant,
+        &ctx.accounts.platform_treasury.to_account_info(),
+        &ctx.accounts.config,
+    )?;
+
+    let nft_claim = &mut ctx.accounts.nft_claim;
+    nft_claim.campaign = campaign.key();
+    nft_claim.nft_mint = asset_id;
+    nft_claim.claimant = claimant;
+    nft_claim.coupon = ctx.accounts.coupon.key();
+    nft_claim.claimed_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, coupon_index: u64)]
+pub struct ClaimCouponWithCnft<'info> {
+    /// Global config – fee switches and platform treasury.
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"campaign",
+            campaign.creator.as_ref(),
+            &campaign_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// Coupon PDA. One PDA per (campaign, coupon_index); `coupon_index` must
+    /// be `campaign.next_coupon_index`.
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + Coupon::SIZE,
+        seeds = [
+            b"coupon",
+            campaign.key().as_ref(),
+            &coupon_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub coupon: Box<Account<'info, Coupon>>,
+
+    /// Claim marker. One PDA per (campaign, asset id, period).
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + NftClaim::SIZE,
+        seeds = [
+            b"nft_claim",
+            campaign.key().as_ref(),
+            asset_id.key().as_ref(),
+            &campaign.period.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub nft_claim: Box<Account<'info, NftClaim>>,
+
+    /// CHECK: Bubblegum asset id of the cNFT (not an account); checked
+    /// against the id derived from the tree and leaf nonce.
+    pub asset_id: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum Merkle tree holding the cNFT; owner and authority
+    /// are verified in `verify_cnft_collection`.
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program, verified by address.
+    #[account(address = ACCOUNT_COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// NFT holder receiving the coupon and paying the rent.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// CHECK: Blocklist PDA of the claimant (usually uninitialized); the claimant
+    /// is rejected if it exists.
+    #[account(
+        seeds = [
+            b"blocked",
+            campaign.key().as_ref(),
+            claimant.key().as_ref(),
+        ],
+        bump
+    )]
+    pub blocked_wallet: UncheckedAccount<'info>,
+
+    /// CHECK: Platform treasury receiving the mint cost; must match `config.platform_treasury`.
+    #[account(
+        mut,
+        constraint = config.platform_treasury != Pubkey::default()
+            && platform_treasury.key() == config.platform_treasury
+            @ PromoError::InvalidPlatformTreasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// Audience segment referenced by the campaign (required only if `campaign.segment` is set).
+    pub segment: Option<Account<'info, Segment>>,
+
+    /// CHECK: Coupon or redemption receipt from `campaign.prerequisite_campaign`.
+    /// Owner, discriminator and contents are verified in the handler.
+    pub prerequisite_proof: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: `CustomerHistory` PDA of the claimant at this merchant (may be uninitialized).
+    /// Required only if `campaign.first_purchase_only` or `min_prior_redemptions`
+    /// is set; read via `prior_redemptions`.
+    #[account(
+        seeds = [
+            b"history",
+            campaign.merchant.as_ref(),
+            claimant.key().as_ref(),
+        ],
+        bump
+    )]
+    pub claimant_history: Option<UncheckedAccount<'info>>,
+
+    /// Claimant's progress on `campaign.required_achievement` (required only if set).
+    pub claimant_achievement: Option<Account<'info, AchievementProgress>>,
+
+    /// Coupons already received by the claimant (required only if
+    /// `campaign.max_coupons_per_wallet` is set).
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = 8 + WalletCouponCount::SIZE,
+        seeds = [
+            b"wallet_coupons",
+            campaign.key().as_ref(),
+            claimant.key().as_ref(),
+            &campaign.period.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub wallet_coupon_count: Option<Account<'info, WalletCouponCount>>,
+
+    /// Coupons received by the claimant in the campaign category this epoch
+    /// (required only if `config.category_claims_per_epoch` is set).
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = 8 + CategoryClaimCount::SIZE,
+        seeds = [
+            b"category_claims",
+            claimant.key().as_ref(),
+            &campaign.category_code.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub category_claim_count: Option<Account<'info, CategoryClaimCount>>,
+
+    /// Claimant's token account of `campaign.gate_mint` (required only if set).
+    pub gate_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: Instructions sysvar, read for the Ed25519 region attestation
+    /// (required only if `campaign.region_attestor` is set).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: SNS name registry account of a domain owned by the claimant
+    /// (required only if `campaign.sns_parent_domain` is set); verified in
+    /// `verify_sns_domain`.
+    pub sns_domain: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: `campaign.eligibility_program` (required only if set); checked by
+    /// address and invoked read-only.
+    pub eligibility_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Optional state account handed to the eligibility program as-is.
+    pub eligibility_state: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: KYC / age attestation of the claimant (required only if
+    /// `campaign.kyc_attestation_program` is set); verified in `verify_kyc_attestation`.
+    pub kyc_attestation: Option<UncheckedAccount<'info>>,
+
+    /// Whitelist entry of the claimant (required only if `campaign.requires_whitelist`).
+    pub whitelist_entry: Option<Box<Account<'info, WhitelistEntry>>>,
+
+    /// CHECK: Stake account withdrawable by the claimant (required only if the
+    /// campaign sybil gate is set); verified in `verify_stake`.
+    pub stake_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Marker PDA `[b"stake_claim", campaign, stake_account]` (required
+    /// only with `stake_account`); created by `record_stake_claim`.
+    #[account(mut)]
+    pub stake_claim: Option<UncheckedAccount<'info>>,
+
+    /// Registration of the claimant (age path of the sybil gate).
+    #[account(
+        seeds = [
+            b"wallet_registration",
+            claimant.key().as_ref(),
+        ],
+        bump
+    )]
+    pub wallet_registration: Option<Box<Account<'info, WalletRegistration>>>,
+
+    pub system_program: Program<'info, System>,
+}
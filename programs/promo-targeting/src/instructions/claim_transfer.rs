@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Approved recipient takes a coupon offered with `approve_transfer`.
+///
+/// The transfer is checked and recorded exactly like `transfer_coupon`
+/// (hold, transferability, transfer limits); any listing is cleared.
+pub fn claim_transfer(ctx: Context<ClaimTransfer>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let coupon = &mut ctx.accounts.coupon;
+    let new_owner = ctx.accounts.new_owner.key();
+
+    require!(
+        coupon.pending_owner != Pubkey::default() && coupon.pending_owner == new_owner,
+        PromoError::NotPendingOwner
+    );
+    require!(!coupon.used, PromoError::CouponAlreadyUsed);
+    require!(
+        !coupon.is_held(&Clock::get()?),
+        PromoError::CouponOnHold
+    );
+    require!(campaign.transferable, PromoError::CouponNotTransferable);
+
+    record_coupon_transfer(campaign, coupon)?;
+
+    coupon.owner = new_owner;
+    coupon.listed = false;
+    coupon.sale_price_lamports = 0;
+    coupon.reserved_buyer = Pubkey::default();
+    coupon.held_until = 0;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimTransfer<'info> {
+    /// Campaign of the coupon (transferability and transfer limits).
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        has_one = campaign @ PromoError::InvalidCouponCampaign
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    /// Recipient approved by the owner.
+    pub new_owner: Signer<'info>,
+}
@@ -0,0 +1,219 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Claim a coupon with a voucher signed off-chain by the campaign merchant.
+///
+/// The voucher is an Ed25519 signature by `campaign.merchant` over
+/// `campaign || claimant || nonce (u64 LE)`, verified through an Ed25519
+/// program instruction earlier in the same transaction. Each nonce can be
+/// used once per campaign (`VoucherNonce` PDA).
+///
+/// The voucher stands in for the campaign's targeting rules; excluded and
+/// blocked wallets, the KYC requirement and the per-wallet limit still
+/// apply. The claimant pays the rent; the mint cost is paid by the vault
+/// exactly as in `mint_coupon`.
+pub fn claim_with_voucher(
+    ctx: Context<ClaimWithVoucher>,
+    campaign_id: u64,
+    coupon_index: u64,
+    nonce: u64,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let claimant = ctx.accounts.claimant.key();
+
+    require!(
+        campaign.campaign_id == campaign_id,
+        PromoError::InvalidCampaignId
+    );
+
+    require_next_coupon_index(campaign, coupon_index)?;
+
+    // Anti-bot throttling of self-service claims
+    throttle_claim(campaign)?;
+
+    let mut message = campaign.key().to_bytes().to_vec();
+    message.extend_from_slice(claimant.as_ref());
+    message.extend_from_slice(&nonce.to_le_bytes());
+    require!(
+        verify_ed25519_signature(
+            &ctx.accounts.instructions_sysvar,
+            &campaign.merchant,
+            &message,
+        )?,
+        PromoError::InvalidVoucher
+    );
+
+    require!(
+        !campaign.excluded_wallets.contains(&claimant),
+        PromoError::WalletExcluded
+    );
+    require_not_blocked(&ctx.accounts.blocked_wallet)?;
+    verify_kyc_attestation(
+        campaign,
+        ctx.accounts.kyc_attestation.as_ref().map(|attestation| attestation.as_ref()),
+        &claimant,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    record_wallet_coupon(
+        campaign,
+        ctx.accounts.wallet_coupon_count.as_mut(),
+        &claimant,
+    )?;
+
+    record_category_claim(
+        &ctx.accounts.config,
+        campaign,
+        ctx.accounts.category_claim_count.as_mut(),
+        &claimant,
+    )?;
+
+    issue_coupon(
+        campaign,
+        &mut ctx.accounts.vault,
+        &mut ctx.accounts.coupon,
+        coupon_index,
+        claimant,
+        &ctx.accounts.platform_treasury.to_account_info(),
+        &ctx.accounts.config,
+    )?;
+
+    let voucher_nonce = &mut ctx.accounts.voucher_nonce;
+    voucher_nonce.campaign = campaign.key();
+    voucher_nonce.nonce = nonce;
+    voucher_nonce.claimant = claimant;
+    voucher_nonce.consumed_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, coupon_index: u64, nonce: u64)]
+pub struct ClaimWithVoucher<'info> {
+    /// Global config – fee switches and platform treasury.
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"campaign",
+            campaign.creator.as_ref(),
+            &campaign_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// Coupon PDA. One PDA per (campaign, coupon_index); `coupon_index` must
+    /// be `campaign.next_coupon_index`.
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + Coupon::SIZE,
+        seeds = [
+            b"coupon",
+            campaign.key().as_ref(),
+            &coupon_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub coupon: Box<Account<'info, Coupon>>,
+
+    /// Consumed voucher nonce. One PDA per (campaign, nonce); its creation
+    /// is what prevents replay.
+    #[account(
+        init,
+        payer = claimant,
+        space = 8 + VoucherNonce::SIZE,
+        seeds = [
+            b"voucher",
+            campaign.key().as_ref(),
+            &nonce.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub voucher_nonce: Box<Account<'info, VoucherNonce>>,
+
+    /// Wallet named in the voucher, receiving the coupon and paying the rent.
+    #[account(mut)]
+    pub claimant: Signer<'info>,
+
+    /// CHECK: Blocklist PDA of the claimant (usually uninitialized); the claimant
+    /// is rejected if it exists.
+    #[account(
+        seeds = [
+            b"blocked",
+            campaign.key().as_ref(),
+            claimant.key().as_ref(),
+        ],
+        bump
+    )]
+    pub blocked_wallet: UncheckedAccount<'info>,
+
+    /// CHECK: KYC / age attestation of the claimant (required only if
+    /// `campaign.kyc_attestation_program` is set); verified in `verify_kyc_attestation`.
+    pub kyc_attestation: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Platform treasury receiving the mint cost; must match `config.platform_treasury`.
+    #[account(
+        mut,
+        constraint = config.platform_treasury != Pubkey::default()
+            && platform_treasury.key() == config.platform_treasury
+            @ PromoError::InvalidPlatformTreasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// Coupons already received by the claimant (required only if
+    /// `campaign.max_coupons_per_wallet` is set).
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = 8 + WalletCouponCount::SIZE,
+        seeds = [
+            b"wallet_coupons",
+            campaign.key().as_ref(),
+            claimant.key().as_ref(),
+            &campaign.period.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub wallet_coupon_count: Option<Account<'info, WalletCouponCount>>,
+
+    /// Coupons received by the claimant in the campaign category this epoch
+    /// (required only if `config.category_claims_per_epoch` is set).
+    #[account(
+        init_if_needed,
+        payer = claimant,
+        space = 8 + CategoryClaimCount::SIZE,
+        seeds = [
+            b"category_claims",
+            claimant.key().as_ref(),
+            &campaign.category_code.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub category_claim_count: Option<Account<'info, CategoryClaimCount>>,
+
+    /// CHECK: Instructions sysvar, read for the Ed25519 voucher signature.
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::*;
+use crate::states::*;
+
+use super::create_campaign::init_campaign;
+
+/// Merchant creates a new campaign from one of their existing campaigns.
+///
+/// Copies discount, resale, coupon count / costs, category and product
+/// codes, name and targeting (target wallet, segment, exclusions and the
+/// `TargetingParams` gates) from `source_campaign`; only the id, expiration
+/// and deposit are new. Useful for recurring weekly promotions.
+///
+/// The clone is validated like `create_campaign` against the current
+/// GlobalConfig and starts active, with fresh counters and analytics.
+pub fn clone_campaign(
+    ctx: Context<CloneCampaign>,
+    new_campaign_id: u64,
+    new_expiration: i64,
+    deposit_amount: u64,
+) -> Result<()> {
+    let source = &ctx.accounts.source_campaign;
+    let campaign = &mut ctx.accounts.campaign;
+    let vault = &mut ctx.accounts.vault;
+    let merchant = &ctx.accounts.merchant;
+
+    let campaign_key = campaign.key();
+    init_campaign(
+        campaign,
+        campaign_key,
+        vault,
+        ctx.bumps.vault,
+        &ctx.accounts.config,
+        &ctx.accounts.category_registry,
+        merchant.key(),
+        CampaignParams {
+            campaign_id: new_campaign_id,
+            discount_bps: source.discount_bps,
+            resale_bps: source.resale_bps,
+            expiration_timestamp: new_expiration,
+            total_coupons: source.total_coupons,
+            mint_cost_lamports: source.mint_cost_lamports,
+            max_discount_lamports: source.max_discount_lamports,
+            category_code: source.category_code,
+            product_code: source.product_code,
+            campaign_name: source.campaign_name.clone(),
+            deposit_amount,
+            requires_wallet: source.requires_wallet,
+            target_wallet: source.target_wallet,
+            draft: false,
+        },
+    )?;
+
+    // Same discount type and product family
+    campaign.discount_kind = source.discount_kind;
+    campaign.extra_product_codes = source.extra_product_codes.clone();
+
+    // Same stacking policy
+    campaign.stackable = source.stackable;
+    campaign.max_stacked_discount_bps = source.max_stacked_discount_bps;
+
+    // Same store-credit value
+    campaign.coupon_value_lamports = source.coupon_value_lamports;
+
+    // Same secondary-market limits
+    campaign.max_transfers = source.max_transfers;
+    campaign.transfer_cooldown_secs = source.transfer_cooldown_secs;
+
+    // Same off-chain metadata
+    campaign.metadata_uri = source.metadata_uri.clone();
+
+    // Targeting of the source campaign
+    campaign.segment = source.segment;
+    campaign.excluded_wallets = source.excluded_wallets.clone();
+    TargetingParams::of(source).apply_to(campaign);
+
+    // Transfer lamports from merchant (system account) to vault (program-owned PDA).
+    let cpi_accounts = system_program::Transfer {
+        from: merchant.to_account_info(),
+        to: vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, deposit_amount)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(new_campaign_id: u64)]
+pub struct CloneCampaign<'info> {
+    /// Global config – defines policy for campaigns (including max_resale_bps).
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Campaign whose parameters are copied.
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub source_campaign: Box<Account<'info, Campaign>>,
+
+    /// New campaign PDA. One PDA per (merchant, campaign_id).
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + Campaign::SIZE,
+        seeds = [
+            b"campaign",
+            merchant.key().as_ref(),
+            &new_campaign_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    /// Vault PDA that holds the new campaign budget.
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + Vault::SIZE,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// CHECK: Tombstone PDA of a previously closed campaign with this id;
+    /// must not exist (see `close_campaign`).
+    #[account(
+        seeds = [
+            b"closed_campaign",
+            campaign.key().as_ref(),
+        ],
+        bump,
+        constraint = closed_campaign.data_is_empty() @ PromoError::CampaignIdRetired
+    )]
+    pub closed_campaign: UncheckedAccount<'info>,
+
+    /// Merchant funding the new campaign.
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    /// Registry of the category codes campaigns can use.
+    #[account(
+        seeds = [b"category_registry"],
+        bump
+    )]
+    pub category_registry: Box<Account<'info, CategoryRegistry>>,
+
+    /// Profile of the merchant (see `register_merchant`).
+    #[account(
+        seeds = [
+            b"merchant_profile",
+            merchant.key().as_ref(),
+        ],
+        bump
+    )]
+    pub merchant_profile: Account<'info, MerchantProfile>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant closes the campaign account and reclaims its rent once the
+/// campaign ended more than `retention_secs` ago.
+///
+/// - `retention_secs` can't be shorter than `Campaign::MIN_RETENTION_SECS`,
+///   so analytics consumers always have that window to read the account.
+/// - The vault must already be closed (`close_campaign_vault`).
+/// - Every coupon must be redeemed or expired (`expire_coupon`,
+///   `expire_coupons_batch`).
+/// - A `ClosedCampaign` tombstone (paid from the reclaimed rent) retires the
+///   `campaign_id`: receipts, wallet counters, claim markers and the other
+///   campaign-derived PDAs outlive the campaign, so the id can't be reused.
+/// - A final `CampaignFinalStats` event carries the analytics that go away
+///   with the account.
+pub fn close_campaign(ctx: Context<CloseCampaign>, retention_secs: i64) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let closed_campaign = &mut ctx.accounts.closed_campaign;
+
+    require!(
+        retention_secs >= Campaign::MIN_RETENTION_SECS,
+        PromoError::RetentionPeriodNotElapsed
+    );
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        campaign.is_expired(now)
+            && now >= campaign.ended_at().saturating_add(retention_secs),
+        PromoError::RetentionPeriodNotElapsed
+    );
+    require!(ctx.accounts.vault.data_is_empty(), PromoError::VaultStillOpen);
+    require!(campaign.outstanding_coupons == 0, PromoError::CouponsOutstanding);
+
+    closed_campaign.campaign_id = campaign.campaign_id;
+    closed_campaign.closed_at = now;
+
+    emit!(CampaignFinalStats {
+        campaign: campaign.key(),
+        merchant: campaign.merchant,
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        campaign_id: campaign.campaign_id,
+        total_coupons: campaign.total_coupons,
+        minted_coupons: campaign.minted_coupons,
+        used_coupons: campaign.used_coupons,
+        total_purchase_amount: campaign.total_purchase_amount,
+        total_discount_lamports: campaign.total_discount_lamports,
+        receipts_hash: campaign.receipts_hash,
+        closed_at: now,
+    });
+
+    Ok(())
+}
+
+/// Final analytics of a campaign, emitted right before its account is closed.
+#[event]
+pub struct CampaignFinalStats {
+    pub campaign: Pubkey,
+    pub merchant: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub campaign_id: u64,
+    pub total_coupons: u32,
+    pub minted_coupons: u32,
+    pub used_coupons: u32,
+    pub total_purchase_amount: u64,
+    pub total_discount_lamports: u64,
+    pub receipts_hash: [u8; 32],
+    pub closed_at: i64,
+}
+
+#[derive(Accounts)]
+pub struct CloseCampaign<'info> {
+    /// Campaign to close; its rent goes back to the merchant.
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant,
+        close = merchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Vault PDA of the campaign; must already be closed.
+    #[account(
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump
+    )]
+    pub vault: UncheckedAccount<'info>,
+
+    /// Tombstone retiring the campaign id.
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + ClosedCampaign::SIZE,
+        seeds = [
+            b"closed_campaign",
+            campaign.key().as_ref(),
+        ],
+        bump
+    )]
+    pub closed_campaign: Account<'info, ClosedCampaign>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,14 +1,25 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, CloseAccount, Mint, Token, TokenAccount, TransferChecked};
 
 use crate::errors::*;
 use crate::states::*;
+use crate::utils::*;
 
     /// Close the campaign vault and return remaining budget to the merchant
-    /// after campaign expiration.
+    /// after campaign expiration (or once an "until funds last" budget ran out,
+    /// every coupon was redeemed, or the campaign was cancelled).
     ///
     /// - Mint costs and service fees have already been transferred to the
     ///   platform treasury at each operation.
-    /// - Remaining lamports in the vault (if any) are returned to the merchant.
+    /// - If the vault was topped up by the matching pool, the unspent share of
+    ///   the match (pro-rata to `matched_lamports / (total_deposit + matched_lamports)`)
+    ///   goes back to the pool first.
+    /// - Remaining lamports in the vault (if any) are returned to the campaign
+    ///   payout wallet (the merchant unless `set_payout_wallet` was used).
+    /// - If a companion reward is set (`set_redemption_reward`), the tokens
+    ///   left in the vault-owned reward account go to a payout wallet token
+    ///   account and the reward account is closed (rent to the payout wallet),
+    ///   since only the vault PDA can move them.
     /// - The campaign account stays alive for historical analytics.
     pub fn close_campaign_vault(ctx: Context<CloseCampaignVault>) -> Result<()> {
         let campaign = &ctx.accounts.campaign;
@@ -20,10 +31,87 @@ use crate::states::*;
         // Campaign must be expired
         let clock = Clock::get()?;
         require!(
-            clock.unix_timestamp > campaign.expiration_timestamp,
+            campaign.is_expired(clock.unix_timestamp),
             PromoError::CampaignNotExpired
         );
 
+        // Return the unspent share of the platform match to the matching pool
+        let matched = ctx.accounts.vault.matched_lamports;
+        if matched > 0 {
+            let pool = ctx
+                .accounts
+                .matching_pool
+                .as_mut()
+                .ok_or(PromoError::MatchingPoolRequired)?;
+            let vault_info = ctx.accounts.vault.to_account_info();
+
+            let rent_floor = Rent::get()?.minimum_balance(vault_info.data_len());
+            let spendable = vault_info.lamports().saturating_sub(rent_floor);
+            let funded = ctx
+                .accounts
+                .vault
+                .total_deposit
+                .checked_add(matched)
+                .ok_or(PromoError::Overflow)?;
+            let unspent_match = (spendable as u128)
+                .checked_mul(matched as u128)
+                .ok_or(PromoError::Overflow)?
+                / funded as u128;
+
+            transfer_lamports(&vault_info, &pool.to_account_info(), unspent_match as u64)?;
+
+            pool.total_matched = pool.total_matched.saturating_sub(matched);
+        }
+
+        // Sweep the companion reward tokens before the vault PDA goes away
+        if campaign.reward_mint != Pubkey::default() {
+            let (Some(mint), Some(source), Some(destination), Some(token_program)) = (
+                ctx.accounts.reward_mint.as_ref(),
+                ctx.accounts.reward_source.as_ref(),
+                ctx.accounts.reward_destination.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(PromoError::RewardAccountsRequired);
+            };
+
+            let vault = &ctx.accounts.vault;
+            let payout = &ctx.accounts.payout;
+            require_keys_eq!(mint.key(), campaign.reward_mint, PromoError::InvalidRewardAccount);
+            require_keys_eq!(source.mint, campaign.reward_mint, PromoError::InvalidRewardAccount);
+            require_keys_eq!(source.owner, vault.key(), PromoError::InvalidRewardAccount);
+            require_keys_eq!(destination.mint, campaign.reward_mint, PromoError::InvalidRewardAccount);
+            require_keys_eq!(destination.owner, payout.key(), PromoError::InvalidRewardAccount);
+
+            let campaign_key = campaign.key();
+            let signer_seeds: &[&[&[u8]]] = &[&[b"vault", campaign_key.as_ref(), &[vault.bump]]];
+            if source.amount > 0 {
+                let cpi_accounts = TransferChecked {
+                    from: source.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: destination.to_account_info(),
+                    authority: vault.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds,
+                );
+                token::transfer_checked(cpi_ctx, source.amount, mint.decimals)?;
+            }
+
+            let cpi_accounts = CloseAccount {
+                account: source.to_account_info(),
+                destination: payout.to_account_info(),
+                authority: vault.to_account_info(),
+            };
+            let cpi_ctx = CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                cpi_accounts,
+                signer_seeds,
+            );
+            token::close_account(cpi_ctx)?;
+        }
+
         Ok(())
     }
 
@@ -34,7 +122,7 @@ use crate::states::*;
     #[account(has_one = merchant)]
     pub campaign: Account<'info, Campaign>,
 
-    /// Vault to be closed. Remaining lamports go to `merchant`.
+    /// Vault to be closed. Remaining lamports go to `payout`.
     #[account(
         mut,
         seeds = [
@@ -42,15 +130,45 @@ use crate::states::*;
             campaign.key().as_ref(),
         ],
         bump = vault.bump,
-        close = merchant
+        close = payout
     )]
     pub vault: Account<'info, Vault>,
 
 
-    /// Merchant receiving the remaining lamports from the vault.
-    #[account(mut)]
+    /// Merchant authority closing the vault.
     pub merchant: Signer<'info>,
 
 
+    /// CHECK: Receives the remaining lamports; must be `campaign.payout_destination()`.
+    #[account(
+        mut,
+        constraint = payout.key() == campaign.payout_destination() @ PromoError::InvalidPayoutWallet
+    )]
+    pub payout: UncheckedAccount<'info>,
+
+
+    /// Matching pool receiving the unspent match. Required only when
+    /// `vault.matched_lamports > 0`.
+    #[account(
+        mut,
+        seeds = [b"matching_pool"],
+        bump = matching_pool.bump
+    )]
+    pub matching_pool: Option<Account<'info, MatchingPool>>,
+
+
+    /// Companion reward accounts (required only if `campaign.reward_mint` is
+    /// set): the vault-owned reward account and a payout wallet token account.
+    pub reward_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub reward_source: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub reward_destination: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+
     pub system_program: Program<'info, System>,
     }
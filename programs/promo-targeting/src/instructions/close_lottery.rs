@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Merchant closes a lottery once every winner was drawn, or after campaign
+/// expiration. Undrawn prizes and the rent go to the campaign payout wallet.
+///
+/// A pending draw commitment is closed as well (rent back to the randomness
+/// oracle), so a new lottery of the campaign can commit draws again.
+pub fn close_lottery(ctx: Context<CloseLottery>) -> Result<()> {
+    let lottery = &ctx.accounts.lottery;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        lottery.winners_drawn == lottery.total_winners || ctx.accounts.campaign.is_expired(now),
+        PromoError::LotteryActive
+    );
+
+    let draw_commitment = ctx.accounts.draw_commitment.to_account_info();
+    if !draw_commitment.data_is_empty() {
+        transfer_lamports(
+            &draw_commitment,
+            &ctx.accounts.randomness_oracle.to_account_info(),
+            draw_commitment.lamports(),
+        )?;
+        draw_commitment.assign(&system_program::ID);
+        draw_commitment.resize(0)?;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseLottery<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lottery",
+            campaign.key().as_ref(),
+        ],
+        bump = lottery.bump,
+        has_one = campaign,
+        close = payout
+    )]
+    pub lottery: Account<'info, Lottery>,
+
+    /// CHECK: Pending draw commitment of the lottery, if any (PDA of this
+    /// program, checked by seeds); closed here.
+    #[account(
+        mut,
+        seeds = [
+            b"draw_commitment",
+            lottery.key().as_ref(),
+        ],
+        bump
+    )]
+    pub draw_commitment: UncheckedAccount<'info>,
+
+    /// CHECK: Randomness oracle of the lottery, receiving the commitment rent.
+    #[account(
+        mut,
+        address = lottery.randomness_oracle @ PromoError::NotRandomnessOracle
+    )]
+    pub randomness_oracle: UncheckedAccount<'info>,
+
+    pub merchant: Signer<'info>,
+
+    /// CHECK: Receives the remaining pot; must be `campaign.payout_destination()`.
+    #[account(
+        mut,
+        constraint = payout.key() == campaign.payout_destination() @ PromoError::InvalidPayoutWallet
+    )]
+    pub payout: UncheckedAccount<'info>,
+}
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Randomness oracle commits to the secret of the next lottery draw and to
+/// the tickets it draws from.
+///
+/// - `commitment` is `sha256(secret)`; `draw_winner` reveals `secret`.
+/// - `ticket_root` / `ticket_count` snapshot the tickets: the coupon indexes
+///   issued in the current campaign period, as a Merkle list of
+///   `draw_ticket_leaf(position, coupon_index)` leaves. The winner is drawn
+///   among these positions and has to present that coupon (or its receipt).
+/// - The draw also mixes in the hash of the slot this commitment lands in,
+///   unknown to the oracle when it picks the secret and the snapshot, so it
+///   can't grind a winner.
+/// - One pending commitment per lottery, and it can't be replaced: the
+///   oracle has to reveal within the `SlotHashes` window (512 slots). A
+///   commitment that wasn't revealed in time can be dropped with
+///   `abandon_draw`, and the draw committed again.
+pub fn commit_draw(
+    ctx: Context<CommitDraw>,
+    commitment: [u8; 32],
+    ticket_root: [u8; 32],
+    ticket_count: u32,
+) -> Result<()> {
+    let lottery = &ctx.accounts.lottery;
+
+    require!(
+        lottery.winners_drawn < lottery.total_winners,
+        PromoError::LotteryComplete
+    );
+    require!(ticket_count > 0, PromoError::InvalidLottery);
+
+    let draw_commitment = &mut ctx.accounts.draw_commitment;
+    draw_commitment.lottery = lottery.key();
+    draw_commitment.commitment = commitment;
+    draw_commitment.slot = Clock::get()?.slot;
+    draw_commitment.draw_number = lottery.winners_drawn;
+    draw_commitment.period = ctx.accounts.campaign.period;
+    draw_commitment.ticket_root = ticket_root;
+    draw_commitment.ticket_count = ticket_count;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitDraw<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [
+            b"lottery",
+            campaign.key().as_ref(),
+        ],
+        bump = lottery.bump,
+        has_one = campaign,
+        has_one = randomness_oracle @ PromoError::NotRandomnessOracle
+    )]
+    pub lottery: Account<'info, Lottery>,
+
+    #[account(
+        init,
+        payer = randomness_oracle,
+        space = 8 + DrawCommitment::SIZE,
+        seeds = [
+            b"draw_commitment",
+            lottery.key().as_ref(),
+        ],
+        bump
+    )]
+    pub draw_commitment: Account<'info, DrawCommitment>,
+
+    #[account(mut)]
+    pub randomness_oracle: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
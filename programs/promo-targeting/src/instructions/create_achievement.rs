@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant defines an achievement unlocked after `required_redemptions`
+/// redemptions in `category_code` (0 = any category) across its campaigns.
+///
+/// Users opt in with `join_achievement`; only redemptions made after joining
+/// count. Unlocked achievements can gate exclusive campaigns
+/// (`set_required_achievement`).
+pub fn create_achievement(
+    ctx: Context<CreateAchievement>,
+    achievement_id: u64,
+    category_code: u16,
+    required_redemptions: u32,
+) -> Result<()> {
+    require!(required_redemptions > 0, PromoError::InvalidAchievement);
+
+    let achievement = &mut ctx.accounts.achievement;
+    achievement.merchant = ctx.accounts.merchant.key();
+    achievement.achievement_id = achievement_id;
+    achievement.category_code = category_code;
+    achievement.required_redemptions = required_redemptions;
+    achievement.bump = ctx.bumps.achievement;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(achievement_id: u64)]
+pub struct CreateAchievement<'info> {
+    /// Achievement PDA. One PDA per (merchant, achievement_id).
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + Achievement::SIZE,
+        seeds = [
+            b"achievement",
+            merchant.key().as_ref(),
+            &achievement_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub achievement: Account<'info, Achievement>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
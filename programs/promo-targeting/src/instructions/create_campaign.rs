@@ -7,6 +7,7 @@ use crate::states::*;
 /// Merchant creates a new discount campaign and funds a vault for it.
     ///
     /// Business logic:
+    /// - The merchant must have a `MerchantProfile` (see `register_merchant`).
     /// - The merchant deposits a budget into a dedicated vault.
     /// - This vault is used to:
     ///   * pay minting costs for each coupon (to the platform treasury)
@@ -23,6 +24,13 @@ use crate::states::*;
     /// - `requires_wallet = true`:
     ///     * Targeted campaign that requires a specific `target_wallet`.
     ///     * Only this wallet will be able to receive minted coupons on-chain.
+    /// - Optional `segment` account:
+    ///     * Restricts recipients to a reusable audience segment owned by the merchant.
+    /// - `draft = true` creates an inactive campaign: nothing can be minted or
+    ///   claimed until the merchant calls `activate_campaign`.
+    /// - Optional `template` account:
+    ///     * Applies a merchant-owned `TargetingTemplate` (gates, region, KYC, ...).
+    #[allow(clippy::too_many_arguments)]
     pub fn create_campaign(
         ctx: Context<CreateCampaign>,
         campaign_id: u64,
@@ -38,75 +46,63 @@ use crate::states::*;
         deposit_amount: u64,
         requires_wallet: bool, // false = All users, true = targeted
         target_wallet: Pubkey, // only relevant if requires_wallet = true
+        draft: bool,           // true = inactive until activate_campaign
     ) -> Result<()> {
         let config = &ctx.accounts.config;
         let campaign = &mut ctx.accounts.campaign;
         let vault = &mut ctx.accounts.vault;
         let merchant = &ctx.accounts.merchant;
 
-        // Basic validation for inputs
-        require!(discount_bps <= 10_000, PromoError::InvalidBps);
-        require!(resale_bps <= 10_000, PromoError::InvalidBps);
-        require!(total_coupons > 0, PromoError::InvalidTotalCoupons);
-        require!(mint_cost_lamports > 0, PromoError::InvalidMintCost);
-        require!(max_discount_lamports > 0, PromoError::InvalidMaxDiscount);
-        require!(deposit_amount > 0, PromoError::InvalidDepositAmount);
+        let campaign_key = campaign.key();
+        init_campaign(
+            campaign,
+            campaign_key,
+            vault,
+            ctx.bumps.vault,
+            config,
+            &ctx.accounts.category_registry,
+            merchant.key(),
+            CampaignParams {
+                campaign_id,
+                discount_bps,
+                resale_bps,
+                expiration_timestamp,
+                total_coupons,
+                mint_cost_lamports,
+                max_discount_lamports,
+                category_code,
+                product_code,
+                campaign_name,
+                deposit_amount,
+                requires_wallet,
+                target_wallet,
+                draft,
+            },
+        )?;
 
-        // Enforce resale_bps policy defined by the admin in GlobalConfig
-        require!(
-            resale_bps <= config.max_resale_bps,
-            PromoError::InvalidResalePrice
-        );
+        // Reusable audience segment (must belong to the same merchant)
+        campaign.segment = match &ctx.accounts.segment {
+            Some(segment) => {
+                require_keys_eq!(segment.merchant, merchant.key(), PromoError::InvalidSegment);
+                segment.key()
+            }
+            None => Pubkey::default(),
+        };
 
-        // If the campaign requires a wallet, dashboard/frontend must provide a non-default target wallet.
-        if requires_wallet {
-            require!(
-                target_wallet != Pubkey::default(),
-                PromoError::TargetWalletRequired
+        // Targeting template: copies its gates, and its segment unless one
+        // was passed explicitly.
+        if let Some(template) = &ctx.accounts.template {
+            require_keys_eq!(
+                template.merchant,
+                merchant.key(),
+                PromoError::InvalidTargetingTemplate
             );
+            template.params.apply_to(campaign);
+            if ctx.accounts.segment.is_none() {
+                campaign.segment = template.segment;
+            }
         }
 
-        // Enforce a maximum length for the campaign name (in bytes)
-        require!(
-            campaign_name.as_bytes().len() <= Campaign::MAX_NAME_LEN,
-            PromoError::NameTooLong
-        );
-
-        // Initialize campaign fields
-        campaign.merchant = merchant.key();
-        campaign.campaign_id = campaign_id;
-        campaign.discount_bps = discount_bps;
-        campaign.service_fee_bps = config.service_fee_bps;
-        campaign.resale_bps = resale_bps;
-        campaign.expiration_timestamp = expiration_timestamp;
-        campaign.total_coupons = total_coupons;
-        campaign.used_coupons = 0;
-        campaign.minted_coupons = 0;
-        campaign.mint_cost_lamports = mint_cost_lamports;
-        campaign.max_discount_lamports = max_discount_lamports;
-        campaign.category_code = category_code;
-        campaign.product_code = product_code;
-        campaign.campaign_name = campaign_name;
-        campaign.requires_wallet = requires_wallet;
-        campaign.target_wallet = if requires_wallet {
-            target_wallet
-        } else {
-            Pubkey::default()
-        };
-
-        // Analytics helpers
-        campaign.total_purchase_amount = 0;
-        campaign.total_discount_lamports = 0;
-        campaign.last_redeem_timestamp = 0;
-
-        // Initialize vault fields
-        vault.campaign = campaign.key();
-        vault.merchant = merchant.key();
-        vault.bump = ctx.bumps.vault;
-        vault.total_deposit = deposit_amount;
-        vault.total_mint_spent = 0;
-        vault.total_service_spent = 0;
-
         // Transfer lamports from merchant (system account) to vault (program-owned PDA).
         let cpi_accounts = system_program::Transfer {
             from: merchant.to_account_info(),
@@ -118,6 +114,300 @@ use crate::states::*;
         Ok(())
     }
 
+/// Validate `params` and initialize a freshly created campaign / vault pair.
+///
+/// Shared by every campaign creation path; the caller applies the audience
+/// segment / targeting template and funds the vault with `deposit_amount`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn init_campaign(
+    campaign: &mut Campaign,
+    campaign_key: Pubkey,
+    vault: &mut Vault,
+    vault_bump: u8,
+    config: &GlobalConfig,
+    categories: &CategoryRegistry,
+    merchant: Pubkey,
+    params: CampaignParams,
+) -> Result<()> {
+    let CampaignParams {
+        campaign_id,
+        discount_bps,
+        resale_bps,
+        expiration_timestamp,
+        total_coupons,
+        mint_cost_lamports,
+        max_discount_lamports,
+        category_code,
+        product_code,
+        campaign_name,
+        deposit_amount,
+        requires_wallet,
+        target_wallet,
+        draft,
+    } = params;
+
+    // Basic validation for inputs
+    require!(discount_bps <= 10_000, PromoError::InvalidBps);
+    require!(resale_bps <= 10_000, PromoError::InvalidBps);
+    require!(total_coupons > 0, PromoError::InvalidTotalCoupons);
+    require!(mint_cost_lamports > 0, PromoError::InvalidMintCost);
+    require!(max_discount_lamports > 0, PromoError::InvalidMaxDiscount);
+    require!(deposit_amount > 0, PromoError::InvalidDepositAmount);
+
+    // Enforce resale_bps policy defined by the admin in GlobalConfig
+    require!(
+        resale_bps <= config.max_resale_bps,
+        PromoError::InvalidResalePrice
+    );
+
+    // If the campaign requires a wallet, dashboard/frontend must provide a non-default target wallet.
+    if requires_wallet {
+        require!(
+            target_wallet != Pubkey::default(),
+            PromoError::TargetWalletRequired
+        );
+    }
+
+    // Category must be one of the admin-curated codes
+    require!(
+        categories.is_active(category_code),
+        PromoError::UnknownCategory
+    );
+
+    // Enforce a maximum length for the campaign name (in bytes)
+    require!(
+        campaign_name.len() <= Campaign::MAX_NAME_LEN,
+        PromoError::NameTooLong
+    );
+
+    // Initialize campaign fields
+    campaign.merchant = merchant;
+    campaign.campaign_id = campaign_id;
+    campaign.discount_bps = discount_bps;
+    campaign.service_fee_bps = config.service_fee_bps;
+    campaign.resale_bps = resale_bps;
+    campaign.expiration_timestamp = expiration_timestamp;
+    campaign.total_coupons = total_coupons;
+    campaign.used_coupons = 0;
+    campaign.minted_coupons = 0;
+    campaign.mint_cost_lamports = mint_cost_lamports;
+    campaign.max_discount_lamports = max_discount_lamports;
+    campaign.category_code = category_code;
+    campaign.product_code = product_code;
+    campaign.campaign_name = campaign_name;
+    campaign.requires_wallet = requires_wallet;
+    campaign.target_wallet = if requires_wallet {
+        target_wallet
+    } else {
+        Pubkey::default()
+    };
+
+    // Audience segment / targeting template are applied by the caller
+    campaign.segment = Pubkey::default();
+
+    // Behavioral targeting is configured separately (see set_category_targeting)
+    campaign.required_category_code = 0;
+    campaign.min_category_redemptions = 0;
+
+    // No exclusions until the merchant sets them (see set_excluded_wallets)
+    campaign.excluded_wallets = Vec::new();
+
+    // Sequel-campaign retargeting is configured separately (see set_prerequisite_campaign)
+    campaign.prerequisite_campaign = Pubkey::default();
+
+    // In-store geofencing is configured separately (see set_location_requirement)
+    campaign.location_oracle = Pubkey::default();
+    campaign.location_region_code = 0;
+    campaign.location_max_age_secs = 0;
+
+    // Weekday / time-of-day schedule is configured separately (see set_redemption_schedule)
+    campaign.redeem_days_mask = 0;
+    campaign.redeem_hours_mask = 0;
+    campaign.schedule_utc_offset_mins = 0;
+
+    // New-customer restriction is configured separately (see set_first_purchase_only)
+    campaign.first_purchase_only = false;
+
+    // Resale withholding jurisdiction is configured separately (see set_campaign_jurisdiction)
+    campaign.jurisdiction_code = 0;
+
+    // Per-user discount cap is configured separately (see set_user_discount_cap)
+    campaign.max_discount_per_user = 0;
+
+    // Daily budget pacing is configured separately (see set_daily_budget)
+    campaign.daily_budget_lamports = 0;
+    campaign.budget_day = 0;
+    campaign.budget_day_spent = 0;
+
+    // "Until funds last" expiration is configured separately (see set_budget_expiration)
+    campaign.expire_when_budget_exhausted = false;
+    campaign.budget_exhausted_at = 0;
+
+    // Refunds go to the merchant until a payout wallet is set (see set_payout_wallet)
+    campaign.payout_wallet = Pubkey::default();
+
+    // Authority handoff (see transfer_campaign_authority)
+    campaign.creator = merchant;
+    campaign.pending_merchant = Pubkey::default();
+
+    // Dual-approval redemption is configured separately (see set_redemption_verifier)
+    campaign.redemption_verifier = Pubkey::default();
+
+    // Streak bonus is configured separately (see set_streak_bonus)
+    campaign.streak_bonus_bps = 0;
+    campaign.max_streak_bonus_bps = 0;
+
+    // Achievement gating is configured separately (see set_required_achievement)
+    campaign.required_achievement = Pubkey::default();
+
+    // Oracle-conditional redemption is configured separately (see set_redemption_condition)
+    campaign.condition_oracle = Pubkey::default();
+    campaign.condition_feed_id = 0;
+    campaign.condition_op = 0;
+    campaign.condition_value = 0;
+
+    // Companion token reward is configured separately (see set_redemption_reward)
+    campaign.reward_mint = Pubkey::default();
+    campaign.reward_amount = 0;
+
+    // Gas rebates are configured separately (see set_gas_rebate)
+    campaign.gas_rebate_lamports = 0;
+
+    // Co-branding partner is configured separately (see set_campaign_partner)
+    campaign.partner = Pubkey::default();
+    campaign.partner_share_bps = 0;
+
+    // Rounding of discount / fee / resale-cap math is fixed at creation
+    campaign.rounding_mode = config.rounding_mode;
+
+    // QR nonce requirement is configured separately (see set_qr_nonce_required)
+    campaign.requires_qr_nonce = false;
+
+    // Running hash of the receipt set (see archive_campaign)
+    campaign.receipts_hash = [0u8; 32];
+
+    // Token gating (NFT collection, SPL balance, cNFT collection)
+    campaign.required_collection = Pubkey::default();
+    campaign.gate_mint = Pubkey::default();
+    campaign.gate_min_amount = 0;
+    campaign.required_cnft_collection = Pubkey::default();
+
+    // Per-wallet limit (see set_wallet_coupon_limit)
+    campaign.max_coupons_per_wallet = 0;
+
+    // Region targeting (see set_region_targeting)
+    campaign.region_attestor = Pubkey::default();
+    campaign.region_code = 0;
+
+    // Coupons are transferable unless made soulbound (see set_transferable)
+    campaign.transferable = true;
+
+    // Loyalty tier (see set_loyalty_tier)
+    campaign.min_prior_redemptions = 0;
+
+    // SNS gating (see set_sns_parent_domain)
+    campaign.sns_parent_domain = Pubkey::default();
+
+    // Eligibility hook (see set_eligibility_program)
+    campaign.eligibility_program = Pubkey::default();
+
+    // KYC / age gate (see set_kyc_requirement)
+    campaign.kyc_attestation_program = Pubkey::default();
+    campaign.kyc_credential = Pubkey::default();
+    campaign.kyc_schema = Pubkey::default();
+
+    // Claim window of the target wallet (see set_target_claim_deadline)
+    campaign.target_claim_deadline = 0;
+
+    // Whitelist (see set_whitelist_required / whitelist_wallet)
+    campaign.requires_whitelist = false;
+
+    // Claim throttling (see set_claim_rate_limit)
+    campaign.claims_per_slot_limit = 0;
+    campaign.claim_slot = 0;
+    campaign.claims_in_slot = 0;
+
+    // Sybil gate (see set_sybil_gate)
+    campaign.sybil_min_stake_lamports = 0;
+    campaign.sybil_min_wallet_age_epochs = 0;
+
+    // Not cancelled (see cancel_campaign)
+    campaign.cancelled_at = 0;
+
+    // Draft campaigns stay inactive until activate_campaign
+    campaign.draft = draft;
+
+    // Single discount unless phases are set (see set_discount_phases)
+    campaign.discount_phases = Vec::new();
+
+    // First period (see renew_campaign)
+    campaign.period = 0;
+
+    // No delegated managers (see add_manager)
+    campaign.managers = Vec::new();
+
+    // Daily redemption limit (see set_daily_redemption_limit)
+    campaign.max_redemptions_per_day = 0;
+    campaign.redemption_day = 0;
+    campaign.redemptions_today = 0;
+
+    // No off-chain metadata until set (see update_campaign_metadata)
+    campaign.metadata_uri = String::new();
+
+    // Layout version (see migrate_campaign)
+    campaign.version = Campaign::CURRENT_VERSION;
+
+    // Not completed (set by redeem_coupon once every coupon is used)
+    campaign.completed_at = 0;
+
+    // Single product unless a family is set (see set_product_codes)
+    campaign.extra_product_codes = Vec::new();
+
+    // Static max discount unless tapering is enabled (see set_budget_taper)
+    campaign.budget_taper_bps = 0;
+
+    // Not frozen (see freeze_campaign)
+    campaign.frozen = false;
+
+    // Percentage discount unless changed (see set_discount_kind)
+    campaign.discount_kind = DiscountKind::Percentage;
+
+    // Not combinable with other coupons unless enabled (see set_stacking_policy)
+    campaign.stackable = false;
+    campaign.max_stacked_discount_bps = 0;
+
+    // Regular (single-use) coupons unless set (see set_coupon_value)
+    campaign.coupon_value_lamports = 0;
+
+    // Coupon index counter (see issue_coupon)
+    campaign.next_coupon_index = 0;
+
+    // Unlimited, back-to-back transfers unless limited (see set_transfer_limits)
+    campaign.max_transfers = 0;
+    campaign.transfer_cooldown_secs = 0;
+
+    // No coupon accounts yet (see close_campaign)
+    campaign.outstanding_coupons = 0;
+
+    // Analytics helpers
+    campaign.total_purchase_amount = 0;
+    campaign.total_discount_lamports = 0;
+    campaign.last_redeem_timestamp = 0;
+
+    // Initialize vault fields
+    vault.campaign = campaign_key;
+    vault.merchant = merchant;
+    vault.bump = vault_bump;
+    vault.total_deposit = deposit_amount;
+    vault.total_mint_spent = 0;
+    vault.total_service_spent = 0;
+    vault.matched_lamports = 0;
+    vault.version = Vault::CURRENT_VERSION;
+    vault.low_balance_threshold = 0;
+
+    Ok(())
+}
+
 #[derive(Accounts)]
 #[instruction(campaign_id: u64)]
 pub struct CreateCampaign<'info> {
@@ -156,10 +446,45 @@ pub struct CreateCampaign<'info> {
     )]
     pub vault: Account<'info, Vault>,
 
+    /// CHECK: Tombstone PDA of a previously closed campaign with this id;
+    /// must not exist (see `close_campaign`).
+    #[account(
+        seeds = [
+            b"closed_campaign",
+            campaign.key().as_ref(),
+        ],
+        bump,
+        constraint = closed_campaign.data_is_empty() @ PromoError::CampaignIdRetired
+    )]
+    pub closed_campaign: UncheckedAccount<'info>,
+
 
     /// Merchant funding the campaign.
     #[account(mut)]
     pub merchant: Signer<'info>,
 
+    /// Registry of the category codes campaigns can use.
+    #[account(
+        seeds = [b"category_registry"],
+        bump
+    )]
+    pub category_registry: Box<Account<'info, CategoryRegistry>>,
+
+    /// Profile of the merchant (see `register_merchant`).
+    #[account(
+        seeds = [
+            b"merchant_profile",
+            merchant.key().as_ref(),
+        ],
+        bump
+    )]
+    pub merchant_profile: Account<'info, MerchantProfile>,
+
+    /// Optional reusable audience segment restricting coupon recipients.
+    pub segment: Option<Account<'info, Segment>>,
+
+    /// Optional targeting template whose parameters are copied into the campaign.
+    pub template: Option<Account<'info, TargetingTemplate>>,
+
     pub system_program: Program<'info, System>,
 }
\ No newline at end of file
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::create_program_account;
+
+use super::create_campaign::init_campaign;
+
+/// Merchant creates and funds several campaigns atomically.
+///
+/// `remaining_accounts` is a flat list of `(campaign, vault, closed_campaign)`
+/// tuples, one per entry of `campaigns` and in the same order:
+/// - `campaign`: the campaign PDA `[b"campaign", merchant, campaign_id]` (writable, uninitialized)
+/// - `vault`: the vault PDA `[b"vault", campaign]` (writable, uninitialized)
+/// - `closed_campaign`: the tombstone PDA `[b"closed_campaign", campaign]`,
+///   which must not exist (the id wasn't used by a closed campaign)
+///
+/// Every entry is validated and initialized exactly like `create_campaign`
+/// (without segment / template, which are set per campaign afterwards with
+/// `update_campaign_targeting` and the `set_*` instructions). At most
+/// `Campaign::MAX_BATCH_CREATE` campaigns per call.
+pub fn create_campaigns_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CreateCampaignsBatch<'info>>,
+    campaigns: Vec<CampaignParams>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let merchant = &ctx.accounts.merchant;
+    let system_program = &ctx.accounts.system_program;
+    let remaining = ctx.remaining_accounts;
+
+    require!(
+        !campaigns.is_empty()
+            && campaigns.len() <= Campaign::MAX_BATCH_CREATE
+            && remaining.len() == campaigns.len() * 3,
+        PromoError::InvalidBatch
+    );
+
+    for (params, accounts) in campaigns.into_iter().zip(remaining.chunks(3)) {
+        let campaign_info = &accounts[0];
+        let vault_info = &accounts[1];
+        let closed_campaign_info = &accounts[2];
+
+        let merchant_key = merchant.key();
+        let campaign_id_bytes = params.campaign_id.to_le_bytes();
+        let (campaign_key, campaign_bump) = Pubkey::find_program_address(
+            &[b"campaign", merchant_key.as_ref(), &campaign_id_bytes],
+            &crate::ID,
+        );
+        let (vault_key, vault_bump) =
+            Pubkey::find_program_address(&[b"vault", campaign_key.as_ref()], &crate::ID);
+        require_keys_eq!(campaign_info.key(), campaign_key, PromoError::InvalidBatch);
+        require_keys_eq!(vault_info.key(), vault_key, PromoError::InvalidBatch);
+        let (closed_campaign_key, _) = Pubkey::find_program_address(
+            &[b"closed_campaign", campaign_key.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            closed_campaign_info.key(),
+            closed_campaign_key,
+            PromoError::InvalidBatch
+        );
+        require!(
+            closed_campaign_info.data_is_empty(),
+            PromoError::CampaignIdRetired
+        );
+
+        create_program_account(
+            merchant,
+            campaign_info,
+            8 + Campaign::SIZE,
+            &[
+                b"campaign",
+                merchant_key.as_ref(),
+                &campaign_id_bytes,
+                &[campaign_bump],
+            ],
+            system_program,
+        )?;
+        create_program_account(
+            merchant,
+            vault_info,
+            8 + Vault::SIZE,
+            &[b"vault", campaign_key.as_ref(), &[vault_bump]],
+            system_program,
+        )?;
+
+        let mut campaign = Account::<Campaign>::try_from_unchecked(campaign_info)?;
+        let mut vault = Account::<Vault>::try_from_unchecked(vault_info)?;
+        let deposit_amount = params.deposit_amount;
+
+        init_campaign(
+            &mut campaign,
+            campaign_key,
+            &mut vault,
+            vault_bump,
+            config,
+            &ctx.accounts.category_registry,
+            merchant_key,
+            params,
+        )?;
+
+        // Fund the vault
+        let cpi_accounts = system_program::Transfer {
+            from: merchant.to_account_info(),
+            to: vault_info.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, deposit_amount)?;
+
+        campaign.exit(&crate::ID)?;
+        vault.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// Create a batch of campaigns; campaign / vault / tombstone PDAs are passed via `remaining_accounts`.
+#[derive(Accounts)]
+pub struct CreateCampaignsBatch<'info> {
+    /// Global config – defines policy for campaigns (including max_resale_bps).
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Merchant funding every campaign of the batch.
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    /// Registry of the category codes campaigns can use.
+    #[account(
+        seeds = [b"category_registry"],
+        bump
+    )]
+    pub category_registry: Box<Account<'info, CategoryRegistry>>,
+
+    /// Profile of the merchant (see `register_merchant`).
+    #[account(
+        seeds = [
+            b"merchant_profile",
+            merchant.key().as_ref(),
+        ],
+        bump
+    )]
+    pub merchant_profile: Account<'info, MerchantProfile>,
+
+    pub system_program: Program<'info, System>,
+}
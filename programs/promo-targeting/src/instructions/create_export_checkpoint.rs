@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant creates the analytics export checkpoint of a campaign and names
+/// the `exporter` key (e.g. the ETL service) allowed to advance it.
+pub fn create_export_checkpoint(
+    ctx: Context<CreateExportCheckpoint>,
+    exporter: Pubkey,
+) -> Result<()> {
+    let checkpoint = &mut ctx.accounts.checkpoint;
+    checkpoint.campaign = ctx.accounts.campaign.key();
+    checkpoint.exporter = exporter;
+    checkpoint.last_sequence = 0;
+    checkpoint.updated_at = Clock::get()?.unix_timestamp;
+    checkpoint.bump = ctx.bumps.checkpoint;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateExportCheckpoint<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Checkpoint PDA. One PDA per campaign.
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + ExportCheckpoint::SIZE,
+        seeds = [
+            b"export",
+            campaign.key().as_ref(),
+        ],
+        bump
+    )]
+    pub checkpoint: Account<'info, ExportCheckpoint>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
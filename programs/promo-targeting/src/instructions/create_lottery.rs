@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant adds a prize drawing to a campaign and funds its pot.
+///
+/// - Every coupon issued in the campaign period of a draw is a ticket; the
+///   randomness oracle snapshots them when it commits the draw (`commit_draw`).
+/// - `prize_lamports * total_winners` moves from the merchant to the lottery
+///   PDA, which holds the pot until `draw_winner` pays it out.
+/// - `randomness_oracle` commits to and reveals the secret of each draw
+///   (`commit_draw` / `draw_winner`).
+pub fn create_lottery(
+    ctx: Context<CreateLottery>,
+    randomness_oracle: Pubkey,
+    prize_lamports: u64,
+    total_winners: u8,
+) -> Result<()> {
+    require!(prize_lamports > 0, PromoError::InvalidLottery);
+    require!(total_winners > 0, PromoError::InvalidLottery);
+    require!(
+        randomness_oracle != Pubkey::default(),
+        PromoError::InvalidLottery
+    );
+
+    let pot = prize_lamports
+        .checked_mul(total_winners as u64)
+        .ok_or(PromoError::Overflow)?;
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.merchant.to_account_info(),
+        to: ctx.accounts.lottery.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, pot)?;
+
+    let lottery = &mut ctx.accounts.lottery;
+    lottery.campaign = ctx.accounts.campaign.key();
+    lottery.randomness_oracle = randomness_oracle;
+    lottery.prize_lamports = prize_lamports;
+    lottery.total_winners = total_winners;
+    lottery.winners_drawn = 0;
+    lottery.bump = ctx.bumps.lottery;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateLottery<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Lottery PDA. One PDA per campaign.
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + Lottery::SIZE,
+        seeds = [
+            b"lottery",
+            campaign.key().as_ref(),
+        ],
+        bump
+    )]
+    pub lottery: Account<'info, Lottery>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
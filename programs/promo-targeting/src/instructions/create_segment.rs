@@ -0,0 +1,56 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant creates a reusable audience segment.
+///
+/// A segment holds a small explicit wallet list and/or the Merkle root of a
+/// larger wallet tree. Campaigns reference it at creation, so the same
+/// audience does not have to be re-uploaded for every promo.
+pub fn create_segment(
+    ctx: Context<CreateSegment>,
+    segment_id: u64,
+    name: String,
+    merkle_root: [u8; 32],
+    wallets: Vec<Pubkey>,
+) -> Result<()> {
+    require!(name.len() <= Segment::MAX_NAME_LEN, PromoError::NameTooLong);
+    require!(
+        wallets.len() <= Segment::MAX_WALLETS,
+        PromoError::TooManySegmentWallets
+    );
+
+    let segment = &mut ctx.accounts.segment;
+    segment.merchant = ctx.accounts.merchant.key();
+    segment.segment_id = segment_id;
+    segment.name = name;
+    segment.merkle_root = merkle_root;
+    segment.wallets = wallets;
+    segment.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(segment_id: u64)]
+pub struct CreateSegment<'info> {
+    /// Segment PDA. One PDA per (merchant, segment_id).
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + Segment::SIZE,
+        seeds = [
+            b"segment",
+            merchant.key().as_ref(),
+            &segment_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub segment: Account<'info, Segment>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant creates a reusable targeting template.
+///
+/// The template bundles the gating parameters (audience segment, token /
+/// collection gates, region, KYC, ...) so they can be applied to a new
+/// campaign by passing the template to `create_campaign`.
+pub fn create_targeting_template(
+    ctx: Context<CreateTargetingTemplate>,
+    template_id: u64,
+    params: TargetingParams,
+) -> Result<()> {
+    params.validate()?;
+
+    let merchant = ctx.accounts.merchant.key();
+    let template = &mut ctx.accounts.template;
+    template.merchant = merchant;
+    template.template_id = template_id;
+    template.segment = match &ctx.accounts.segment {
+        Some(segment) => {
+            require_keys_eq!(segment.merchant, merchant, PromoError::InvalidSegment);
+            segment.key()
+        }
+        None => Pubkey::default(),
+    };
+    template.params = params;
+    template.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(template_id: u64)]
+pub struct CreateTargetingTemplate<'info> {
+    /// Template PDA. One PDA per (merchant, template_id).
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + TargetingTemplate::SIZE,
+        seeds = [
+            b"targeting_template",
+            merchant.key().as_ref(),
+            &template_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub template: Account<'info, TargetingTemplate>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    /// Audience segment to include (must belong to the merchant); omit for none.
+    pub segment: Option<Account<'info, Segment>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin deprecates a category code: new campaigns can't use it anymore,
+/// existing campaigns are unaffected.
+pub fn deprecate_category(ctx: Context<DeprecateCategory>, code: u16) -> Result<()> {
+    let entry = ctx
+        .accounts
+        .category_registry
+        .categories
+        .iter_mut()
+        .find(|entry| entry.code == code)
+        .ok_or(PromoError::UnknownCategory)?;
+
+    entry.deprecated = true;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeprecateCategory<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"category_registry"],
+        bump
+    )]
+    pub category_registry: Account<'info, CategoryRegistry>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Randomness oracle reveals the secret of the pending draw (see
+/// `commit_draw`), drawing the next lottery winner and paying the prize.
+///
+/// - `secret` must hash to the commitment, and the slot hash of the
+///   commitment must still be in the `SlotHashes` sysvar.
+/// - The winning position in the ticket snapshot is
+///   `sha256(secret || commitment slot hash || lottery || winners_drawn)`
+///   (first 8 bytes, little-endian) modulo `ticket_count`.
+/// - `coupon_index` and `ticket_proof` prove the ticket at that position
+///   against `ticket_root`.
+/// - `ticket` must be that coupon, issued in the snapshot's campaign period,
+///   or its redemption receipt if the coupon was already redeemed; its
+///   holder receives `prize_lamports`.
+/// - The same ticket can win more than one draw.
+pub fn draw_winner(
+    ctx: Context<DrawWinner>,
+    secret: [u8; 32],
+    coupon_index: u64,
+    ticket_proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let lottery = &mut ctx.accounts.lottery;
+    let draw_commitment = &ctx.accounts.draw_commitment;
+
+    require!(
+        lottery.winners_drawn < lottery.total_winners,
+        PromoError::LotteryComplete
+    );
+    require!(
+        draw_commitment.draw_number == lottery.winners_drawn
+            && hashv(&[&secret]).to_bytes() == draw_commitment.commitment,
+        PromoError::InvalidDrawReveal
+    );
+    require!(
+        Clock::get()?.slot > draw_commitment.slot,
+        PromoError::DrawSlotHashUnavailable
+    );
+    let commit_slot_hash = slot_hash(&ctx.accounts.slot_hashes, draw_commitment.slot)?
+        .ok_or(PromoError::DrawSlotHashUnavailable)?;
+
+    let seed = hashv(&[
+        &secret,
+        &commit_slot_hash,
+        lottery.key().as_ref(),
+        &[lottery.winners_drawn],
+    ])
+    .to_bytes();
+    let mut draw = [0u8; 8];
+    draw.copy_from_slice(&seed[..8]);
+    let position = (u64::from_le_bytes(draw) % draw_commitment.ticket_count as u64) as u32;
+    require!(
+        verify_merkle_leaf(
+            draw_ticket_leaf(position, coupon_index),
+            &ticket_proof,
+            &draw_commitment.ticket_root,
+        ),
+        PromoError::InvalidLotteryWinner
+    );
+
+    let (ticket_campaign, ticket_index, holder, ticket_period) =
+        coupon_participant(&ctx.accounts.ticket)?.ok_or(PromoError::InvalidLotteryWinner)?;
+    require_keys_eq!(ticket_campaign, campaign.key(), PromoError::InvalidLotteryWinner);
+    require!(
+        ticket_index == coupon_index && ticket_period == draw_commitment.period,
+        PromoError::InvalidLotteryWinner
+    );
+    require_keys_eq!(holder, ctx.accounts.winner.key(), PromoError::InvalidLotteryWinner);
+
+    transfer_lamports(
+        &lottery.to_account_info(),
+        &ctx.accounts.winner.to_account_info(),
+        lottery.prize_lamports,
+    )?;
+    lottery.winners_drawn += 1;
+
+    emit!(LotteryWinnerDrawn {
+        campaign: campaign.key(),
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        coupon_index,
+        winner: holder,
+        prize_lamports: lottery.prize_lamports,
+        draw_number: lottery.winners_drawn,
+    });
+
+    Ok(())
+}
+
+/// Event emitted for every lottery draw.
+#[event]
+pub struct LotteryWinnerDrawn {
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub coupon_index: u64,
+    pub winner: Pubkey,
+    pub prize_lamports: u64,
+    pub draw_number: u8,
+}
+
+#[derive(Accounts)]
+pub struct DrawWinner<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"lottery",
+            campaign.key().as_ref(),
+        ],
+        bump = lottery.bump,
+        has_one = campaign,
+        has_one = randomness_oracle @ PromoError::NotRandomnessOracle
+    )]
+    pub lottery: Account<'info, Lottery>,
+
+    /// Pending commitment of this draw, closed back to the oracle.
+    #[account(
+        mut,
+        seeds = [
+            b"draw_commitment",
+            lottery.key().as_ref(),
+        ],
+        bump,
+        has_one = lottery,
+        close = randomness_oracle
+    )]
+    pub draw_commitment: Account<'info, DrawCommitment>,
+
+    /// CHECK: Winning coupon or redemption receipt, decoded in the handler.
+    pub ticket: UncheckedAccount<'info>,
+
+    /// CHECK: Ticket holder receiving the prize (checked against `ticket`).
+    #[account(mut)]
+    pub winner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub randomness_oracle: Signer<'info>,
+
+    /// CHECK: SlotHashes sysvar, read in place by `slot_hash`.
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+}
@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Admin executes a published emergency withdrawal once the timelock elapsed.
+///
+/// - Moves `proposal.amount` lamports from the vault to `proposal.destination`,
+///   never below the vault's rent-exempt minimum.
+/// - The withdrawal is shared between the merchant deposit and the platform
+///   match pro-rata (as in `close_campaign_vault`): the match share goes back
+///   to the matching pool, and `total_deposit` / `matched_lamports` are
+///   reduced accordingly.
+/// - The proposal is closed and its rent returns to the admin.
+pub fn execute_emergency_withdrawal(ctx: Context<ExecuteEmergencyWithdrawal>) -> Result<()> {
+    let proposal = &ctx.accounts.proposal;
+    let vault = &mut ctx.accounts.vault;
+    let vault_info = vault.to_account_info();
+
+    let clock = Clock::get()?;
+    require!(
+        clock.unix_timestamp >= proposal.executable_at,
+        PromoError::TimelockNotElapsed
+    );
+
+    let rent_floor = Rent::get()?.minimum_balance(vault_info.data_len());
+    let available = vault_info.lamports().saturating_sub(rent_floor);
+    require!(
+        proposal.amount <= available,
+        PromoError::InsufficientVaultBalance
+    );
+
+    // Pro-rata share of the platform match, returned to the pool
+    let matched = vault.matched_lamports;
+    let match_share = if matched > 0 {
+        let funded = vault
+            .total_deposit
+            .checked_add(matched)
+            .ok_or(PromoError::Overflow)?;
+        let share = (proposal.amount as u128)
+            .checked_mul(matched as u128)
+            .ok_or(PromoError::Overflow)?
+            / funded as u128;
+        (share as u64).min(matched)
+    } else {
+        0
+    };
+    if match_share > 0 {
+        let pool = ctx
+            .accounts
+            .matching_pool
+            .as_mut()
+            .ok_or(PromoError::MatchingPoolRequired)?;
+        transfer_lamports(&vault_info, &pool.to_account_info(), match_share)?;
+        pool.total_matched = pool.total_matched.saturating_sub(match_share);
+    }
+
+    let deposit_share = proposal.amount - match_share;
+    transfer_lamports(
+        &vault_info,
+        &ctx.accounts.destination.to_account_info(),
+        deposit_share,
+    )?;
+
+    vault.matched_lamports = matched - match_share;
+    vault.total_deposit = vault.total_deposit.saturating_sub(deposit_share);
+
+    emit!(EmergencyWithdrawalExecuted {
+        vault: proposal.vault,
+        destination: proposal.destination,
+        amount: proposal.amount,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when an emergency withdrawal is executed.
+#[event]
+pub struct EmergencyWithdrawalExecuted {
+    pub vault: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteEmergencyWithdrawal<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"emergency",
+            vault.key().as_ref(),
+        ],
+        bump = proposal.bump,
+        has_one = vault,
+        has_one = destination,
+        close = admin
+    )]
+    pub proposal: Account<'info, EmergencyProposal>,
+
+    /// CHECK: Destination recorded in the proposal (validated by `has_one`).
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    /// Matching pool receiving the match share. Required only when
+    /// `vault.matched_lamports > 0`.
+    #[account(
+        mut,
+        seeds = [b"matching_pool"],
+        bump = matching_pool.bump
+    )]
+    pub matching_pool: Option<Account<'info, MatchingPool>>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
@@ -5,29 +5,35 @@ use crate::states::*;
 
     /// Expire (burn) a coupon after campaign expiration.
     ///
-    /// - Can only be called by the merchant that owns the campaign.
-    /// - Campaign must be expired.
+    /// - Can only be called by the campaign merchant or a delegated manager.
+    /// - Campaign must be expired (by time, or budget exhausted).
     /// - Coupon must belong to this campaign.
     /// - Coupon must not be listed.
     /// - Coupon is closed and rent is returned to the merchant.
     pub fn expire_coupon(ctx: Context<ExpireCoupon>) -> Result<()> {
-        let campaign = &ctx.accounts.campaign;
+        let campaign = &mut ctx.accounts.campaign;
         let coupon = &ctx.accounts.coupon;
-        let merchant = &ctx.accounts.merchant;
 
-        // Campaign must belong to this merchant
-        require_keys_eq!(campaign.merchant, merchant.key(), PromoError::NotMerchant);
+        // Merchant or delegated manager
+        require!(
+            campaign.is_operator(&ctx.accounts.authority.key()),
+            PromoError::NotMerchant
+        );
 
         // Campaign must be expired
         let clock = Clock::get()?;
         require!(
-            clock.unix_timestamp > campaign.expiration_timestamp,
+            campaign.is_expired(clock.unix_timestamp),
             PromoError::CampaignNotExpired
         );
 
         // Coupon must not be listed at expiration cleanup
         require!(!coupon.listed, PromoError::CouponListed);
 
+        // Saturating: campaigns migrated from a layout without the counter
+        // may undercount coupons of earlier periods
+        campaign.outstanding_coupons = campaign.outstanding_coupons.saturating_sub(1);
+
         // We allow expiring both used and unused coupons here.
         // The actual close is handled by `close = merchant` in the accounts struct.
         Ok(())
@@ -37,7 +43,7 @@ use crate::states::*;
     /// The coupon account is closed and rent is returned to the merchant.
     #[derive(Accounts)]
 pub struct ExpireCoupon<'info> {
-    #[account(has_one = merchant)]
+    #[account(mut, has_one = merchant)]
     pub campaign: Account<'info, Campaign>,
 
     #[account(
@@ -48,6 +54,11 @@ pub struct ExpireCoupon<'info> {
     pub coupon: Account<'info, Coupon>,
 
 
+    /// CHECK: Campaign merchant receiving the coupon rent.
     #[account(mut)]
-    pub merchant: Signer<'info>,
+    pub merchant: UncheckedAccount<'info>,
+
+
+    /// Campaign merchant or delegated manager.
+    pub authority: Signer<'info>,
 }
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Expire (burn) many coupons of an expired campaign in one instruction.
+///
+/// `remaining_accounts` is a flat list of writable coupon accounts. Each is
+/// validated exactly like `expire_coupon` (belongs to `campaign`, not
+/// listed) and closed, with its rent returned to the merchant.
+pub fn expire_coupons_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ExpireCouponsBatch<'info>>,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let merchant = ctx.accounts.merchant.to_account_info();
+    let remaining = ctx.remaining_accounts;
+
+    // Merchant or delegated manager
+    require!(
+        campaign.is_operator(&ctx.accounts.authority.key()),
+        PromoError::NotMerchant
+    );
+
+    // Campaign must be expired
+    let clock = Clock::get()?;
+    require!(
+        campaign.is_expired(clock.unix_timestamp),
+        PromoError::CampaignNotExpired
+    );
+
+    require!(!remaining.is_empty(), PromoError::InvalidBatch);
+
+    for coupon_info in remaining {
+        let coupon = Account::<Coupon>::try_from(coupon_info)?;
+
+        require_keys_eq!(
+            coupon.campaign,
+            campaign.key(),
+            PromoError::InvalidCouponCampaign
+        );
+        require!(!coupon.listed, PromoError::CouponListed);
+
+        coupon.close(merchant.clone())?;
+        campaign.outstanding_coupons = campaign.outstanding_coupons.saturating_sub(1);
+    }
+
+    Ok(())
+}
+
+/// Expire a batch of coupons; coupons are passed via `remaining_accounts`.
+#[derive(Accounts)]
+pub struct ExpireCouponsBatch<'info> {
+    #[account(mut, has_one = merchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// CHECK: Campaign merchant receiving the coupon rent.
+    #[account(mut)]
+    pub merchant: UncheckedAccount<'info>,
+
+    /// Campaign merchant or delegated manager.
+    pub authority: Signer<'info>,
+}
@@ -0,0 +1,104 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Merchant pushes `expiration_timestamp` later (never earlier), optionally
+/// topping up the vault in the same instruction.
+///
+/// - Only live campaigns can be extended (not expired, exhausted or cancelled).
+/// - After the top-up, the vault must still cover the worst-case obligations
+///   of the campaign (`vault_obligations`), so an underfunded campaign has
+///   to be topped up to be extended.
+/// - `top_up_amount` is added to `vault.total_deposit`.
+pub fn extend_campaign(
+    ctx: Context<ExtendCampaign>,
+    new_expiration_timestamp: i64,
+    top_up_amount: u64,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let vault = &mut ctx.accounts.vault;
+
+    let clock = Clock::get()?;
+    require!(
+        !campaign.is_expired(clock.unix_timestamp),
+        PromoError::CampaignExpired
+    );
+    require!(
+        new_expiration_timestamp > campaign.expiration_timestamp,
+        PromoError::InvalidExpirationExtension
+    );
+
+    if top_up_amount > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.merchant.to_account_info(),
+            to: vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, top_up_amount)?;
+
+        vault.total_deposit = vault
+            .total_deposit
+            .checked_add(top_up_amount)
+            .ok_or(PromoError::Overflow)?;
+    }
+
+    require!(
+        vault_spendable(&vault.to_account_info())? >= vault_obligations(campaign)?,
+        PromoError::InsufficientVaultBalance
+    );
+
+    let previous_expiration = campaign.expiration_timestamp;
+    campaign.expiration_timestamp = new_expiration_timestamp;
+
+    emit!(CampaignExtended {
+        campaign: campaign.key(),
+        merchant: campaign.merchant,
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        previous_expiration,
+        new_expiration: new_expiration_timestamp,
+        top_up_amount,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when a merchant extends a campaign.
+#[event]
+pub struct CampaignExtended {
+    pub campaign: Pubkey,
+    pub merchant: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub previous_expiration: i64,
+    pub new_expiration: i64,
+    pub top_up_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct ExtendCampaign<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Merchant extending the campaign and paying the optional top-up.
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,194 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Ticket holder opts in and turns their `ClaimTicket` into a coupon.
+///
+/// The user's signature is the explicit opt-in; they pay the coupon rent,
+/// the ticket is closed back to whoever paid for it, and the mint cost is
+/// paid by the vault exactly as in `mint_coupon`. Like vouchers, the ticket
+/// stands in for the targeting rules; excluded and blocked wallets, the KYC
+/// requirement and the per-wallet limit still apply.
+pub fn finalize_claim(
+    ctx: Context<FinalizeClaim>,
+    campaign_id: u64,
+    coupon_index: u64,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let user = ctx.accounts.user.key();
+
+    require!(
+        campaign.campaign_id == campaign_id,
+        PromoError::InvalidCampaignId
+    );
+
+    require_next_coupon_index(campaign, coupon_index)?;
+
+    // Anti-bot throttling of self-service claims
+    throttle_claim(campaign)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(!campaign.is_expired(now), PromoError::CampaignExpired);
+
+    require!(
+        !campaign.excluded_wallets.contains(&user),
+        PromoError::WalletExcluded
+    );
+    require_not_blocked(&ctx.accounts.blocked_wallet)?;
+    verify_kyc_attestation(
+        campaign,
+        ctx.accounts.kyc_attestation.as_ref().map(|attestation| attestation.as_ref()),
+        &user,
+        now,
+    )?;
+
+    record_wallet_coupon(campaign, ctx.accounts.wallet_coupon_count.as_mut(), &user)?;
+
+    record_category_claim(
+        &ctx.accounts.config,
+        campaign,
+        ctx.accounts.category_claim_count.as_mut(),
+        &user,
+    )?;
+
+    issue_coupon(
+        campaign,
+        &mut ctx.accounts.vault,
+        &mut ctx.accounts.coupon,
+        coupon_index,
+        user,
+        &ctx.accounts.platform_treasury.to_account_info(),
+        &ctx.accounts.config,
+    )?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64, coupon_index: u64)]
+pub struct FinalizeClaim<'info> {
+    /// Global config – fee switches and platform treasury.
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Box<Account<'info, GlobalConfig>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"campaign",
+            campaign.creator.as_ref(),
+            &campaign_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub campaign: Box<Account<'info, Campaign>>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Box<Account<'info, Vault>>,
+
+    /// Ticket being redeemed; closed to its payer.
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer @ PromoError::InvalidClaimTicket,
+        seeds = [
+            b"ticket",
+            campaign.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub ticket: Box<Account<'info, ClaimTicket>>,
+
+    /// CHECK: Original payer of the ticket rent (checked via `has_one`).
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// Coupon PDA. One PDA per (campaign, coupon_index); `coupon_index` must
+    /// be `campaign.next_coupon_index`.
+    #[account(
+        init,
+        payer = user,
+        space = 8 + Coupon::SIZE,
+        seeds = [
+            b"coupon",
+            campaign.key().as_ref(),
+            &coupon_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub coupon: Box<Account<'info, Coupon>>,
+
+    /// Ticket wallet, opting in and paying the coupon rent.
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Blocklist PDA of the user (usually uninitialized); the user
+    /// is rejected if it exists.
+    #[account(
+        seeds = [
+            b"blocked",
+            campaign.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub blocked_wallet: UncheckedAccount<'info>,
+
+    /// CHECK: KYC / age attestation of the user (required only if
+    /// `campaign.kyc_attestation_program` is set); verified in `verify_kyc_attestation`.
+    pub kyc_attestation: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Platform treasury receiving the mint cost; must match `config.platform_treasury`.
+    #[account(
+        mut,
+        constraint = config.platform_treasury != Pubkey::default()
+            && platform_treasury.key() == config.platform_treasury
+            @ PromoError::InvalidPlatformTreasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// Coupons already received by the user (required only if
+    /// `campaign.max_coupons_per_wallet` is set).
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + WalletCouponCount::SIZE,
+        seeds = [
+            b"wallet_coupons",
+            campaign.key().as_ref(),
+            user.key().as_ref(),
+            &campaign.period.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub wallet_coupon_count: Option<Account<'info, WalletCouponCount>>,
+
+    /// Coupons received by the user in the campaign category this epoch
+    /// (required only if `config.category_claims_per_epoch` is set).
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + CategoryClaimCount::SIZE,
+        seeds = [
+            b"category_claims",
+            user.key().as_ref(),
+            &campaign.category_code.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub category_claim_count: Option<Account<'info, CategoryClaimCount>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin permanently freezes a subset of the fee parameters.
+///
+/// `fields` is a bitmask of `GlobalConfig::FINALIZE_*` flags:
+/// - `FINALIZE_MAX_RESALE_BPS`: `max_resale_bps`
+/// - `FINALIZE_SERVICE_FEE_BPS`: `service_fee_bps`
+/// - `FINALIZE_FEE_SWITCHES`: the `set_fee_switches` flags
+/// - `FINALIZE_ROUNDING_MODE`: `rounding_mode`
+/// - `FINALIZE_PLATFORM_TREASURY`: `platform_treasury`
+/// - `FINALIZE_MARKETPLACE_FEE_BPS`: `marketplace_fee_bps`
+///
+/// Irreversible: bits are only ever added, never cleared. Gives merchants a
+/// guarantee that frozen fees can't be raised retroactively.
+pub fn finalize_config(ctx: Context<FinalizeConfig>, fields: u8) -> Result<()> {
+    require!(
+        fields != 0 && fields & !GlobalConfig::FINALIZE_ALL == 0,
+        PromoError::InvalidFinalizeFields
+    );
+
+    let config = &mut ctx.accounts.config;
+    config.finalized_fields |= fields;
+
+    emit!(ConfigFinalized {
+        admin: ctx.accounts.admin.key(),
+        fields,
+        finalized_fields: config.finalized_fields,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
+
+#[event]
+pub struct ConfigFinalized {
+    pub admin: Pubkey,
+    pub fields: u8,
+    pub finalized_fields: u8,
+}
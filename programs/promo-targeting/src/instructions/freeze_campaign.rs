@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin freezes a flagged campaign (compliance / fraud handling).
+///
+/// Minting, claiming, redemption, listing and purchases of its coupons are
+/// blocked until `unfreeze_campaign`. The merchant keeps access to the
+/// vault: excess withdrawal, and `close_campaign_vault` once expired.
+pub fn freeze_campaign(ctx: Context<FreezeCampaign>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    campaign.frozen = true;
+
+    emit!(CampaignFreezeChanged {
+        campaign: campaign.key(),
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        merchant: campaign.merchant,
+        frozen: true,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when the admin freezes or unfreezes a campaign.
+#[event]
+pub struct CampaignFreezeChanged {
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub merchant: Pubkey,
+    pub frozen: bool,
+}
+
+#[derive(Accounts)]
+pub struct FreezeCampaign<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    pub admin: Signer<'info>,
+}
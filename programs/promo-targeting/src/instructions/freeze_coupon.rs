@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant freezes a single suspicious coupon while investigating fraud.
+///
+/// Redemption, transfers (direct, two-step, sales and swaps) and new
+/// listings of the coupon are blocked until `unfreeze_coupon`; the rest of
+/// the campaign is unaffected (see `freeze_campaign` for the admin-level
+/// campaign freeze).
+pub fn freeze_coupon(ctx: Context<FreezeCoupon>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let coupon = &mut ctx.accounts.coupon;
+
+    coupon.frozen = true;
+
+    emit!(CouponFreezeChanged {
+        campaign: coupon.campaign,
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        coupon: coupon.key(),
+        coupon_index: coupon.coupon_index,
+        frozen: true,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when a merchant freezes or unfreezes a coupon.
+#[event]
+pub struct CouponFreezeChanged {
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub coupon: Pubkey,
+    pub coupon_index: u64,
+    pub frozen: bool,
+}
+
+#[derive(Accounts)]
+pub struct FreezeCoupon<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        has_one = campaign @ PromoError::InvalidCouponCampaign
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    pub merchant: Signer<'info>,
+}
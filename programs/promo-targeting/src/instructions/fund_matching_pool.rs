@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Deposit lamports into the matching pool.
+///
+/// Anyone can fund the pool; only the admin decides which campaigns are matched.
+pub fn fund_matching_pool(ctx: Context<FundMatchingPool>, amount: u64) -> Result<()> {
+    require!(amount > 0, PromoError::InvalidDepositAmount);
+
+    let cpi_accounts = system_program::Transfer {
+        from: ctx.accounts.funder.to_account_info(),
+        to: ctx.accounts.matching_pool.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+    system_program::transfer(cpi_ctx, amount)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundMatchingPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"matching_pool"],
+        bump = matching_pool.bump
+    )]
+    pub matching_pool: Account<'info, MatchingPool>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
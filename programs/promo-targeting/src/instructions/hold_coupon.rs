@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Owner puts a coupon on a checkout hold until `expiry`.
+///
+/// - While held the coupon cannot be transferred, listed or swapped, so it
+///   can't be resold between cart creation and payment confirmation.
+///   Redemption is still allowed.
+/// - `expiry` must be in the future and at most `Coupon::MAX_HOLD_SECS`
+///   away; the hold lapses on its own, no release instruction is needed.
+/// - Calling again replaces the current hold (e.g. to extend it).
+pub fn hold_coupon(ctx: Context<HoldCoupon>, expiry: i64) -> Result<()> {
+    let coupon = &mut ctx.accounts.coupon;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!coupon.used, PromoError::CouponAlreadyUsed);
+    require!(!coupon.listed, PromoError::CouponListed);
+    require!(
+        expiry > now && expiry - now <= Coupon::MAX_HOLD_SECS,
+        PromoError::InvalidHoldExpiry
+    );
+
+    coupon.held_until = expiry;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct HoldCoupon<'info> {
+    #[account(
+        mut,
+        constraint = coupon.owner == owner.key() @ PromoError::NotCouponOwner
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    pub owner: Signer<'info>,
+}
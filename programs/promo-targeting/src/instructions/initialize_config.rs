@@ -8,18 +8,34 @@ use crate::states::*;
     /// This should be called once by the protocol owner (admin) after deploy.
     /// - `max_resale_bps` defines the maximum percentage (over max_discount_lamports)
     ///   that each campaign can use as `resale_bps` to cap secondary prices.
+    /// - `platform_treasury` is the only account `mint_coupon` / `redeem_coupon`
+    ///   will pay protocol fees to.
     pub fn initialize_config(
         ctx: Context<InitializeConfig>,
         max_resale_bps: u16,
         service_fee_bps: u16,
+        platform_treasury: Pubkey,
     ) -> Result<()> {
         require!(max_resale_bps <= 10_000, PromoError::InvalidBps);
         require!(service_fee_bps <= 10_000, PromoError::InvalidBps);
+        require!(
+            platform_treasury != Pubkey::default(),
+            PromoError::InvalidPlatformTreasury
+        );
 
         let config = &mut ctx.accounts.config;
         config.admin = ctx.accounts.admin.key();
         config.max_resale_bps = max_resale_bps;
         config.service_fee_bps = service_fee_bps;
+        config.pending_admin = Pubkey::default();
+        config.mint_fee_disabled = false;
+        config.redemption_fee_disabled = false;
+        config.finalized_fields = 0;
+        config.rounding_mode = GlobalConfig::ROUNDING_FLOOR;
+        config.platform_treasury = platform_treasury;
+        config.category_claims_per_epoch = 0;
+        config.marketplace_fee_bps = 0;
+        config.marketplace_fee_disabled = false;
 
         Ok(())
     }
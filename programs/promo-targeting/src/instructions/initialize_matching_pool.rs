@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Create the platform matching pool.
+///
+/// - Only the `GlobalConfig.admin` can create it.
+/// - `match_bps` is the share of a merchant deposit that the pool tops up.
+/// - `max_match_lamports` caps the match granted to a single campaign.
+pub fn initialize_matching_pool(
+    ctx: Context<InitializeMatchingPool>,
+    match_bps: u16,
+    max_match_lamports: u64,
+) -> Result<()> {
+    require!(match_bps <= 10_000, PromoError::InvalidBps);
+
+    let pool = &mut ctx.accounts.matching_pool;
+    pool.match_bps = match_bps;
+    pool.max_match_lamports = max_match_lamports;
+    pool.total_matched = 0;
+    pool.bump = ctx.bumps.matching_pool;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeMatchingPool<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Matching pool PDA. Holds the lamports used to match campaign deposits.
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + MatchingPool::SIZE,
+        seeds = [b"matching_pool"],
+        bump
+    )]
+    pub matching_pool: Account<'info, MatchingPool>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant pre-authorizes `wallet` to claim one coupon of the campaign.
+///
+/// Creates a small `ClaimTicket` PDA; the coupon itself is only created when
+/// the wallet opts in by signing `finalize_claim`, which also moves the
+/// coupon rent to the user and refunds the ticket rent to the merchant.
+/// Unclaimed tickets can be closed with `revoke_claim_ticket`.
+pub fn issue_claim_ticket(ctx: Context<IssueClaimTicket>, wallet: Pubkey) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let ticket = &mut ctx.accounts.ticket;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(!campaign.is_expired(now), PromoError::CampaignExpired);
+
+    ticket.campaign = campaign.key();
+    ticket.wallet = wallet;
+    ticket.payer = ctx.accounts.merchant.key();
+    ticket.issued_at = now;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct IssueClaimTicket<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Claim ticket. One PDA per (campaign, wallet).
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + ClaimTicket::SIZE,
+        seeds = [
+            b"ticket",
+            campaign.key().as_ref(),
+            wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub ticket: Account<'info, ClaimTicket>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::states::*;
+
+/// User opts in to an achievement, creating their progress PDA.
+///
+/// The progress account is then passed to `redeem_coupon` (as a remaining
+/// account, after its achievement) to count redemptions.
+pub fn join_achievement(ctx: Context<JoinAchievement>) -> Result<()> {
+    let progress = &mut ctx.accounts.progress;
+    progress.achievement = ctx.accounts.achievement.key();
+    progress.user = ctx.accounts.user.key();
+    progress.redemptions = 0;
+    progress.unlocked_at = 0;
+    progress.bump = ctx.bumps.progress;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct JoinAchievement<'info> {
+    pub achievement: Account<'info, Achievement>,
+
+    /// Progress PDA. One PDA per (achievement, user).
+    #[account(
+        init,
+        payer = user,
+        space = 8 + AchievementProgress::SIZE,
+        seeds = [
+            b"achievement_progress",
+            achievement.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub progress: Account<'info, AchievementProgress>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
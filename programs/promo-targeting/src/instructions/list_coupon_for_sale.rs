@@ -2,19 +2,24 @@ use anchor_lang::prelude::*;
 
 use crate::errors::*;
 use crate::states::*;
+use crate::utils::*;
 
 /// List a coupon for sale on the secondary market.
     ///
     /// - Only the current owner can list.
-    /// - Coupon must not be used.
+    /// - Coupon must not be used or on a checkout hold.
     /// - Caller chooses `sale_price_lamports`, but:
     ///   * must be > 0
     ///   * must be <= campaign.max_discount_lamports
     ///   * must be <= max_allowed, where
-    ///       max_allowed = max_discount_lamports * resale_bps / 10_000
+    ///     `max_allowed = max_discount_lamports * resale_bps / 10_000`
+    ///     (rounded per `campaign.rounding_mode`)
+    /// - Optional `reserved_buyer` makes the listing private: only that wallet
+    ///   can buy it (OTC deals negotiated off-chain cannot be sniped).
     pub fn list_coupon_for_sale(
         ctx: Context<ListCouponForSale>,
         sale_price_lamports: u64,
+        reserved_buyer: Option<Pubkey>,
     ) -> Result<()> {
         let campaign = &ctx.accounts.campaign;
         let coupon = &mut ctx.accounts.coupon;
@@ -26,9 +31,22 @@ use crate::states::*;
         // Cannot list used coupons
         require!(!coupon.used, PromoError::CouponAlreadyUsed);
 
+        // Soulbound coupons can't be sold, coupons of frozen campaigns not for now
+        require!(campaign.transferable, PromoError::CouponNotTransferable);
+        require!(!campaign.frozen, PromoError::CampaignFrozen);
+
         // Prevent double listing
         require!(!coupon.listed, PromoError::CouponAlreadyListed);
 
+        // Cannot list while a checkout hold is active
+        require!(
+            !coupon.is_held(&Clock::get()?),
+            PromoError::CouponOnHold
+        );
+
+        // A coupon that can't change hands (anymore, or yet) can't be sold
+        require_transfer_allowed(campaign, coupon, Clock::get()?.unix_timestamp)?;
+
         require!(sale_price_lamports > 0, PromoError::InvalidResalePrice);
 
         // Upper bound: cannot sell the coupon for more than the max discount
@@ -38,11 +56,11 @@ use crate::states::*;
         );
 
         // Additional bound: apply campaign-level resale_bps (capped by global config)
-        let max_allowed = campaign
-            .max_discount_lamports
-            .checked_mul(campaign.resale_bps as u64)
-            .ok_or(PromoError::Overflow)?
-            / 10_000;
+        let max_allowed = apply_bps(
+            campaign.max_discount_lamports,
+            campaign.resale_bps as u64,
+            campaign.rounding_mode,
+        )?;
 
         require!(
             sale_price_lamports <= max_allowed,
@@ -51,6 +69,7 @@ use crate::states::*;
 
         coupon.listed = true;
         coupon.sale_price_lamports = sale_price_lamports;
+        coupon.reserved_buyer = reserved_buyer.unwrap_or_default();
 
         Ok(())
     }
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Top up a qualifying campaign vault from the matching pool.
+///
+/// - Only the platform admin (`GlobalConfig.admin`) decides which campaigns qualify.
+/// - Match = `vault.total_deposit * match_bps / 10_000`, capped by
+///   `max_match_lamports` and by the idle balance of the pool.
+/// - A campaign can only be matched once, and only while it is live.
+/// - The match is recorded in `vault.matched_lamports` (separately from
+///   `total_deposit`) so the unspent share returns to the pool at close.
+pub fn match_campaign_deposit(ctx: Context<MatchCampaignDeposit>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let vault = &mut ctx.accounts.vault;
+    let pool = &mut ctx.accounts.matching_pool;
+
+    require!(vault.matched_lamports == 0, PromoError::MatchAlreadyApplied);
+
+    let clock = Clock::get()?;
+    require!(
+        !campaign.is_expired(clock.unix_timestamp),
+        PromoError::CampaignExpired
+    );
+
+    let mut match_amount = vault
+        .total_deposit
+        .checked_mul(pool.match_bps as u64)
+        .ok_or(PromoError::Overflow)?
+        / 10_000;
+    if match_amount > pool.max_match_lamports {
+        match_amount = pool.max_match_lamports;
+    }
+    require!(match_amount > 0, PromoError::InvalidDepositAmount);
+
+    let pool_info = pool.to_account_info();
+    let rent_floor = Rent::get()?.minimum_balance(pool_info.data_len());
+    let available = pool_info.lamports().saturating_sub(rent_floor);
+    require!(
+        match_amount <= available,
+        PromoError::InsufficientMatchingPoolBalance
+    );
+
+    transfer_lamports(&pool_info, &vault.to_account_info(), match_amount)?;
+
+    vault.matched_lamports = match_amount;
+    pool.total_matched = pool
+        .total_matched
+        .checked_add(match_amount)
+        .ok_or(PromoError::Overflow)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MatchCampaignDeposit<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"matching_pool"],
+        bump = matching_pool.bump
+    )]
+    pub matching_pool: Account<'info, MatchingPool>,
+
+    /// Campaign receiving the match.
+    pub campaign: Account<'info, Campaign>,
+
+    /// Vault PDA associated with this campaign.
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,402 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use std::io::Cursor;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Migrate a campaign account to the latest `Campaign` layout.
+///
+/// Fields are only ever appended to `Campaign`. A legacy campaign is decoded
+/// through the structs of its layout version (`CampaignV0` for unversioned
+/// accounts, then the fields each later version appended), so the stale
+/// slack behind shrunk strings / vecs is never read as a newer field; fields
+/// its version doesn't have start at their zero/default value.
+///
+/// Fields whose zero value would change the behaviour of a legacy campaign
+/// are backfilled: `creator` (the PDA seed, the merchant before authority
+/// handoff existed) and `transferable` (coupons could change hands before
+/// soulbound mode).
+///
+/// Callable by the campaign merchant or the platform admin, who pays the
+/// rent of the extra space.
+pub fn migrate_campaign(ctx: Context<MigrateCampaign>) -> Result<()> {
+    let campaign_info = &ctx.accounts.campaign;
+    let authority = ctx.accounts.authority.key();
+
+    const DISCRIMINATOR_LEN: usize = 8;
+
+    // `Campaign::SIZE` of the first layout with `transferable`
+    const TRANSFERABLE_LAYOUT_SIZE: usize = 1131;
+
+    let expected_len = DISCRIMINATOR_LEN + Campaign::SIZE;
+    let legacy_len = campaign_info.data_len();
+
+    // Only the bytes each layout version defines are decoded: the slack after
+    // the serialized struct (left by shrinking strings / vecs) is not zeroed
+    let mut campaign = {
+        let data = campaign_info.try_borrow_data()?;
+        require!(
+            data.starts_with(Campaign::DISCRIMINATOR),
+            PromoError::InvalidCampaignAccount
+        );
+
+        // Unversioned layouts older than the last one are zero-padded
+        let mut buffer = data[DISCRIMINATOR_LEN..].to_vec();
+        if buffer.len() < layout_size(0) {
+            buffer.resize(layout_size(0), 0);
+        }
+        let mut cursor = &buffer[..];
+
+        let v0: CampaignV0 = read(&mut cursor)?;
+        let version: u8 = if legacy_len < DISCRIMINATOR_LEN + layout_size(1) {
+            0
+        } else {
+            read(&mut cursor)?
+        };
+        require!(
+            version <= Campaign::CURRENT_VERSION
+                && legacy_len >= DISCRIMINATOR_LEN + layout_size(version),
+            PromoError::InvalidCampaignAccount
+        );
+
+        // Unversioned fields, every later field at its zero/default value
+        let mut latest = Campaign::DISCRIMINATOR.to_vec();
+        latest.extend(v0.try_to_vec()?);
+        latest.resize(DISCRIMINATOR_LEN + Campaign::SIZE, 0);
+        let mut campaign = Campaign::try_deserialize(&mut &latest[..])
+            .map_err(|_| PromoError::InvalidCampaignAccount)?;
+        campaign.version = version;
+
+        if version >= 2 {
+            let v2: CampaignV2 = read(&mut cursor)?;
+            campaign.completed_at = v2.completed_at;
+        }
+        if version >= 3 {
+            let v3: CampaignV3 = read(&mut cursor)?;
+            campaign.extra_product_codes = v3.extra_product_codes;
+        }
+        if version >= 4 {
+            let v4: CampaignV4 = read(&mut cursor)?;
+            campaign.budget_taper_bps = v4.budget_taper_bps;
+        }
+        if version >= 5 {
+            let v5: CampaignV5 = read(&mut cursor)?;
+            campaign.frozen = v5.frozen;
+        }
+        if version >= 6 {
+            let v6: CampaignV6 = read(&mut cursor)?;
+            campaign.discount_kind = v6.discount_kind;
+        }
+        if version >= 7 {
+            let v7: CampaignV7 = read(&mut cursor)?;
+            campaign.stackable = v7.stackable;
+            campaign.max_stacked_discount_bps = v7.max_stacked_discount_bps;
+        }
+        if version >= 8 {
+            let v8: CampaignV8 = read(&mut cursor)?;
+            campaign.coupon_value_lamports = v8.coupon_value_lamports;
+        }
+        if version >= 9 {
+            let v9: CampaignV9 = read(&mut cursor)?;
+            campaign.next_coupon_index = v9.next_coupon_index;
+        }
+        if version >= 10 {
+            let v10: CampaignV10 = read(&mut cursor)?;
+            campaign.max_transfers = v10.max_transfers;
+        }
+        if version >= 11 {
+            let v11: CampaignV11 = read(&mut cursor)?;
+            campaign.transfer_cooldown_secs = v11.transfer_cooldown_secs;
+        }
+        if version >= 12 {
+            let v12: CampaignV12 = read(&mut cursor)?;
+            campaign.outstanding_coupons = v12.outstanding_coupons;
+        }
+
+        campaign
+    };
+
+    // Campaigns created before `creator` existed were derived from the merchant
+    if campaign.creator == Pubkey::default() {
+        campaign.creator = campaign.merchant;
+    }
+
+    // Must be a campaign PDA of this program
+    let (expected_key, _) = Pubkey::find_program_address(
+        &[
+            b"campaign",
+            campaign.creator.as_ref(),
+            &campaign.campaign_id.to_le_bytes(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(expected_key, campaign_info.key(), PromoError::InvalidCampaignAccount);
+    require!(
+        authority == campaign.merchant || authority == ctx.accounts.config.admin,
+        PromoError::NotMerchant
+    );
+
+    if campaign_info.data_len() < expected_len {
+        // The authority pays the extra rent
+        let rent = Rent::get()?;
+        let diff = rent
+            .minimum_balance(expected_len)
+            .saturating_sub(rent.minimum_balance(campaign_info.data_len()));
+        if diff > 0 {
+            let transfer_accounts = system_program::Transfer {
+                from: ctx.accounts.authority.to_account_info(),
+                to: ctx.accounts.campaign.clone(),
+            };
+            let cpi_ctx =
+                CpiContext::new(ctx.accounts.system_program.to_account_info(), transfer_accounts);
+            system_program::transfer(cpi_ctx, diff)?;
+        }
+
+        campaign_info.resize(expected_len)?;
+    }
+
+    // Unversioned accounts allocated before soulbound mode had transferable coupons
+    if campaign.version == 0 && legacy_len < DISCRIMINATOR_LEN + TRANSFERABLE_LAYOUT_SIZE {
+        campaign.transferable = true;
+    }
+
+    // Before version 9 clients picked every index themselves; start the
+    // counter past the indexes of a sequentially minted campaign
+    if campaign.version < 9 {
+        let issued = (campaign.period as u64 + 1)
+            .checked_mul(campaign.total_coupons as u64)
+            .ok_or(PromoError::Overflow)?;
+        campaign.next_coupon_index = campaign.next_coupon_index.max(issued);
+    }
+
+    // Coupons of the current period still open; those of earlier periods
+    // were never counted before version 12
+    if campaign.version < 12 {
+        campaign.outstanding_coupons = campaign
+            .minted_coupons
+            .saturating_sub(campaign.used_coupons);
+    }
+
+    campaign.version = Campaign::CURRENT_VERSION;
+
+    // Rewrite the whole account (discriminator included) with the latest layout
+    let mut data = campaign_info.try_borrow_mut_data()?;
+    data.fill(0);
+    let mut cursor = Cursor::new(&mut data[..]);
+    campaign.try_serialize(&mut cursor)?;
+
+    Ok(())
+}
+
+/// Decode one legacy struct from the account bytes.
+fn read<T: AnchorDeserialize>(cursor: &mut &[u8]) -> Result<T> {
+    T::deserialize(cursor).map_err(|_| PromoError::InvalidCampaignAccount.into())
+}
+
+/// Bytes appended to the `Campaign` layout by each version (index = version).
+const VERSION_FIELDS_SIZE: [usize; Campaign::CURRENT_VERSION as usize + 1] = [
+    0,
+    1,                                          // version
+    8,                                          // completed_at
+    4 + Campaign::MAX_EXTRA_PRODUCT_CODES * 2,  // extra_product_codes
+    2,                                          // budget_taper_bps
+    1,                                          // frozen
+    DiscountKind::SIZE,                         // discount_kind
+    1 + 2,                                      // stackable, max_stacked_discount_bps
+    8,                                          // coupon_value_lamports
+    8,                                          // next_coupon_index
+    2,                                          // max_transfers
+    4,                                          // transfer_cooldown_secs
+    4,                                          // outstanding_coupons
+];
+
+/// `Campaign::SIZE` of layout `version` (0 = the last unversioned layout).
+fn layout_size(version: u8) -> usize {
+    VERSION_FIELDS_SIZE[version as usize + 1..]
+        .iter()
+        .fold(Campaign::SIZE, |size, fields| size - fields)
+}
+
+/// `Campaign` fields of the unversioned layout, up to `metadata_uri`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+struct CampaignV0 {
+    merchant: Pubkey,
+    campaign_id: u64,
+    discount_bps: u16,
+    service_fee_bps: u16,
+    resale_bps: u16,
+    expiration_timestamp: i64,
+    total_coupons: u32,
+    used_coupons: u32,
+    minted_coupons: u32,
+    mint_cost_lamports: u64,
+    max_discount_lamports: u64,
+    category_code: u16,
+    product_code: u16,
+    campaign_name: String,
+    requires_wallet: bool,
+    target_wallet: Pubkey,
+    total_purchase_amount: u64,
+    total_discount_lamports: u64,
+    last_redeem_timestamp: i64,
+    segment: Pubkey,
+    required_category_code: u16,
+    min_category_redemptions: u8,
+    excluded_wallets: Vec<Pubkey>,
+    prerequisite_campaign: Pubkey,
+    location_oracle: Pubkey,
+    location_region_code: u16,
+    location_max_age_secs: u32,
+    redeem_days_mask: u8,
+    redeem_hours_mask: u32,
+    schedule_utc_offset_mins: i16,
+    first_purchase_only: bool,
+    jurisdiction_code: u16,
+    max_discount_per_user: u64,
+    daily_budget_lamports: u64,
+    budget_day: i64,
+    budget_day_spent: u64,
+    expire_when_budget_exhausted: bool,
+    budget_exhausted_at: i64,
+    payout_wallet: Pubkey,
+    creator: Pubkey,
+    pending_merchant: Pubkey,
+    redemption_verifier: Pubkey,
+    streak_bonus_bps: u16,
+    max_streak_bonus_bps: u16,
+    required_achievement: Pubkey,
+    condition_oracle: Pubkey,
+    condition_feed_id: u64,
+    condition_op: u8,
+    condition_value: i64,
+    reward_mint: Pubkey,
+    reward_amount: u64,
+    gas_rebate_lamports: u64,
+    partner: Pubkey,
+    partner_share_bps: u16,
+    rounding_mode: u8,
+    requires_qr_nonce: bool,
+    receipts_hash: [u8; 32],
+    required_collection: Pubkey,
+    gate_mint: Pubkey,
+    gate_min_amount: u64,
+    max_coupons_per_wallet: u32,
+    region_attestor: Pubkey,
+    region_code: u16,
+    transferable: bool,
+    min_prior_redemptions: u32,
+    required_cnft_collection: Pubkey,
+    sns_parent_domain: Pubkey,
+    eligibility_program: Pubkey,
+    kyc_attestation_program: Pubkey,
+    kyc_credential: Pubkey,
+    kyc_schema: Pubkey,
+    target_claim_deadline: i64,
+    requires_whitelist: bool,
+    claims_per_slot_limit: u16,
+    claim_slot: u64,
+    claims_in_slot: u16,
+    sybil_min_stake_lamports: u64,
+    sybil_min_wallet_age_epochs: u64,
+    cancelled_at: i64,
+    draft: bool,
+    discount_phases: Vec<DiscountPhase>,
+    period: u32,
+    managers: Vec<Pubkey>,
+    max_redemptions_per_day: u32,
+    redemption_day: i64,
+    redemptions_today: u32,
+    metadata_uri: String,
+}
+
+/// Fields appended by layout version 2.
+#[derive(AnchorDeserialize)]
+struct CampaignV2 {
+    completed_at: i64,
+}
+
+/// Fields appended by layout version 3.
+#[derive(AnchorDeserialize)]
+struct CampaignV3 {
+    extra_product_codes: Vec<u16>,
+}
+
+/// Fields appended by layout version 4.
+#[derive(AnchorDeserialize)]
+struct CampaignV4 {
+    budget_taper_bps: u16,
+}
+
+/// Fields appended by layout version 5.
+#[derive(AnchorDeserialize)]
+struct CampaignV5 {
+    frozen: bool,
+}
+
+/// Fields appended by layout version 6.
+#[derive(AnchorDeserialize)]
+struct CampaignV6 {
+    discount_kind: DiscountKind,
+}
+
+/// Fields appended by layout version 7.
+#[derive(AnchorDeserialize)]
+struct CampaignV7 {
+    stackable: bool,
+    max_stacked_discount_bps: u16,
+}
+
+/// Fields appended by layout version 8.
+#[derive(AnchorDeserialize)]
+struct CampaignV8 {
+    coupon_value_lamports: u64,
+}
+
+/// Fields appended by layout version 9.
+#[derive(AnchorDeserialize)]
+struct CampaignV9 {
+    next_coupon_index: u64,
+}
+
+/// Fields appended by layout version 10.
+#[derive(AnchorDeserialize)]
+struct CampaignV10 {
+    max_transfers: u16,
+}
+
+/// Fields appended by layout version 11.
+#[derive(AnchorDeserialize)]
+struct CampaignV11 {
+    transfer_cooldown_secs: u32,
+}
+
+/// Fields appended by layout version 12.
+#[derive(AnchorDeserialize)]
+struct CampaignV12 {
+    outstanding_coupons: u32,
+}
+
+#[derive(Accounts)]
+pub struct MigrateCampaign<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// CHECK: Legacy campaigns may not match the latest struct. Owner is
+    /// checked here; discriminator, PDA address and authority are verified
+    /// in the handler.
+    #[account(
+        mut,
+        owner = crate::ID @ PromoError::InvalidCampaignAccount
+    )]
+    pub campaign: AccountInfo<'info>,
+
+    /// Campaign merchant or platform admin, paying the extra rent.
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -1,26 +1,94 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
 
 use crate::errors::*;
 use crate::states::*;
 use crate::utils::*;
 
-/// Merchant mints a coupon for a recipient.
+/// Merchant (or one of its device keys) mints a coupon for a recipient.
     ///
     /// Targeting rules:
     /// - If `campaign.requires_wallet == false`:
     ///   * `recipient` can be any wallet (open campaign).
     /// - If `campaign.requires_wallet == true`:
-    ///   * `recipient` MUST match `campaign.target_wallet`.
+    ///   * `recipient` MUST match `campaign.target_wallet`, before
+    ///     `campaign.target_claim_deadline` when set.
+    /// - If `campaign.requires_whitelist`:
+    ///   * `whitelist_entry` must be the recipient's entry, within its
+    ///     `claim_deadline` when set.
+    /// - If `campaign.segment` is set:
+    ///   * `recipient` MUST belong to that segment (wallet list, or Merkle
+    ///     `segment_proof` against the segment root).
+    /// - If `campaign.min_category_redemptions > 0`:
+    ///   * the recipient's redemption receipts in `required_category_code`
+    ///     must be passed as remaining accounts, at least that many of them.
+    ///
+    /// - If `campaign.prerequisite_campaign` is set:
+    ///   * `prerequisite_proof` must be a coupon (held by the recipient) or a
+    ///     redemption receipt (redeemed by the recipient) from that campaign.
+    /// - If `campaign.first_purchase_only`:
+    ///   * `recipient_history` must show no prior redemption at this merchant.
+    /// - If `campaign.min_prior_redemptions > 0`:
+    ///   * `recipient_history` must show at least that many redemptions at
+    ///     this merchant (loyalty tier).
+    /// - If `campaign.required_achievement` is set:
+    ///   * `recipient_achievement` must be the recipient's unlocked progress.
+    /// - If `campaign.required_collection` is set:
+    ///   * `nft_token_account` must hold an NFT of that verified Metaplex
+    ///     collection, owned by the recipient, with its `nft_metadata`.
+    /// - If `campaign.gate_mint` is set:
+    ///   * `gate_token_account` must be a recipient token account of that mint
+    ///     holding at least `campaign.gate_min_amount`.
+    /// - If `campaign.sns_parent_domain` is set:
+    ///   * `sns_domain` must be a name account under that parent, owned by
+    ///     the recipient.
+    /// - If the sybil gate is set (see `set_sybil_gate`):
+    ///   * `stake_account` must be an active stake of the recipient, large
+    ///     enough and not used by another wallet of the campaign
+    ///     (`stake_claim` marker), or
+    ///   * `wallet_registration` of the recipient must be old enough.
+    /// - If `campaign.eligibility_program` is set:
+    ///   * its `check_eligibility(recipient, campaign)` must succeed (CPI).
+    /// - If `campaign.kyc_attestation_program` is set:
+    ///   * `kyc_attestation` must be a valid, unexpired attestation of the
+    ///     recipient (see `set_kyc_requirement`).
+    /// - If `campaign.region_attestor` is set:
+    ///   * the transaction must carry the attestor's Ed25519 signature over
+    ///     `recipient || region_code` (see `set_region_targeting`).
+    /// - `recipient` must not be in `campaign.excluded_wallets` nor blocked
+    ///   (`add_blocked_wallet`).
+    /// - If `campaign.max_coupons_per_wallet > 0`, `recipient` must have
+    ///   received fewer coupons than that (`wallet_coupon_count`).
+    /// - If `config.category_claims_per_epoch > 0`, `recipient` must have
+    ///   received fewer coupons than that in `campaign.category_code` this
+    ///   epoch, across all campaigns (`category_claim_count`).
+    ///
+    /// Issuer:
+    /// - The campaign merchant, or
+    /// - a `DeviceKey` of the merchant (in-store terminal), which must not be
+    ///   revoked and is limited to `daily_quota` mints per UTC day.
     ///
     /// Additionally:
     /// - Creates a logical "NFT-like" coupon account.
     /// - Transfers `mint_cost_lamports` in real lamports from the campaign vault
     ///   to the platform treasury using a custom lamports transfer helper.
     /// - Updates vault accounting (`total_mint_spent`).
-    pub fn mint_coupon(
-        ctx: Context<MintCoupon>,
+    /// - Skips the mint cost while `GlobalConfig.mint_fee_disabled` (fee holiday).
+    /// - "Until funds last" campaigns stop minting once the budget is exhausted,
+    ///   and are flagged exhausted when this mint drains the vault.
+    /// - `coupon_index` is optional: without it the coupon takes the campaign's
+    ///   `next_coupon_index`, so concurrent issuers don't have to coordinate
+    ///   indexes (a concurrent mint at the same index fails and can be retried).
+    /// - Stores `metadata` (SKU / memo / URI) and the merchant `serial` (an
+    ///   opaque hash of its internal coupon code, reported on redemption) on
+    ///   the coupon.
+    pub fn mint_coupon<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintCoupon<'info>>,
         campaign_id: u64,
-        coupon_index: u64,
+        coupon_index: Option<u64>,
+        segment_proof: Vec<[u8; 32]>,
+        metadata: CouponMetadata,
+        serial: [u8; 32],
     ) -> Result<()> {
         let campaign = &mut ctx.accounts.campaign;
         let vault = &mut ctx.accounts.vault;
@@ -28,77 +96,161 @@ use crate::utils::*;
         let recipient = &ctx.accounts.recipient;
         let platform_treasury = &ctx.accounts.platform_treasury;
 
+        metadata.validate()?;
+
         // Ensure the campaign id matches (safety)
         require!(
             campaign.campaign_id == campaign_id,
             PromoError::InvalidCampaignId
         );
+        let coupon_index = coupon_index.unwrap_or(campaign.next_coupon_index);
 
-        // Ensure we do not exceed the total number of coupons configured for this campaign
-        require!(
-            campaign.minted_coupons < campaign.total_coupons,
-            PromoError::NoCouponsLeft
-        );
-
-        let mint_cost = campaign.mint_cost_lamports;
-        require!(mint_cost > 0, PromoError::InvalidMintCost);
-
-        // Enforce targeting logic:
-        // - If requires_wallet == true, only the configured target_wallet can receive coupons.
-        if campaign.requires_wallet {
+        // Device keys can issue coupons on behalf of the merchant, within their daily quota
+        if !campaign.is_operator(&ctx.accounts.issuer.key()) {
+            let device_key = ctx
+                .accounts
+                .device_key
+                .as_mut()
+                .ok_or(PromoError::DeviceKeyRequired)?;
+            require_keys_eq!(
+                device_key.merchant,
+                campaign.merchant,
+                PromoError::DeviceKeyRequired
+            );
             require_keys_eq!(
-                recipient.key(),
-                campaign.target_wallet,
-                PromoError::NotEligibleForCampaign
+                device_key.device,
+                ctx.accounts.issuer.key(),
+                PromoError::DeviceKeyRequired
             );
+            require!(!device_key.revoked, PromoError::DeviceKeyRevoked);
+
+            let today = local_day_index(Clock::get()?.unix_timestamp, 0);
+            if device_key.day != today {
+                device_key.day = today;
+                device_key.minted_today = 0;
+            }
+            require!(
+                device_key.minted_today < device_key.daily_quota,
+                PromoError::DeviceQuotaExceeded
+            );
+            device_key.minted_today += 1;
         }
 
-        // Check if vault has enough lamports for mint cost (real SOL check)
-        let vault_lamports = **vault.to_account_info().lamports.borrow();
-        require!(
-            vault_lamports >= mint_cost,
-            PromoError::InsufficientVaultBalance
-        );
+        check_recipient_eligibility(
+            campaign,
+            &recipient.key(),
+            &EligibilityProofs {
+                blocked_wallet: &ctx.accounts.blocked_wallet,
+                segment: ctx.accounts.segment.as_ref(),
+                segment_proof: &segment_proof,
+                category_receipts: ctx.remaining_accounts,
+                achievement: ctx.accounts.recipient_achievement.as_ref(),
+                prerequisite_proof: ctx
+                    .accounts
+                    .prerequisite_proof
+                    .as_ref()
+                    .map(|proof| proof.as_ref()),
+                history: ctx
+                    .accounts
+                    .recipient_history
+                    .as_ref()
+                    .map(|history| history.as_ref()),
+                nft_token_account: ctx.accounts.nft_token_account.as_ref(),
+                nft_metadata: ctx
+                    .accounts
+                    .nft_metadata
+                    .as_ref()
+                    .map(|metadata| metadata.as_ref()),
+                gate_token_account: ctx.accounts.gate_token_account.as_ref(),
+                instructions_sysvar: ctx
+                    .accounts
+                    .instructions_sysvar
+                    .as_ref()
+                    .map(|sysvar| sysvar.as_ref()),
+                sns_domain: ctx
+                    .accounts
+                    .sns_domain
+                    .as_ref()
+                    .map(|domain| domain.as_ref()),
+                eligibility_program: ctx
+                    .accounts
+                    .eligibility_program
+                    .as_ref()
+                    .map(|program| program.as_ref()),
+                eligibility_state: ctx
+                    .accounts
+                    .eligibility_state
+                    .as_ref()
+                    .map(|state| state.as_ref()),
+                kyc_attestation: ctx
+                    .accounts
+                    .kyc_attestation
+                    .as_ref()
+                    .map(|attestation| attestation.as_ref()),
+                whitelist_entry: ctx.accounts.whitelist_entry.as_deref(),
+                stake_account: ctx
+                    .accounts
+                    .stake_account
+                    .as_ref()
+                    .map(|stake| stake.as_ref()),
+                wallet_registration: ctx.accounts.wallet_registration.as_deref(),
+                cnft_verified: false,
+            },
+        )?;
 
-        // Transfer real lamports from vault PDA to platform treasury.
-        transfer_lamports(
-            &vault.to_account_info(),
-            &platform_treasury.to_account_info(),
-            mint_cost,
+        record_stake_claim(
+            campaign,
+            ctx.accounts.stake_account.as_ref().map(|stake| stake.as_ref()),
+            ctx.accounts.stake_claim.as_ref().map(|claim| claim.as_ref()),
+            &ctx.accounts.issuer,
+            &ctx.accounts.system_program,
+        )?;
+
+        record_wallet_coupon(
+            campaign,
+            ctx.accounts.wallet_coupon_count.as_mut(),
+            &recipient.key(),
+        )?;
+
+        record_category_claim(
+            &ctx.accounts.config,
+            campaign,
+            ctx.accounts.category_claim_count.as_mut(),
+            &recipient.key(),
         )?;
 
-        // Update vault analytics (logical mint spending)
-        vault.total_mint_spent = vault
-            .total_mint_spent
-            .checked_add(mint_cost)
-            .ok_or(PromoError::Overflow)?;
-
-        // Initialize coupon fields
-        coupon.campaign = campaign.key();
-        coupon.coupon_index = coupon_index;
-        coupon.owner = recipient.key();
-        coupon.used = false;
-        coupon.listed = false;
-        coupon.sale_price_lamports = 0;
-
-        // Update campaign minted count
-        campaign.minted_coupons = campaign
-            .minted_coupons
-            .checked_add(1)
-            .ok_or(PromoError::Overflow)?;
+        issue_coupon(
+            campaign,
+            vault,
+            coupon,
+            coupon_index,
+            recipient.key(),
+            &platform_treasury.to_account_info(),
+            &ctx.accounts.config,
+        )?;
+        coupon.metadata = metadata;
+        coupon.serial = serial;
 
         Ok(())
     }
 
 #[derive(Accounts)]
-#[instruction(campaign_id: u64, coupon_index: u64)]
+#[instruction(campaign_id: u64, coupon_index: Option<u64>)]
 pub struct MintCoupon<'info> {
-    /// Campaign PDA for this coupon.
+    /// Global config – fee switches.
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Campaign PDA for this coupon (derived from its creator, which stays
+    /// the seed after an authority handoff).
     #[account(
         mut,
         seeds = [
             b"campaign",
-            merchant.key().as_ref(),
+            campaign.creator.as_ref(),
             &campaign_id.to_le_bytes(),
         ],
         bump
@@ -117,35 +269,185 @@ pub struct MintCoupon<'info> {
     pub vault: Account<'info, Vault>,
 
 
-    /// Coupon PDA. One PDA per (campaign, coupon_index).
+    /// Coupon PDA. One PDA per (campaign, coupon_index); without an explicit
+    /// index, at `campaign.next_coupon_index`.
     #[account(
         init,
-        payer = merchant,
+        payer = issuer,
         space = 8 + Coupon::SIZE,
         seeds = [
             b"coupon",
             campaign.key().as_ref(),
-            &coupon_index.to_le_bytes(),
+            &coupon_index.unwrap_or(campaign.next_coupon_index).to_le_bytes(),
         ],
         bump
     )]
     pub coupon: Account<'info, Coupon>,
 
 
-    /// Merchant paying for the account creation (rent).
+    /// Issuer paying for the account creation (rent): the campaign merchant,
+    /// a delegated manager or a registered device key.
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+
+    /// Device key registration of `issuer` (required only when the issuer is
+    /// neither the campaign merchant nor a manager).
     #[account(mut)]
-    pub merchant: Signer<'info>,
+    pub device_key: Option<Account<'info, DeviceKey>>,
 
 
     /// CHECK: This is the wallet that will receive the coupon. We only read its public key.
     pub recipient: UncheckedAccount<'info>,
 
 
+    /// CHECK: Blocklist PDA of the recipient (usually uninitialized); the recipient
+    /// is rejected if it exists.
+    #[account(
+        seeds = [
+            b"blocked",
+            campaign.key().as_ref(),
+            recipient.key().as_ref(),
+        ],
+        bump
+    )]
+    pub blocked_wallet: UncheckedAccount<'info>,
+
+
     /// CHECK: This is the platform treasury account that will receive real lamports
     /// from the vault (mint cost and service fees).
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = config.platform_treasury != Pubkey::default()
+            && platform_treasury.key() == config.platform_treasury
+            @ PromoError::InvalidPlatformTreasury
+    )]
     pub platform_treasury: UncheckedAccount<'info>,
 
 
+    /// Audience segment referenced by the campaign (required only if `campaign.segment` is set).
+    pub segment: Option<Account<'info, Segment>>,
+
+
+    /// CHECK: Coupon or redemption receipt from `campaign.prerequisite_campaign`.
+    /// Owner, discriminator and contents are verified in the handler.
+    pub prerequisite_proof: Option<UncheckedAccount<'info>>,
+
+
+    /// CHECK: `CustomerHistory` PDA of the recipient at this merchant (may be uninitialized).
+    /// Required only if `campaign.first_purchase_only` or `min_prior_redemptions`
+    /// is set; read via `prior_redemptions`.
+    #[account(
+        seeds = [
+            b"history",
+            campaign.merchant.as_ref(),
+            recipient.key().as_ref(),
+        ],
+        bump
+    )]
+    pub recipient_history: Option<UncheckedAccount<'info>>,
+
+
+    /// Recipient's progress on `campaign.required_achievement` (required only if set).
+    pub recipient_achievement: Option<Account<'info, AchievementProgress>>,
+
+
+    /// Coupons already received by the recipient (required only if
+    /// `campaign.max_coupons_per_wallet` is set).
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = 8 + WalletCouponCount::SIZE,
+        seeds = [
+            b"wallet_coupons",
+            campaign.key().as_ref(),
+            recipient.key().as_ref(),
+            &campaign.period.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub wallet_coupon_count: Option<Account<'info, WalletCouponCount>>,
+
+
+    /// Coupons received by the recipient in the campaign category this epoch
+    /// (required only if `config.category_claims_per_epoch` is set).
+    #[account(
+        init_if_needed,
+        payer = issuer,
+        space = 8 + CategoryClaimCount::SIZE,
+        seeds = [
+            b"category_claims",
+            recipient.key().as_ref(),
+            &campaign.category_code.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub category_claim_count: Option<Account<'info, CategoryClaimCount>>,
+
+
+    /// Recipient's token account holding an NFT of `campaign.required_collection`
+    /// (required only if set).
+    pub nft_token_account: Option<Account<'info, TokenAccount>>,
+
+
+    /// CHECK: Metaplex metadata PDA of the NFT in `nft_token_account`.
+    /// Owner, address and collection are verified in `verify_collection_nft`.
+    pub nft_metadata: Option<UncheckedAccount<'info>>,
+
+
+    /// Recipient's token account of `campaign.gate_mint` (required only if set).
+    pub gate_token_account: Option<Account<'info, TokenAccount>>,
+
+
+    /// CHECK: Instructions sysvar, read for the Ed25519 region attestation
+    /// (required only if `campaign.region_attestor` is set).
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+
+    /// CHECK: SNS name registry account of a domain owned by the recipient
+    /// (required only if `campaign.sns_parent_domain` is set); verified in
+    /// `verify_sns_domain`.
+    pub sns_domain: Option<UncheckedAccount<'info>>,
+
+
+    /// CHECK: `campaign.eligibility_program` (required only if set); checked by
+    /// address and invoked read-only.
+    pub eligibility_program: Option<UncheckedAccount<'info>>,
+
+
+    /// CHECK: Optional state account handed to the eligibility program as-is.
+    pub eligibility_state: Option<UncheckedAccount<'info>>,
+
+
+    /// CHECK: KYC / age attestation of the recipient (required only if
+    /// `campaign.kyc_attestation_program` is set); verified in `verify_kyc_attestation`.
+    pub kyc_attestation: Option<UncheckedAccount<'info>>,
+
+
+    /// Whitelist entry of the recipient (required only if `campaign.requires_whitelist`).
+    pub whitelist_entry: Option<Box<Account<'info, WhitelistEntry>>>,
+
+
+    /// CHECK: Stake account withdrawable by the recipient (required only if the
+    /// campaign sybil gate is set); verified in `verify_stake`.
+    pub stake_account: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Marker PDA `[b"stake_claim", campaign, stake_account]` (required
+    /// only with `stake_account`); created by `record_stake_claim`.
+    #[account(mut)]
+    pub stake_claim: Option<UncheckedAccount<'info>>,
+
+    /// Registration of the recipient (age path of the sybil gate).
+    #[account(
+        seeds = [
+            b"wallet_registration",
+            recipient.key().as_ref(),
+        ],
+        bump
+    )]
+    pub wallet_registration: Option<Box<Account<'info, WalletRegistration>>>,
+
+
     pub system_program: Program<'info, System>,
 }
@@ -0,0 +1,258 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Merchant (or a delegated manager) mints many coupons in one instruction,
+/// e.g. for an airdrop.
+///
+/// `remaining_accounts` is a flat list of
+/// `(coupon, recipient, blocked_wallet, wallet_coupon_count, category_claim_count)`
+/// tuples, one per entry of `coupon_indexes` and in the same order:
+/// - `coupon`: the coupon PDA `[b"coupon", campaign, coupon_index]` (writable, uninitialized)
+/// - `recipient`: the wallet receiving the coupon (read-only)
+/// - `blocked_wallet`: the blocklist PDA `[b"blocked", campaign, recipient]` (usually uninitialized)
+/// - `wallet_coupon_count`: the PDA `[b"wallet_coupons", campaign, recipient, period]`
+///   (writable, created if needed; only used with `max_coupons_per_wallet`)
+/// - `category_claim_count`: the PDA `[b"category_claims", recipient, category_code]`
+///   (writable, created if needed; only used with `category_claims_per_epoch`)
+///
+/// Each coupon is issued exactly like `mint_coupon` (mint cost charged to the
+/// vault once per coupon), but only with the eligibility proofs that fit a
+/// batch: campaigns whose rules need per-recipient accounts or proofs
+/// (whitelist, Merkle segment, token / NFT gates, history, sybil gate, ...)
+/// fail with the same errors as a `mint_coupon` without them and must be
+/// minted one by one. At most `Campaign::MAX_BATCH_MINT` coupons per call.
+/// Every coupon of the batch gets the same `metadata`; `serials` is either
+/// empty (no merchant serials) or one per coupon.
+pub fn mint_coupons_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, MintCouponsBatch<'info>>,
+    coupon_indexes: Vec<u64>,
+    metadata: CouponMetadata,
+    serials: Vec<[u8; 32]>,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let vault = &mut ctx.accounts.vault;
+    let issuer = &ctx.accounts.issuer;
+    let platform_treasury = &ctx.accounts.platform_treasury;
+    let system_program = &ctx.accounts.system_program;
+    let remaining = ctx.remaining_accounts;
+
+    // Merchant or delegated manager (device keys mint one coupon at a time)
+    require!(campaign.is_operator(&issuer.key()), PromoError::NotMerchant);
+
+    require!(
+        !coupon_indexes.is_empty()
+            && coupon_indexes.len() <= Campaign::MAX_BATCH_MINT
+            && remaining.len() == coupon_indexes.len() * 5
+            && (serials.is_empty() || serials.len() == coupon_indexes.len()),
+        PromoError::InvalidBatch
+    );
+
+    metadata.validate()?;
+
+    let campaign_key = campaign.key();
+    for (i, (coupon_index, accounts)) in coupon_indexes
+        .into_iter()
+        .zip(remaining.chunks(5))
+        .enumerate()
+    {
+        let coupon_info = &accounts[0];
+        let recipient = accounts[1].key();
+        let blocked_wallet = &accounts[2];
+        let wallet_count_info = &accounts[3];
+        let category_count_info = &accounts[4];
+
+        let coupon_index_bytes = coupon_index.to_le_bytes();
+        let (coupon_key, coupon_bump) = Pubkey::find_program_address(
+            &[b"coupon", campaign_key.as_ref(), &coupon_index_bytes],
+            &crate::ID,
+        );
+        require_keys_eq!(coupon_info.key(), coupon_key, PromoError::InvalidBatch);
+        let (blocked_key, _) = Pubkey::find_program_address(
+            &[b"blocked", campaign_key.as_ref(), recipient.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(blocked_wallet.key(), blocked_key, PromoError::InvalidBatch);
+
+        check_recipient_eligibility(
+            campaign,
+            &recipient,
+            &EligibilityProofs {
+                blocked_wallet,
+                segment: ctx.accounts.segment.as_ref(),
+                segment_proof: &[],
+                category_receipts: &[],
+                achievement: None,
+                prerequisite_proof: None,
+                history: None,
+                nft_token_account: None,
+                nft_metadata: None,
+                gate_token_account: None,
+                instructions_sysvar: None,
+                sns_domain: None,
+                eligibility_program: None,
+                eligibility_state: None,
+                kyc_attestation: None,
+                whitelist_entry: None,
+                stake_account: None,
+                wallet_registration: None,
+                cnft_verified: false,
+            },
+        )?;
+
+        // Per-wallet cap counter
+        let period_bytes = campaign.period.to_le_bytes();
+        let (wallet_count_key, wallet_count_bump) = Pubkey::find_program_address(
+            &[
+                b"wallet_coupons",
+                campaign_key.as_ref(),
+                recipient.as_ref(),
+                &period_bytes,
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(wallet_count_info.key(), wallet_count_key, PromoError::InvalidBatch);
+        let mut wallet_count = if campaign.max_coupons_per_wallet == 0 {
+            None
+        } else if wallet_count_info.data_is_empty() {
+            create_program_account(
+                issuer,
+                wallet_count_info,
+                8 + WalletCouponCount::SIZE,
+                &[
+                    b"wallet_coupons",
+                    campaign_key.as_ref(),
+                    recipient.as_ref(),
+                    &period_bytes,
+                    &[wallet_count_bump],
+                ],
+                system_program,
+            )?;
+            Some(Account::<WalletCouponCount>::try_from_unchecked(wallet_count_info)?)
+        } else {
+            Some(Account::<WalletCouponCount>::try_from(wallet_count_info)?)
+        };
+        record_wallet_coupon(campaign, wallet_count.as_mut(), &recipient)?;
+
+        // Per-category cap counter
+        let category_code_bytes = campaign.category_code.to_le_bytes();
+        let (category_count_key, category_count_bump) = Pubkey::find_program_address(
+            &[b"category_claims", recipient.as_ref(), &category_code_bytes],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            category_count_info.key(),
+            category_count_key,
+            PromoError::InvalidBatch
+        );
+        let mut category_count = if ctx.accounts.config.category_claims_per_epoch == 0 {
+            None
+        } else if category_count_info.data_is_empty() {
+            create_program_account(
+                issuer,
+                category_count_info,
+                8 + CategoryClaimCount::SIZE,
+                &[
+                    b"category_claims",
+                    recipient.as_ref(),
+                    &category_code_bytes,
+                    &[category_count_bump],
+                ],
+                system_program,
+            )?;
+            Some(Account::<CategoryClaimCount>::try_from_unchecked(category_count_info)?)
+        } else {
+            Some(Account::<CategoryClaimCount>::try_from(category_count_info)?)
+        };
+        record_category_claim(
+            &ctx.accounts.config,
+            campaign,
+            category_count.as_mut(),
+            &recipient,
+        )?;
+
+        create_program_account(
+            issuer,
+            coupon_info,
+            8 + Coupon::SIZE,
+            &[
+                b"coupon",
+                campaign_key.as_ref(),
+                &coupon_index_bytes,
+                &[coupon_bump],
+            ],
+            system_program,
+        )?;
+        let mut coupon = Account::<Coupon>::try_from_unchecked(coupon_info)?;
+
+        issue_coupon(
+            campaign,
+            vault,
+            &mut coupon,
+            coupon_index,
+            recipient,
+            &platform_treasury.to_account_info(),
+            &ctx.accounts.config,
+        )?;
+        coupon.metadata = metadata.clone();
+        coupon.serial = serials.get(i).copied().unwrap_or_default();
+
+        coupon.exit(&crate::ID)?;
+        if let Some(wallet_count) = wallet_count {
+            wallet_count.exit(&crate::ID)?;
+        }
+        if let Some(category_count) = category_count {
+            category_count.exit(&crate::ID)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Mint a batch of coupons; coupon / recipient accounts are passed via `remaining_accounts`.
+#[derive(Accounts)]
+pub struct MintCouponsBatch<'info> {
+    /// Global config – fee switches.
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Campaign the coupons belong to.
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Vault PDA associated with this campaign.
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Campaign merchant or delegated manager, paying for the coupon accounts (rent).
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    /// CHECK: This is the platform treasury account that will receive real lamports
+    /// from the vault (mint cost).
+    #[account(
+        mut,
+        constraint = config.platform_treasury != Pubkey::default()
+            && platform_treasury.key() == config.platform_treasury
+            @ PromoError::InvalidPlatformTreasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// Audience segment referenced by the campaign (required only if
+    /// `campaign.segment` is set; wallet-list segments only).
+    pub segment: Option<Account<'info, Segment>>,
+
+    pub system_program: Program<'info, System>,
+}
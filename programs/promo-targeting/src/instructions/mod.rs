@@ -29,4 +29,391 @@ pub use expire_coupon::*;
 pub mod expire_coupon;
 
 pub use check_treasury_balance::*;
-pub mod check_treasury_balance;
\ No newline at end of file
+pub mod check_treasury_balance;
+
+pub use initialize_matching_pool::*;
+pub mod initialize_matching_pool;
+
+pub use update_matching_pool::*;
+pub mod update_matching_pool;
+
+pub use fund_matching_pool::*;
+pub mod fund_matching_pool;
+
+pub use withdraw_matching_pool::*;
+pub mod withdraw_matching_pool;
+
+pub use match_campaign_deposit::*;
+pub mod match_campaign_deposit;
+
+pub use create_segment::*;
+pub mod create_segment;
+
+pub use update_segment::*;
+pub mod update_segment;
+
+pub use set_category_targeting::*;
+pub mod set_category_targeting;
+
+pub use set_excluded_wallets::*;
+pub mod set_excluded_wallets;
+
+pub use set_prerequisite_campaign::*;
+pub mod set_prerequisite_campaign;
+
+pub use set_location_requirement::*;
+pub mod set_location_requirement;
+
+pub use attest_location::*;
+pub mod attest_location;
+
+pub use set_redemption_schedule::*;
+pub mod set_redemption_schedule;
+
+pub use set_first_purchase_only::*;
+pub mod set_first_purchase_only;
+
+pub use propose_coupon_swap::*;
+pub mod propose_coupon_swap;
+
+pub use accept_coupon_swap::*;
+pub mod accept_coupon_swap;
+
+pub use cancel_coupon_swap::*;
+pub mod cancel_coupon_swap;
+
+pub use buy_listed_coupons_batch::*;
+pub mod buy_listed_coupons_batch;
+
+pub use set_withholding_rule::*;
+pub mod set_withholding_rule;
+
+pub use set_campaign_jurisdiction::*;
+pub mod set_campaign_jurisdiction;
+
+pub use propose_emergency_withdrawal::*;
+pub mod propose_emergency_withdrawal;
+
+pub use execute_emergency_withdrawal::*;
+pub mod execute_emergency_withdrawal;
+
+pub use cancel_emergency_withdrawal::*;
+pub mod cancel_emergency_withdrawal;
+
+pub use propose_config_admin::*;
+pub mod propose_config_admin;
+
+pub use accept_config_admin::*;
+pub mod accept_config_admin;
+
+pub use set_fee_switches::*;
+pub mod set_fee_switches;
+
+pub use finalize_config::*;
+pub mod finalize_config;
+
+pub use hold_coupon::*;
+pub mod hold_coupon;
+
+pub use set_user_discount_cap::*;
+pub mod set_user_discount_cap;
+
+pub use set_daily_budget::*;
+pub mod set_daily_budget;
+
+pub use set_budget_expiration::*;
+pub mod set_budget_expiration;
+
+pub use withdraw_vault_excess::*;
+pub mod withdraw_vault_excess;
+
+pub use set_payout_wallet::*;
+pub mod set_payout_wallet;
+
+pub use transfer_campaign_authority::*;
+pub mod transfer_campaign_authority;
+
+pub use accept_campaign_authority::*;
+pub mod accept_campaign_authority;
+
+pub use register_device_key::*;
+pub mod register_device_key;
+
+pub use revoke_device_key::*;
+pub mod revoke_device_key;
+
+pub use set_redemption_verifier::*;
+pub mod set_redemption_verifier;
+
+pub use set_streak_bonus::*;
+pub mod set_streak_bonus;
+
+pub use create_achievement::*;
+pub mod create_achievement;
+
+pub use join_achievement::*;
+pub mod join_achievement;
+
+pub use set_required_achievement::*;
+pub mod set_required_achievement;
+
+pub use create_lottery::*;
+pub mod create_lottery;
+
+pub use commit_draw::*;
+pub mod commit_draw;
+
+pub use draw_winner::*;
+pub mod draw_winner;
+
+pub use close_lottery::*;
+pub mod close_lottery;
+
+pub use publish_oracle_feed::*;
+pub mod publish_oracle_feed;
+
+pub use set_redemption_condition::*;
+pub mod set_redemption_condition;
+
+pub use set_redemption_reward::*;
+pub mod set_redemption_reward;
+
+pub use withdraw_reward_tokens::*;
+pub mod withdraw_reward_tokens;
+
+pub use set_gas_rebate::*;
+pub mod set_gas_rebate;
+
+pub use set_campaign_partner::*;
+pub mod set_campaign_partner;
+
+pub use set_rounding_mode::*;
+pub mod set_rounding_mode;
+
+pub use create_export_checkpoint::*;
+pub mod create_export_checkpoint;
+
+pub use ack_export::*;
+pub mod ack_export;
+
+pub use register_qr_nonce::*;
+pub mod register_qr_nonce;
+
+pub use set_qr_nonce_required::*;
+pub mod set_qr_nonce_required;
+
+pub use upgrade_vault::*;
+pub mod upgrade_vault;
+
+pub use archive_campaign::*;
+pub mod archive_campaign;
+
+pub use set_platform_treasury::*;
+pub mod set_platform_treasury;
+
+pub use set_required_collection::*;
+pub mod set_required_collection;
+
+pub use claim_coupon::*;
+pub mod claim_coupon;
+
+pub use set_token_gate::*;
+pub mod set_token_gate;
+
+pub use set_wallet_coupon_limit::*;
+pub mod set_wallet_coupon_limit;
+
+pub use set_region_targeting::*;
+pub mod set_region_targeting;
+
+pub use set_transferable::*;
+pub mod set_transferable;
+
+pub use set_loyalty_tier::*;
+pub mod set_loyalty_tier;
+
+pub use claim_with_voucher::*;
+pub mod claim_with_voucher;
+
+pub use add_blocked_wallet::*;
+pub mod add_blocked_wallet;
+
+pub use remove_blocked_wallet::*;
+pub mod remove_blocked_wallet;
+
+pub use set_required_cnft_collection::*;
+pub mod set_required_cnft_collection;
+
+pub use claim_coupon_with_cnft::*;
+pub mod claim_coupon_with_cnft;
+
+pub use set_sns_parent_domain::*;
+pub mod set_sns_parent_domain;
+
+pub use set_eligibility_program::*;
+pub mod set_eligibility_program;
+
+pub use set_kyc_requirement::*;
+pub mod set_kyc_requirement;
+
+pub use set_target_claim_deadline::*;
+pub mod set_target_claim_deadline;
+
+pub use issue_claim_ticket::*;
+pub mod issue_claim_ticket;
+
+pub use finalize_claim::*;
+pub mod finalize_claim;
+
+pub use whitelist_wallet::*;
+pub mod whitelist_wallet;
+
+pub use unwhitelist_wallet::*;
+pub mod unwhitelist_wallet;
+
+pub use set_whitelist_required::*;
+pub mod set_whitelist_required;
+
+pub use update_campaign_targeting::*;
+pub mod update_campaign_targeting;
+
+pub use set_category_claim_cap::*;
+pub mod set_category_claim_cap;
+
+pub use set_claim_rate_limit::*;
+pub mod set_claim_rate_limit;
+
+pub use set_sybil_gate::*;
+pub mod set_sybil_gate;
+
+pub use create_targeting_template::*;
+pub mod create_targeting_template;
+
+pub use update_targeting_template::*;
+pub mod update_targeting_template;
+
+pub use cancel_campaign::*;
+pub mod cancel_campaign;
+
+pub use extend_campaign::*;
+pub mod extend_campaign;
+
+pub use update_campaign::*;
+pub mod update_campaign;
+
+pub use activate_campaign::*;
+pub mod activate_campaign;
+
+pub use create_campaigns_batch::*;
+pub mod create_campaigns_batch;
+
+pub use clone_campaign::*;
+pub mod clone_campaign;
+
+pub use close_campaign::*;
+pub mod close_campaign;
+
+pub use set_discount_phases::*;
+pub mod set_discount_phases;
+
+pub use renew_campaign::*;
+pub mod renew_campaign;
+
+pub use set_low_balance_threshold::*;
+pub mod set_low_balance_threshold;
+
+pub use add_manager::*;
+pub mod add_manager;
+
+pub use remove_manager::*;
+pub mod remove_manager;
+
+pub use register_merchant::*;
+pub mod register_merchant;
+
+pub use set_daily_redemption_limit::*;
+pub mod set_daily_redemption_limit;
+
+pub use register_category::*;
+pub mod register_category;
+
+pub use deprecate_category::*;
+pub mod deprecate_category;
+
+pub use update_campaign_metadata::*;
+pub mod update_campaign_metadata;
+
+pub use migrate_campaign::*;
+pub mod migrate_campaign;
+
+pub use expire_coupons_batch::*;
+pub mod expire_coupons_batch;
+
+pub use set_product_codes::*;
+pub mod set_product_codes;
+
+pub use set_budget_taper::*;
+pub mod set_budget_taper;
+
+pub use freeze_campaign::*;
+pub mod freeze_campaign;
+
+pub use unfreeze_campaign::*;
+pub mod unfreeze_campaign;
+
+pub use snapshot_campaign_stats::*;
+pub mod snapshot_campaign_stats;
+
+pub use set_discount_kind::*;
+pub mod set_discount_kind;
+
+pub use set_stacking_policy::*;
+pub mod set_stacking_policy;
+
+pub use redeem_coupons_stacked::*;
+pub mod redeem_coupons_stacked;
+
+pub use mint_coupons_batch::*;
+pub mod mint_coupons_batch;
+
+pub use set_coupon_value::*;
+pub mod set_coupon_value;
+
+pub use redeem_partial::*;
+pub mod redeem_partial;
+
+pub use reserve_coupon::*;
+pub mod reserve_coupon;
+
+pub use release_coupon::*;
+pub mod release_coupon;
+
+pub use set_transfer_limits::*;
+pub mod set_transfer_limits;
+
+pub use approve_transfer::*;
+pub mod approve_transfer;
+
+pub use claim_transfer::*;
+pub mod claim_transfer;
+
+pub use freeze_coupon::*;
+pub mod freeze_coupon;
+
+pub use unfreeze_coupon::*;
+pub mod unfreeze_coupon;
+
+pub use upgrade_coupon::*;
+pub mod upgrade_coupon;
+
+pub use register_wallet::*;
+pub mod register_wallet;
+
+pub use set_marketplace_fee::*;
+pub mod set_marketplace_fee;
+
+pub use revoke_claim_ticket::*;
+pub mod revoke_claim_ticket;
+
+pub use abandon_draw::*;
+pub mod abandon_draw;
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Current admin proposes a new `GlobalConfig.admin` (first step of a handoff).
+///
+/// The protocol can be handed to a DAO by proposing a spl-governance authority:
+/// - pass `governance_program` (must be the SPL Governance program,
+///   `SPL_GOVERNANCE_PROGRAM_ID`) and the `governance` account it owns;
+/// - `new_admin` must be the governance account itself or its native treasury
+///   PDA (`["native-treasury", governance]`), so only governance-executed
+///   proposals (CPIs signed by the governance program) can act as admin.
+///
+/// Admin instructions keep checking `admin: Signer` against `GlobalConfig.admin`;
+/// a governance PDA can only produce that signature through a governance CPI.
+pub fn propose_config_admin(
+    ctx: Context<ProposeConfigAdmin>,
+    new_admin: Pubkey,
+    governance_program: Option<Pubkey>,
+) -> Result<()> {
+    if let Some(governance_program) = governance_program {
+        require_keys_eq!(
+            governance_program,
+            SPL_GOVERNANCE_PROGRAM_ID,
+            PromoError::InvalidGovernanceProgram
+        );
+        let governance = ctx
+            .accounts
+            .governance
+            .as_ref()
+            .ok_or(PromoError::InvalidGovernanceAuthority)?;
+        require_keys_eq!(
+            *governance.owner,
+            governance_program,
+            PromoError::InvalidGovernanceAuthority
+        );
+
+        let (native_treasury, _) = Pubkey::find_program_address(
+            &[b"native-treasury", governance.key().as_ref()],
+            &governance_program,
+        );
+        require!(
+            new_admin == governance.key() || new_admin == native_treasury,
+            PromoError::InvalidGovernanceAuthority
+        );
+    }
+
+    let config = &mut ctx.accounts.config;
+    config.pending_admin = new_admin;
+
+    emit!(ConfigAdminProposed {
+        current_admin: config.admin,
+        pending_admin: new_admin,
+        governance_program,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when the admin proposes a new config authority.
+#[event]
+pub struct ConfigAdminProposed {
+    pub current_admin: Pubkey,
+    pub pending_admin: Pubkey,
+    pub governance_program: Option<Pubkey>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeConfigAdmin<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+
+    /// CHECK: spl-governance governance account (required only when handing
+    /// the config to a DAO). Its owner is checked against `governance_program`,
+    /// i.e. the SPL Governance program.
+    pub governance: Option<UncheckedAccount<'info>>,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Coupon owner proposes a private swap with a specific counterparty.
+///
+/// - The counterparty pays `amount` lamports, bounded by the campaign's resale
+///   cap like a marketplace listing (see `require_resale_price_allowed`).
+/// - The coupon stays with the owner until `accept_coupon_swap` settles both
+///   legs atomically; the owner can withdraw the offer with `cancel_coupon_swap`.
+/// - An offer left over by a previous owner of the coupon must first be
+///   cleared by the new owner with `cancel_coupon_swap`.
+pub fn propose_coupon_swap(ctx: Context<ProposeCouponSwap>, amount: u64) -> Result<()> {
+    let coupon = &ctx.accounts.coupon;
+    let offer = &mut ctx.accounts.offer;
+
+    require!(!coupon.used, PromoError::CouponAlreadyUsed);
+    require!(!coupon.frozen, PromoError::CouponFrozen);
+    require!(!coupon.listed, PromoError::CouponListed);
+    require!(
+        coupon.period == ctx.accounts.campaign.period,
+        PromoError::CouponFromPreviousPeriod
+    );
+    require!(
+        !coupon.is_held(&Clock::get()?),
+        PromoError::CouponOnHold
+    );
+    require!(amount > 0, PromoError::InvalidSwapAmount);
+    require_resale_price_allowed(&ctx.accounts.campaign, amount)?;
+    require!(
+        ctx.accounts.counterparty.key() != ctx.accounts.seller.key(),
+        PromoError::InvalidBuyer
+    );
+
+    offer.coupon = coupon.key();
+    offer.seller = ctx.accounts.seller.key();
+    offer.counterparty = ctx.accounts.counterparty.key();
+    offer.amount = amount;
+    offer.created_at = Clock::get()?.unix_timestamp;
+    offer.bump = ctx.bumps.offer;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ProposeCouponSwap<'info> {
+    #[account(
+        has_one = campaign @ PromoError::InvalidCouponCampaign,
+        constraint = coupon.owner == seller.key() @ PromoError::NotCouponOwner
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    /// Campaign of the coupon (current redemption period).
+    pub campaign: Account<'info, Campaign>,
+
+    /// Swap offer PDA. One open offer per coupon.
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + SwapOffer::SIZE,
+        seeds = [
+            b"swap",
+            coupon.key().as_ref(),
+        ],
+        bump
+    )]
+    pub offer: Account<'info, SwapOffer>,
+
+    /// Current coupon owner proposing the swap (pays the offer rent).
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    /// CHECK: Counterparty allowed to accept. We only read its public key.
+    pub counterparty: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin publishes an emergency withdrawal proposal for a specific vault.
+///
+/// - Only one open proposal per vault.
+/// - `destination` must be the campaign payout wallet (see
+///   `Campaign::payout_destination`) or the matching pool.
+/// - The proposal becomes executable after `EmergencyProposal::TIMELOCK_SECS`.
+/// - An `EmergencyWithdrawalProposed` event is emitted so dashboards can
+///   alert the merchant and users immediately.
+pub fn propose_emergency_withdrawal(
+    ctx: Context<ProposeEmergencyWithdrawal>,
+    amount: u64,
+    destination: Pubkey,
+) -> Result<()> {
+    require!(amount > 0, PromoError::InvalidEmergencyAmount);
+
+    let (matching_pool, _) = Pubkey::find_program_address(&[b"matching_pool"], &crate::ID);
+    require!(
+        destination == ctx.accounts.campaign.payout_destination() || destination == matching_pool,
+        PromoError::InvalidEmergencyDestination
+    );
+
+    let clock = Clock::get()?;
+    let executable_at = clock
+        .unix_timestamp
+        .checked_add(EmergencyProposal::TIMELOCK_SECS)
+        .ok_or(PromoError::Overflow)?;
+
+    let proposal = &mut ctx.accounts.proposal;
+    proposal.vault = ctx.accounts.vault.key();
+    proposal.destination = destination;
+    proposal.amount = amount;
+    proposal.created_at = clock.unix_timestamp;
+    proposal.executable_at = executable_at;
+    proposal.bump = ctx.bumps.proposal;
+
+    emit!(EmergencyWithdrawalProposed {
+        vault: proposal.vault,
+        campaign: ctx.accounts.vault.campaign,
+        partner: ctx.accounts.campaign.partner,
+        partner_share_bps: ctx.accounts.campaign.partner_share_bps,
+        destination,
+        amount,
+        executable_at,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when the admin publishes an emergency withdrawal proposal.
+#[event]
+pub struct EmergencyWithdrawalProposed {
+    pub vault: Pubkey,
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub executable_at: i64,
+}
+
+#[derive(Accounts)]
+pub struct ProposeEmergencyWithdrawal<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Vault targeted by the proposal.
+    pub vault: Account<'info, Vault>,
+
+    /// Campaign of the vault (payout wallet).
+    #[account(address = vault.campaign @ PromoError::InvalidVaultAccount)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Proposal PDA. One open proposal per vault.
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + EmergencyProposal::SIZE,
+        seeds = [
+            b"emergency",
+            vault.key().as_ref(),
+        ],
+        bump
+    )]
+    pub proposal: Account<'info, EmergencyProposal>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::states::*;
+
+/// Oracle publishes the latest value of one of its feeds.
+///
+/// The feed PDA is per (oracle, feed_id) and is overwritten by each
+/// publication; conditional campaigns read it at redemption.
+pub fn publish_oracle_feed(ctx: Context<PublishOracleFeed>, feed_id: u64, value: i64) -> Result<()> {
+    let feed = &mut ctx.accounts.feed;
+
+    feed.oracle = ctx.accounts.oracle.key();
+    feed.feed_id = feed_id;
+    feed.value = value;
+    feed.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(feed_id: u64)]
+pub struct PublishOracleFeed<'info> {
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        space = 8 + OracleFeed::SIZE,
+        seeds = [
+            b"feed",
+            oracle.key().as_ref(),
+            &feed_id.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub feed: Account<'info, OracleFeed>,
+
+    /// Oracle signing (and paying for) the publication.
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
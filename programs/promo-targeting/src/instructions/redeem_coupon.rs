@@ -1,4 +1,6 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, TransferChecked};
 
 use crate::utils::*;
 use crate::errors::*;
@@ -11,127 +13,325 @@ use crate::states::*;
     /// - On-chain:
     ///   * we mark the coupon as used
     ///   * update `used_coupons`
-    ///   * calculate discount and service fee
+    ///   * calculate discount (plus the streak bonus, see below) and service fee (no fee while
+    ///     `GlobalConfig.redemption_fee_disabled`, i.e. during a fee holiday)
+    ///   * round discount and fee per `campaign.rounding_mode`
     ///   * cap the discount by `max_discount_lamports`
+    ///   * cap the discount by what is left of the wallet's
+    ///     `max_discount_per_user` allowance (tracked in `UserStats`)
     ///   * transfer real lamports equal to the service fee from vault to platform treasury
     ///   * update `total_service_spent` in the vault
+    ///   * refund `gas_rebate_lamports` from the vault to the user, if set and
+    ///     the vault can still afford it
     ///   * update campaign analytics (total purchase / discount / last redeem ts)
+    ///   * write a permanent `RedemptionReceipt` for the user
+    ///   * update the user's `CustomerHistory` at this merchant and `UserStats` in this campaign
     ///   * emit an event with all data needed for analytics
     ///   * burn the coupon account (close to user)
     ///
-    /// `product_code` argument must match `campaign.product_code`, ensuring
-    /// the coupon is only used for the product it was configured for.
+    /// If `campaign.location_oracle` is set, a `LocationAttestation` from that
+    /// oracle for the user, in `location_region_code` and not older than
+    /// `location_max_age_secs`, must be passed (in-store-only promotions).
+    ///
+    /// If `campaign.redemption_verifier` is set, that key must co-sign the
+    /// transaction (human confirmation at the counter).
+    ///
+    /// Streaks: `CustomerHistory` counts consecutive weeks with a redemption at
+    /// the merchant. Campaigns with `streak_bonus_bps` add that many bps per
+    /// streak week beyond the first (this redemption included), up to
+    /// `max_streak_bonus_bps`.
+    ///
+    /// Achievements: `(Achievement, AchievementProgress)` pairs of the user can
+    /// be passed as remaining accounts to count this redemption towards them.
+    ///
+    /// If `campaign.condition_oracle` is set, the campaign's `OracleFeed` must
+    /// be passed and its value must satisfy the condition ("free pizza if the
+    /// home team wins").
+    ///
+    /// If `campaign.reward_mint` is set, `reward_amount` tokens move from the
+    /// vault-owned reward token account to the user's token account (less if
+    /// the reward account runs low; nothing once it is empty).
+    ///
+    /// Coupons gifted with `not_redeemable_before` cannot be redeemed earlier.
+    ///
+    /// If `campaign.requires_qr_nonce`, the scanned `qr_nonce` and its
+    /// registered `QrNonce` account must be passed; the nonce must hash to the
+    /// registered hash, and is consumed by this redemption.
+    ///
+    /// If the campaign has a weekday / hour schedule, the clock must fall inside it.
+    ///
+    /// Coupons reserved with `reserve_coupon` require the reserving `terminal`
+    /// to co-sign.
+    ///
+    /// If `daily_budget_lamports` is set, redemptions pause for the rest of the
+    /// (merchant-local) day once the discount granted that day reaches it.
+    /// The discount is capped at what is left of the day's budget, and
+    /// `DailyBudgetReached` is emitted by the redemption that hits the cap.
+    ///
+    /// If `budget_taper_bps` is set, the discount is also capped at that share
+    /// of the spendable vault balance, so the campaign tapers off as its
+    /// budget is consumed instead of failing on the service-fee transfer.
+    ///
+    /// If `max_redemptions_per_day` is set, at most that many coupons can be
+    /// redeemed per (merchant-local) day.
+    ///
+    /// `product_code` argument must match `campaign.product_code` or one of
+    /// `campaign.extra_product_codes`, ensuring the coupon is only used for the
+    /// products it was configured for. It is recorded on the receipt.
     pub fn redeem_coupon(
         ctx: Context<RedeemCoupon>,
         purchase_amount: u64,
         product_code: u16,
+        qr_nonce: Option<[u8; 32]>,
     ) -> Result<()> {
         let campaign = &mut ctx.accounts.campaign;
         let vault = &mut ctx.accounts.vault;
         let coupon = &mut ctx.accounts.coupon;
+        let receipt = &mut ctx.accounts.receipt;
+        let history = &mut ctx.accounts.customer_history;
+        let user_stats = &mut ctx.accounts.user_stats;
         let user = &ctx.accounts.user;
         let platform_treasury = &ctx.accounts.platform_treasury;
 
         let clock = Clock::get()?;
+        let campaign_key = campaign.key();
 
-        // Check campaign expiration
+        // Store credit is spent with redeem_partial
         require!(
-            clock.unix_timestamp <= campaign.expiration_timestamp,
-            PromoError::CampaignExpired
+            campaign.coupon_value_lamports == 0,
+            PromoError::PartialRedemptionRequired
         );
 
-        // Ensure correct product for this coupon
-        require!(
-            product_code == campaign.product_code,
-            PromoError::InvalidProductForCoupon
-        );
+        validate_redemption(
+            &campaign_key,
+            campaign,
+            coupon,
+            &user.key(),
+            product_code,
+            ctx.accounts.terminal.as_ref().map(|terminal| terminal.key()),
+            &clock,
+        )?;
 
-        // Safety check for available coupons
-        require!(
-            campaign.used_coupons < campaign.total_coupons,
-            PromoError::NoCouponsLeft
-        );
-
-        // Ensure coupon is not already used
-        require!(!coupon.used, PromoError::CouponAlreadyUsed);
-
-        // Ensure coupon is not currently listed in the secondary market
-        require!(!coupon.listed, PromoError::CouponListed);
+        // First-purchase-only campaigns cannot be redeemed by returning customers,
+        // even with a coupon bought on the secondary market
+        if campaign.first_purchase_only {
+            require!(history.redemption_count == 0, PromoError::FirstPurchaseOnly);
+        }
 
-        // Ensure coupon owner matches user
-        require_keys_eq!(coupon.owner, user.key(), PromoError::NotCouponOwner);
+        // Oracle-conditional coupons unlock only once the feed meets the condition
+        if campaign.condition_oracle != Pubkey::default() {
+            let feed = ctx
+                .accounts
+                .condition_feed
+                .as_ref()
+                .ok_or(PromoError::OracleFeedRequired)?;
+            require_keys_eq!(
+                feed.oracle,
+                campaign.condition_oracle,
+                PromoError::OracleFeedRequired
+            );
+            require!(
+                feed.feed_id == campaign.condition_feed_id,
+                PromoError::OracleFeedRequired
+            );
+            require!(
+                campaign.condition_met(feed.value),
+                PromoError::RedemptionConditionNotMet
+            );
+        }
 
-        // Calculate raw discount
-        let mut discount_value = purchase_amount
-            .checked_mul(campaign.discount_bps as u64)
-            .ok_or(PromoError::Overflow)?
-            / 10_000;
+        // In-store QR: consume the one-time nonce of the scanned code
+        if campaign.requires_qr_nonce {
+            let nonce = qr_nonce.ok_or(PromoError::QrNonceRequired)?;
+            let registered = ctx
+                .accounts
+                .qr_nonce
+                .as_mut()
+                .ok_or(PromoError::QrNonceRequired)?;
+            require_keys_eq!(registered.campaign, campaign_key, PromoError::QrNonceRequired);
+            require!(
+                hashv(&[&nonce]).to_bytes() == registered.nonce_hash,
+                PromoError::QrNonceRequired
+            );
+            require!(!registered.consumed, PromoError::QrNonceConsumed);
 
-        // Cap discount by max_discount_lamports
-        if discount_value > campaign.max_discount_lamports {
-            discount_value = campaign.max_discount_lamports;
+            registered.consumed = true;
+            registered.consumed_by = user.key();
+            registered.consumed_at = clock.unix_timestamp;
         }
 
-        let service_fee_value = discount_value
-            .checked_mul(campaign.service_fee_bps as u64)
-            .ok_or(PromoError::Overflow)?
-            / 10_000;
+        // Dual approval: the campaign verifier must co-sign
+        if campaign.redemption_verifier != Pubkey::default() {
+            let verifier = ctx
+                .accounts
+                .verifier
+                .as_ref()
+                .ok_or(PromoError::VerifierSignatureRequired)?;
+            require_keys_eq!(
+                verifier.key(),
+                campaign.redemption_verifier,
+                PromoError::VerifierSignatureRequired
+            );
+        }
 
-        // If service fee is > 0, transfer real lamports from vault to treasury
-        if service_fee_value > 0 {
-            let vault_lamports = **vault.to_account_info().lamports.borrow();
+        // Enforce geofencing: a fresh attestation from the configured oracle
+        if campaign.location_oracle != Pubkey::default() {
+            let attestation = ctx
+                .accounts
+                .location_attestation
+                .as_ref()
+                .ok_or(PromoError::LocationAttestationRequired)?;
+            require_keys_eq!(
+                attestation.oracle,
+                campaign.location_oracle,
+                PromoError::InvalidLocationAttestation
+            );
+            require_keys_eq!(
+                attestation.user,
+                user.key(),
+                PromoError::InvalidLocationAttestation
+            );
             require!(
-                vault_lamports >= service_fee_value,
-                PromoError::InsufficientVaultBalance
+                attestation.region_code == campaign.location_region_code,
+                PromoError::InvalidLocationAttestation
             );
-
-            transfer_lamports(
-                &vault.to_account_info(),
-                &platform_treasury.to_account_info(),
-                service_fee_value,
-            )?;
-
-            vault.total_service_spent = vault
-                .total_service_spent
-                .checked_add(service_fee_value)
+            let age = clock
+                .unix_timestamp
+                .checked_sub(attestation.attested_at)
                 .ok_or(PromoError::Overflow)?;
+            require!(
+                age <= campaign.location_max_age_secs as i64,
+                PromoError::LocationAttestationExpired
+            );
         }
 
-        // Mark coupon as used and clear any listing flags
-        coupon.used = true;
-        coupon.listed = false;
-        coupon.sale_price_lamports = 0;
+        // Streak bonus, computed on the streak including this redemption
+        let streak = history.next_streak(clock.unix_timestamp);
+        let discount_value = redemption_discount(
+            campaign,
+            &vault.to_account_info(),
+            user_stats,
+            purchase_amount,
+            streak.0,
+            clock.unix_timestamp,
+        )?;
 
-        // Increase used coupons counter
-        campaign.used_coupons = campaign
-            .used_coupons
-            .checked_add(1)
-            .ok_or(PromoError::Overflow)?;
+        let service_fee_value = charge_service_fee(
+            &ctx.accounts.config,
+            campaign,
+            vault,
+            &platform_treasury.to_account_info(),
+            discount_value,
+        )?;
 
-        // Update campaign analytics
-        campaign.total_purchase_amount = campaign
-            .total_purchase_amount
-            .checked_add(purchase_amount)
-            .ok_or(PromoError::Overflow)?;
+        consume_coupon(
+            campaign_key,
+            campaign,
+            &vault.to_account_info(),
+            coupon,
+            &user.to_account_info(),
+            clock.unix_timestamp,
+        )?;
+        record_campaign_redemption(
+            campaign_key,
+            campaign,
+            purchase_amount,
+            discount_value,
+            clock.unix_timestamp,
+        )?;
 
-        campaign.total_discount_lamports = campaign
-            .total_discount_lamports
-            .checked_add(discount_value)
-            .ok_or(PromoError::Overflow)?;
+        // The service fee may have drained an "until funds last" campaign
+        check_budget_exhaustion(
+            campaign_key,
+            campaign,
+            &vault.to_account_info(),
+            clock.unix_timestamp,
+        )?;
+        check_low_balance(campaign_key, campaign, vault)?;
 
-        campaign.last_redeem_timestamp = clock.unix_timestamp;
+        let receipt_key = receipt.key();
+        write_receipt(
+            receipt,
+            &receipt_key,
+            campaign_key,
+            campaign,
+            coupon,
+            user.key(),
+            product_code,
+            purchase_amount,
+            discount_value,
+            clock.unix_timestamp,
+        );
+        record_customer_history(
+            history,
+            campaign.merchant,
+            user.key(),
+            1,
+            streak,
+            clock.unix_timestamp,
+        )?;
+        record_user_stats(user_stats, campaign_key, user.key(), discount_value)?;
+
+        // Count this redemption towards the achievements passed by the user
+        record_achievement_progress(
+            ctx.remaining_accounts,
+            &campaign_key,
+            campaign,
+            &user.key(),
+            clock.unix_timestamp,
+        )?;
+
+        // Companion loyalty-token reward, paid by the vault PDA
+        if campaign.reward_mint != Pubkey::default() {
+            let (Some(mint), Some(source), Some(destination), Some(token_program)) = (
+                ctx.accounts.reward_mint.as_ref(),
+                ctx.accounts.reward_source.as_ref(),
+                ctx.accounts.reward_destination.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) else {
+                return err!(PromoError::RewardAccountsRequired);
+            };
+
+            require_keys_eq!(mint.key(), campaign.reward_mint, PromoError::InvalidRewardAccount);
+            require_keys_eq!(source.mint, campaign.reward_mint, PromoError::InvalidRewardAccount);
+            require_keys_eq!(source.owner, vault.key(), PromoError::InvalidRewardAccount);
+            require_keys_eq!(destination.mint, campaign.reward_mint, PromoError::InvalidRewardAccount);
+            require_keys_eq!(destination.owner, user.key(), PromoError::InvalidRewardAccount);
+
+            let reward = campaign.reward_amount.min(source.amount);
+            if reward > 0 {
+                let signer_seeds: &[&[&[u8]]] =
+                    &[&[b"vault", campaign_key.as_ref(), &[vault.bump]]];
+                let cpi_accounts = TransferChecked {
+                    from: source.to_account_info(),
+                    mint: mint.to_account_info(),
+                    to: destination.to_account_info(),
+                    authority: vault.to_account_info(),
+                };
+                let cpi_ctx = CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    cpi_accounts,
+                    signer_seeds,
+                );
+                token::transfer_checked(cpi_ctx, reward, mint.decimals)?;
+            }
+        }
 
         // Emit event so the frontend/indexer can aggregate analytics (ROI, etc.)
         emit!(CouponRedeemed {
             merchant: campaign.merchant,
-            campaign: campaign.key(),
+            campaign: campaign_key,
+            partner: campaign.partner,
+            partner_share_bps: campaign.partner_share_bps,
             campaign_id: campaign.campaign_id,
             category_code: campaign.category_code,
-            product_code: campaign.product_code,
+            product_code,
             coupon_index: coupon.coupon_index,
+            serial: coupon.serial,
             purchase_amount,
             discount_value,
             service_fee_value,
+            sequence: campaign.used_coupons,
         });
 
         // Burn coupon: close account and return rent to user
@@ -144,19 +344,31 @@ use crate::states::*;
 pub struct CouponRedeemed {
     pub merchant: Pubkey,
     pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
     pub campaign_id: u64,
     pub category_code: u16,
     pub product_code: u16,
     pub coupon_index: u64,
+    /// Merchant serial of the coupon (zero if none was set at mint), for ERP reconciliation.
+    pub serial: [u8; 32],
     pub purchase_amount: u64,
     pub discount_value: u64,
     pub service_fee_value: u64,
+    /// Redemption sequence number in the campaign (1-based), for export checkpoints.
+    pub sequence: u32,
 }
 
-
 /// Accounts required to redeem a coupon.
 #[derive(Accounts)]
 pub struct RedeemCoupon<'info> {
+    /// Global config – fee switches.
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
     /// Campaign this coupon belongs to.
     #[account(mut)]
     pub campaign: Account<'info, Campaign>,
@@ -184,14 +396,92 @@ pub struct RedeemCoupon<'info> {
     )]
     pub coupon: Account<'info, Coupon>,
 
+    /// Receipt PDA recording this redemption. One PDA per (campaign, coupon_index).
+    #[account(
+        init,
+        payer = user,
+        space = 8 + RedemptionReceipt::SIZE,
+        seeds = [
+            b"receipt",
+            campaign.key().as_ref(),
+            &coupon.coupon_index.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub receipt: Account<'info, RedemptionReceipt>,
+
+    /// Per-(merchant, user) redemption history, created on the first redemption.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + CustomerHistory::SIZE,
+        seeds = [
+            b"history",
+            campaign.merchant.as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub customer_history: Account<'info, CustomerHistory>,
+
+    /// Per-(campaign, user) stats, created on the user's first redemption in the campaign.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserStats::SIZE,
+        seeds = [
+            b"user_stats",
+            campaign.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
     /// User redeeming the coupon (must be the coupon owner).
     #[account(mut)]
     pub user: Signer<'info>,
 
     /// CHECK: This is the platform treasury account that will receive real lamports
     /// from the vault corresponding to the service fee.
-    #[account(mut)]
+    #[account(
+        mut,
+        constraint = config.platform_treasury != Pubkey::default()
+            && platform_treasury.key() == config.platform_treasury
+            @ PromoError::InvalidPlatformTreasury
+    )]
     pub platform_treasury: UncheckedAccount<'info>,
 
+    /// Location attestation for the user (required only if `campaign.location_oracle` is set).
+    pub location_attestation: Option<Account<'info, LocationAttestation>>,
+
+    /// Campaign verifier co-signing the redemption (required only if
+    /// `campaign.redemption_verifier` is set).
+    pub verifier: Option<Signer<'info>>,
+
+    /// Oracle feed of the redemption condition (required only if
+    /// `campaign.condition_oracle` is set).
+    pub condition_feed: Option<Account<'info, OracleFeed>>,
+
+    /// POS terminal holding the coupon reservation (required only while the
+    /// coupon is reserved, see `reserve_coupon`).
+    pub terminal: Option<Signer<'info>>,
+
+    /// Scanned QR nonce (required only if `campaign.requires_qr_nonce`).
+    #[account(mut)]
+    pub qr_nonce: Option<Account<'info, QrNonce>>,
+
+    /// Companion reward accounts (required only if `campaign.reward_mint` is set):
+    /// the vault-owned source and the user's destination token account.
+    pub reward_mint: Option<Account<'info, Mint>>,
+
+    #[account(mut)]
+    pub reward_source: Option<Account<'info, TokenAccount>>,
+
+    #[account(mut)]
+    pub reward_destination: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
     pub system_program: Program<'info, System>,
 }
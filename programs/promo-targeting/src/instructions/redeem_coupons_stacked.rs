@@ -0,0 +1,356 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+use super::redeem_coupon::CouponRedeemed;
+
+/// Redeem several coupons of the same merchant for one purchase (e.g. a
+/// category coupon plus a product coupon in one checkout).
+///
+/// `remaining_accounts` is a flat list of
+/// `(campaign, vault, coupon, receipt, user_stats)` tuples, one per coupon:
+/// - `campaign`: the coupon's campaign (writable)
+/// - `vault`: the vault PDA `[b"vault", campaign]` (writable)
+/// - `coupon`: the coupon to redeem (writable, burned to the user)
+/// - `receipt`: the receipt PDA `[b"receipt", campaign, coupon_index]` (writable, uninitialized)
+/// - `user_stats`: the stats PDA `[b"user_stats", campaign, user]` (writable, created if needed)
+///
+/// Stacking rules:
+/// - every campaign must be `stackable` and belong to `merchant`
+/// - at most one coupon per campaign, at most `Campaign::MAX_STACKED_COUPONS` coupons
+/// - the combined discount is capped at the smallest `max_stacked_discount_bps`
+///   of the stacked campaigns (as a share of `purchase_amount`) and never
+///   exceeds the purchase; coupons are applied in order, and a coupon left
+///   with nothing to discount fails the checkout (`StackedDiscountCapReached`)
+///
+/// Each coupon is otherwise validated, capped, charged and recorded like in
+/// `redeem_coupon` (schedule, product, period, per-user cap, budget taper,
+/// service fee, gas rebate, daily pacing, receipt, `CustomerHistory`,
+/// `UserStats`, `CouponRedeemed`, reservation by the POS `terminal`).
+/// Campaigns that need extra redemption accounts (location attestation,
+/// verifier, oracle condition, QR nonce, companion reward) or carry store
+/// credit cannot be stacked, and stacked redemptions do not count towards
+/// achievements.
+pub fn redeem_coupons_stacked<'info>(
+    ctx: Context<'_, '_, 'info, 'info, RedeemCouponsStacked<'info>>,
+    purchase_amount: u64,
+    product_code: u16,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let history = &mut ctx.accounts.customer_history;
+    let user = &ctx.accounts.user;
+    let merchant_key = ctx.accounts.merchant.key();
+    let platform_treasury = &ctx.accounts.platform_treasury;
+    let system_program = &ctx.accounts.system_program;
+    let terminal = ctx.accounts.terminal.as_ref().map(|terminal| terminal.key());
+    let remaining = ctx.remaining_accounts;
+
+    let clock = Clock::get()?;
+
+    require!(
+        remaining.len() >= 10
+            && remaining.chunks_exact(5).remainder().is_empty()
+            && remaining.len() / 5 <= Campaign::MAX_STACKED_COUPONS,
+        PromoError::InvalidBatch
+    );
+
+    // Load and validate every campaign first, so the combined cap is known
+    // before any coupon is charged
+    let mut campaigns = Vec::with_capacity(remaining.len() / 5);
+    let mut stack_cap = purchase_amount;
+    for accounts in remaining.chunks(5) {
+        let campaign = Account::<Campaign>::try_from(&accounts[0])?;
+
+        require!(
+            campaign.stackable
+                && campaign.location_oracle == Pubkey::default()
+                && campaign.redemption_verifier == Pubkey::default()
+                && campaign.condition_oracle == Pubkey::default()
+                && !campaign.requires_qr_nonce
+                && campaign.reward_mint == Pubkey::default()
+                && campaign.coupon_value_lamports == 0,
+            PromoError::CouponNotStackable
+        );
+        require_keys_eq!(campaign.merchant, merchant_key, PromoError::CouponNotStackable);
+        require!(
+            !campaigns
+                .iter()
+                .any(|c: &Account<Campaign>| c.key() == campaign.key()),
+            PromoError::CouponNotStackable
+        );
+
+        if campaign.max_stacked_discount_bps > 0 {
+            stack_cap = stack_cap.min(apply_bps(
+                purchase_amount,
+                campaign.max_stacked_discount_bps as u64,
+                campaign.rounding_mode,
+            )?);
+        }
+
+        campaigns.push(campaign);
+    }
+
+    // First-purchase-only campaigns are checked against the history before this checkout
+    let prior_redemptions = history.redemption_count;
+    let streak = history.next_streak(clock.unix_timestamp);
+
+    let mut total_discount: u64 = 0;
+    let mut stacked_campaigns = Vec::with_capacity(campaigns.len());
+    let mut partners = Vec::with_capacity(campaigns.len());
+    let mut partner_share_bps = Vec::with_capacity(campaigns.len());
+    for (mut campaign, accounts) in campaigns.into_iter().zip(remaining.chunks(5)) {
+        let vault_info = &accounts[1];
+        let coupon_info = &accounts[2];
+        let receipt_info = &accounts[3];
+        let user_stats_info = &accounts[4];
+        let campaign_key = campaign.key();
+
+        let (vault_key, _) =
+            Pubkey::find_program_address(&[b"vault", campaign_key.as_ref()], &crate::ID);
+        require_keys_eq!(vault_info.key(), vault_key, PromoError::InvalidBatch);
+        let mut vault = Account::<Vault>::try_from(vault_info)?;
+        let mut coupon = Account::<Coupon>::try_from(coupon_info)?;
+
+        validate_redemption(
+            &campaign_key,
+            &campaign,
+            &coupon,
+            &user.key(),
+            product_code,
+            terminal,
+            &clock,
+        )?;
+        if campaign.first_purchase_only {
+            require!(prior_redemptions == 0, PromoError::FirstPurchaseOnly);
+        }
+
+        // Receipt and per-campaign stats PDAs
+        let coupon_index_bytes = coupon.coupon_index.to_le_bytes();
+        let (receipt_key, receipt_bump) = Pubkey::find_program_address(
+            &[b"receipt", campaign_key.as_ref(), &coupon_index_bytes],
+            &crate::ID,
+        );
+        require_keys_eq!(receipt_info.key(), receipt_key, PromoError::InvalidBatch);
+        create_program_account(
+            user,
+            receipt_info,
+            8 + RedemptionReceipt::SIZE,
+            &[
+                b"receipt",
+                campaign_key.as_ref(),
+                &coupon_index_bytes,
+                &[receipt_bump],
+            ],
+            system_program,
+        )?;
+        let mut receipt = Account::<RedemptionReceipt>::try_from_unchecked(receipt_info)?;
+
+        let user_key = user.key();
+        let (user_stats_key, user_stats_bump) = Pubkey::find_program_address(
+            &[b"user_stats", campaign_key.as_ref(), user_key.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(user_stats_info.key(), user_stats_key, PromoError::InvalidBatch);
+        let mut user_stats = if user_stats_info.data_is_empty() {
+            create_program_account(
+                user,
+                user_stats_info,
+                8 + UserStats::SIZE,
+                &[
+                    b"user_stats",
+                    campaign_key.as_ref(),
+                    user_key.as_ref(),
+                    &[user_stats_bump],
+                ],
+                system_program,
+            )?;
+            Account::<UserStats>::try_from_unchecked(user_stats_info)?
+        } else {
+            Account::<UserStats>::try_from(user_stats_info)?
+        };
+
+        // Discount, with the same caps as a single redemption
+        let mut discount_value = redemption_discount(
+            &campaign,
+            &vault.to_account_info(),
+            &user_stats,
+            purchase_amount,
+            streak.0,
+            clock.unix_timestamp,
+        )?;
+
+        // Combined cap of the stack
+        let stack_remaining = stack_cap.saturating_sub(total_discount);
+        require!(stack_remaining > 0, PromoError::StackedDiscountCapReached);
+        discount_value = discount_value.min(stack_remaining);
+        total_discount = total_discount
+            .checked_add(discount_value)
+            .ok_or(PromoError::Overflow)?;
+
+        let service_fee_value = charge_service_fee(
+            config,
+            &campaign,
+            &mut vault,
+            &platform_treasury.to_account_info(),
+            discount_value,
+        )?;
+
+        consume_coupon(
+            campaign_key,
+            &mut campaign,
+            &vault.to_account_info(),
+            &mut coupon,
+            &user.to_account_info(),
+            clock.unix_timestamp,
+        )?;
+        // Campaign analytics: each campaign accounts the whole purchase
+        record_campaign_redemption(
+            campaign_key,
+            &mut campaign,
+            purchase_amount,
+            discount_value,
+            clock.unix_timestamp,
+        )?;
+
+        check_budget_exhaustion(
+            campaign_key,
+            &mut campaign,
+            &vault.to_account_info(),
+            clock.unix_timestamp,
+        )?;
+        check_low_balance(campaign_key, &campaign, &vault)?;
+
+        write_receipt(
+            &mut receipt,
+            &receipt_key,
+            campaign_key,
+            &mut campaign,
+            &coupon,
+            user_key,
+            product_code,
+            purchase_amount,
+            discount_value,
+            clock.unix_timestamp,
+        );
+        record_user_stats(&mut user_stats, campaign_key, user_key, discount_value)?;
+
+        emit!(CouponRedeemed {
+            merchant: campaign.merchant,
+            campaign: campaign_key,
+            partner: campaign.partner,
+            partner_share_bps: campaign.partner_share_bps,
+            campaign_id: campaign.campaign_id,
+            category_code: campaign.category_code,
+            product_code,
+            coupon_index: coupon.coupon_index,
+            serial: coupon.serial,
+            purchase_amount,
+            discount_value,
+            service_fee_value,
+            sequence: campaign.used_coupons,
+        });
+
+        stacked_campaigns.push(campaign_key);
+        partners.push(campaign.partner);
+        partner_share_bps.push(campaign.partner_share_bps);
+
+        campaign.exit(&crate::ID)?;
+        vault.exit(&crate::ID)?;
+        receipt.exit(&crate::ID)?;
+        user_stats.exit(&crate::ID)?;
+
+        // Burn the coupon, rent back to the user
+        coupon.close(user.to_account_info())?;
+    }
+
+    // One customer-history update per coupon, all at the same merchant
+    let redeemed = (remaining.len() / 5) as u32;
+    record_customer_history(
+        history,
+        merchant_key,
+        user.key(),
+        redeemed,
+        streak,
+        clock.unix_timestamp,
+    )?;
+
+    emit!(CouponsStackedRedeemed {
+        merchant: merchant_key,
+        campaigns: stacked_campaigns,
+        partners,
+        partner_share_bps,
+        user: user.key(),
+        product_code,
+        purchase_amount,
+        coupon_count: redeemed as u8,
+        total_discount,
+    });
+
+    Ok(())
+}
+
+/// Event emitted once per stacked checkout, after the per-coupon `CouponRedeemed` events.
+/// `campaigns`, `partners` and `partner_share_bps` have one entry per coupon,
+/// in stack order.
+#[event]
+pub struct CouponsStackedRedeemed {
+    pub merchant: Pubkey,
+    pub campaigns: Vec<Pubkey>,
+    pub partners: Vec<Pubkey>,
+    pub partner_share_bps: Vec<u16>,
+    pub user: Pubkey,
+    pub product_code: u16,
+    pub purchase_amount: u64,
+    pub coupon_count: u8,
+    pub total_discount: u64,
+}
+
+/// Redeem stacked coupons; the per-coupon accounts are passed via `remaining_accounts`.
+#[derive(Accounts)]
+pub struct RedeemCouponsStacked<'info> {
+    /// Global config – fee switches.
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// CHECK: Merchant of every stacked campaign (checked per campaign); only
+    /// used to derive the customer history.
+    pub merchant: UncheckedAccount<'info>,
+
+    /// Per-(merchant, user) redemption history, created on the first redemption.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + CustomerHistory::SIZE,
+        seeds = [
+            b"history",
+            merchant.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub customer_history: Account<'info, CustomerHistory>,
+
+    /// User redeeming the coupons (must own every coupon).
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: Platform treasury receiving the service fees.
+    #[account(
+        mut,
+        constraint = config.platform_treasury != Pubkey::default()
+            && platform_treasury.key() == config.platform_treasury
+            @ PromoError::InvalidPlatformTreasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// POS terminal holding the coupon reservations (required only if one of
+    /// the coupons is reserved, see `reserve_coupon`).
+    pub terminal: Option<Signer<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,274 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Spend part of a value-bearing coupon (store credit, see `set_coupon_value`)
+/// on a purchase.
+///
+/// - `amount` is taken off `coupon.remaining_value` and counts as the
+///   discount of this purchase (at most `purchase_amount`, and within the
+///   wallet's `max_discount_per_user` allowance).
+/// - The service fee is paid from the vault, as in `redeem_coupon`, on the
+///   credit spent so far (each call pays the fee on the new total minus the
+///   fee already paid), so splitting the credit doesn't round the fee away.
+/// - Each call counts towards `max_redemptions_per_day` /
+///   `daily_budget_lamports` and the campaign analytics; an `amount` above
+///   what is left of the day's budget is rejected.
+/// - Once the value is fully consumed the coupon counts as used (completion,
+///   gas rebate), as one redemption of the whole credit in `CustomerHistory`
+///   and `UserStats`, and is burned (rent back to the user).
+///
+/// Coupons reserved with `reserve_coupon` require the reserving `terminal`
+/// to co-sign; the reservation ends with the redemption.
+///
+/// No `RedemptionReceipt` is written for store credit; indexers follow the
+/// `CouponPartiallyRedeemed` events. Campaigns that need extra redemption
+/// accounts (location attestation, verifier, oracle condition, QR nonce,
+/// companion reward) can't issue store credit.
+pub fn redeem_partial(
+    ctx: Context<RedeemPartial>,
+    purchase_amount: u64,
+    amount: u64,
+    product_code: u16,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let vault = &mut ctx.accounts.vault;
+    let coupon = &mut ctx.accounts.coupon;
+    let history = &mut ctx.accounts.customer_history;
+    let user_stats = &mut ctx.accounts.user_stats;
+    let user = &ctx.accounts.user;
+    let platform_treasury = &ctx.accounts.platform_treasury;
+
+    let clock = Clock::get()?;
+    let campaign_key = campaign.key();
+
+    require!(campaign.coupon_value_lamports > 0, PromoError::NotValueCoupon);
+    require!(
+        campaign.location_oracle == Pubkey::default()
+            && campaign.redemption_verifier == Pubkey::default()
+            && campaign.condition_oracle == Pubkey::default()
+            && !campaign.requires_qr_nonce
+            && campaign.reward_mint == Pubkey::default(),
+        PromoError::PartialRedemptionNotSupported
+    );
+
+    validate_redemption(
+        &campaign_key,
+        campaign,
+        coupon,
+        &user.key(),
+        product_code,
+        ctx.accounts.terminal.as_ref().map(|terminal| terminal.key()),
+        &clock,
+    )?;
+
+    // First-purchase-only credit can still be spent after its first use
+    if campaign.first_purchase_only && coupon.remaining_value == campaign.coupon_value_lamports {
+        require!(history.redemption_count == 0, PromoError::FirstPurchaseOnly);
+    }
+
+    require!(
+        amount > 0 && amount <= coupon.remaining_value && amount <= purchase_amount,
+        PromoError::InvalidRedemptionAmount
+    );
+    // Credit already spent from this coupon (recorded in `UserStats` only
+    // once the coupon is consumed)
+    let spent = campaign
+        .coupon_value_lamports
+        .saturating_sub(coupon.remaining_value);
+    if campaign.max_discount_per_user > 0 {
+        let remaining = campaign
+            .max_discount_per_user
+            .saturating_sub(user_stats.total_discount_lamports)
+            .saturating_sub(spent);
+        require!(amount <= remaining, PromoError::UserDiscountCapReached);
+    }
+
+    // Service fee on the cumulative spend
+    let service_fee_value = if ctx.accounts.config.redemption_fee_disabled {
+        0
+    } else {
+        let fee_bps = campaign.service_fee_bps as u64;
+        let fee_before = apply_bps(spent, fee_bps, campaign.rounding_mode)?;
+        let fee_after = apply_bps(spent + amount, fee_bps, campaign.rounding_mode)?;
+        fee_after.saturating_sub(fee_before)
+    };
+    pay_service_fee(
+        vault,
+        &platform_treasury.to_account_info(),
+        service_fee_value,
+    )?;
+
+    // The checkout is done: the remaining credit is free again
+    coupon.remaining_value -= amount;
+    coupon.reserved_by = Pubkey::default();
+    coupon.reserved_until_slot = 0;
+    let consumed = coupon.remaining_value == 0;
+
+    if consumed {
+        consume_coupon(
+            campaign_key,
+            campaign,
+            &vault.to_account_info(),
+            coupon,
+            &user.to_account_info(),
+            clock.unix_timestamp,
+        )?;
+    }
+
+    record_campaign_redemption(
+        campaign_key,
+        campaign,
+        purchase_amount,
+        amount,
+        clock.unix_timestamp,
+    )?;
+
+    check_budget_exhaustion(
+        campaign_key,
+        campaign,
+        &vault.to_account_info(),
+        clock.unix_timestamp,
+    )?;
+    check_low_balance(campaign_key, campaign, vault)?;
+
+    // The coupon counts as one redemption of its whole credit, once consumed
+    if consumed {
+        let streak = history.next_streak(clock.unix_timestamp);
+        record_customer_history(
+            history,
+            campaign.merchant,
+            user.key(),
+            1,
+            streak,
+            clock.unix_timestamp,
+        )?;
+        record_user_stats(
+            user_stats,
+            campaign_key,
+            user.key(),
+            campaign.coupon_value_lamports,
+        )?;
+    }
+
+    emit!(CouponPartiallyRedeemed {
+        merchant: campaign.merchant,
+        campaign: campaign_key,
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        product_code,
+        coupon_index: coupon.coupon_index,
+        serial: coupon.serial,
+        purchase_amount,
+        amount,
+        remaining_value: coupon.remaining_value,
+        service_fee_value,
+    });
+
+    // Fully consumed: burn the coupon, rent back to the user
+    if consumed {
+        coupon.close(user.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+/// Event emitted whenever store credit is spent from a value-bearing coupon.
+#[event]
+pub struct CouponPartiallyRedeemed {
+    pub merchant: Pubkey,
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub product_code: u16,
+    pub coupon_index: u64,
+    pub serial: [u8; 32],
+    pub purchase_amount: u64,
+    pub amount: u64,
+    /// Value left on the coupon (0 = consumed and burned).
+    pub remaining_value: u64,
+    pub service_fee_value: u64,
+}
+
+#[derive(Accounts)]
+pub struct RedeemPartial<'info> {
+    /// Global config – fee switches.
+    #[account(
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Campaign this coupon belongs to.
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Vault associated with this campaign.
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Value-bearing coupon; closed to the user once its value is consumed.
+    #[account(
+        mut,
+        has_one = campaign @ PromoError::InvalidCouponCampaign,
+        constraint = coupon.owner == user.key() @ PromoError::NotCouponOwner
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    /// Per-(merchant, user) redemption history, created on the first redemption.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + CustomerHistory::SIZE,
+        seeds = [
+            b"history",
+            campaign.merchant.as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub customer_history: Account<'info, CustomerHistory>,
+
+    /// Per-(campaign, user) stats, created on the user's first redemption in the campaign.
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + UserStats::SIZE,
+        seeds = [
+            b"user_stats",
+            campaign.key().as_ref(),
+            user.key().as_ref(),
+        ],
+        bump
+    )]
+    pub user_stats: Account<'info, UserStats>,
+
+    /// Coupon owner spending the credit.
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// CHECK: This is the platform treasury account that will receive real lamports
+    /// from the vault corresponding to the service fee.
+    #[account(
+        mut,
+        constraint = config.platform_treasury != Pubkey::default()
+            && platform_treasury.key() == config.platform_treasury
+            @ PromoError::InvalidPlatformTreasury
+    )]
+    pub platform_treasury: UncheckedAccount<'info>,
+
+    /// POS terminal holding the coupon reservation (required only while the
+    /// coupon is reserved, see `reserve_coupon`).
+    pub terminal: Option<Signer<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin registers a new category code (e.g. `1 = coffee`) campaigns can use.
+///
+/// The registry PDA is created on first use. Codes are never removed, only
+/// deprecated (see `deprecate_category`), so past analytics stay readable.
+pub fn register_category(ctx: Context<RegisterCategory>, code: u16, name: String) -> Result<()> {
+    let registry = &mut ctx.accounts.category_registry;
+
+    require!(
+        name.len() <= CategoryEntry::MAX_NAME_LEN,
+        PromoError::NameTooLong
+    );
+    require!(
+        !registry.categories.iter().any(|entry| entry.code == code),
+        PromoError::CategoryAlreadyRegistered
+    );
+    require!(
+        registry.categories.len() < CategoryRegistry::MAX_CATEGORIES,
+        PromoError::CategoryRegistryFull
+    );
+
+    registry.categories.push(CategoryEntry {
+        code,
+        name,
+        deprecated: false,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterCategory<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + CategoryRegistry::SIZE,
+        seeds = [b"category_registry"],
+        bump
+    )]
+    pub category_registry: Account<'info, CategoryRegistry>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
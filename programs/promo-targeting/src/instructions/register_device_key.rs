@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant registers (or updates) a device key for an in-store terminal.
+///
+/// The device can only mint coupons for this merchant's campaigns, up to
+/// `daily_quota` per UTC day, and pays the coupon rent itself. Registering a
+/// revoked device again re-enables it.
+pub fn register_device_key(
+    ctx: Context<RegisterDeviceKey>,
+    device: Pubkey,
+    daily_quota: u32,
+) -> Result<()> {
+    require!(daily_quota > 0, PromoError::InvalidDeviceQuota);
+
+    let device_key = &mut ctx.accounts.device_key;
+    device_key.merchant = ctx.accounts.merchant.key();
+    device_key.device = device;
+    device_key.daily_quota = daily_quota;
+    device_key.revoked = false;
+    device_key.bump = ctx.bumps.device_key;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(device: Pubkey)]
+pub struct RegisterDeviceKey<'info> {
+    /// Device key PDA. One PDA per (merchant, device).
+    #[account(
+        init_if_needed,
+        payer = merchant,
+        space = 8 + DeviceKey::SIZE,
+        seeds = [
+            b"device",
+            merchant.key().as_ref(),
+            device.as_ref(),
+        ],
+        bump
+    )]
+    pub device_key: Account<'info, DeviceKey>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
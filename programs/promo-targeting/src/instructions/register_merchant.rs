@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant registers their on-chain profile.
+///
+/// One `MerchantProfile` per wallet; `create_campaign` (and the batch /
+/// clone variants) require it.
+pub fn register_merchant(
+    ctx: Context<RegisterMerchant>,
+    name: String,
+    metadata_uri: String,
+) -> Result<()> {
+    require!(
+        name.len() <= MerchantProfile::MAX_NAME_LEN,
+        PromoError::NameTooLong
+    );
+    require!(
+        metadata_uri.len() <= MerchantProfile::MAX_URI_LEN,
+        PromoError::UriTooLong
+    );
+
+    let profile = &mut ctx.accounts.merchant_profile;
+    profile.merchant = ctx.accounts.merchant.key();
+    profile.name = name;
+    profile.metadata_uri = metadata_uri;
+    profile.registered_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterMerchant<'info> {
+    /// Profile PDA. One PDA per merchant wallet.
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + MerchantProfile::SIZE,
+        seeds = [
+            b"merchant_profile",
+            merchant.key().as_ref(),
+        ],
+        bump
+    )]
+    pub merchant_profile: Account<'info, MerchantProfile>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
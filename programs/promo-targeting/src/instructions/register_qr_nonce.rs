@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant registers the one-time nonce of a printed in-store QR code.
+///
+/// Only `nonce_hash = sha256(nonce)` is registered; the nonce itself stays
+/// on the printed QR until `redeem_coupon` reveals it. Each nonce can be
+/// consumed by exactly one redemption; a screenshot of an already scanned QR
+/// is rejected at any register.
+pub fn register_qr_nonce(ctx: Context<RegisterQrNonce>, nonce_hash: [u8; 32]) -> Result<()> {
+    let qr_nonce = &mut ctx.accounts.qr_nonce;
+    qr_nonce.campaign = ctx.accounts.campaign.key();
+    qr_nonce.nonce_hash = nonce_hash;
+    qr_nonce.consumed = false;
+    qr_nonce.consumed_by = Pubkey::default();
+    qr_nonce.consumed_at = 0;
+    qr_nonce.bump = ctx.bumps.qr_nonce;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(nonce_hash: [u8; 32])]
+pub struct RegisterQrNonce<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// QR nonce PDA. One PDA per (campaign, nonce hash).
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + QrNonce::SIZE,
+        seeds = [
+            b"qr_nonce",
+            campaign.key().as_ref(),
+            nonce_hash.as_ref(),
+        ],
+        bump
+    )]
+    pub qr_nonce: Account<'info, QrNonce>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
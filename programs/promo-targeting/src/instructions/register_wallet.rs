@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::states::*;
+
+/// Wallet registers with the program, starting its on-chain "account age"
+/// for campaign sybil gates (see `set_sybil_gate`). Once per wallet.
+pub fn register_wallet(ctx: Context<RegisterWallet>) -> Result<()> {
+    let registration = &mut ctx.accounts.wallet_registration;
+    registration.wallet = ctx.accounts.wallet.key();
+    registration.registered_epoch = Clock::get()?.epoch;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterWallet<'info> {
+    #[account(
+        init,
+        payer = wallet,
+        space = 8 + WalletRegistration::SIZE,
+        seeds = [
+            b"wallet_registration",
+            wallet.key().as_ref(),
+        ],
+        bump
+    )]
+    pub wallet_registration: Account<'info, WalletRegistration>,
+
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Reserving POS terminal frees the coupon before its reservation lapses
+/// (e.g. payment failed or the cart was abandoned).
+pub fn release_coupon(ctx: Context<ReleaseCoupon>) -> Result<()> {
+    let coupon = &mut ctx.accounts.coupon;
+
+    require!(
+        coupon.reserving_terminal(Clock::get()?.slot) == Some(ctx.accounts.terminal.key()),
+        PromoError::NotReservingTerminal
+    );
+
+    coupon.reserved_by = Pubkey::default();
+    coupon.reserved_until_slot = 0;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReleaseCoupon<'info> {
+    #[account(mut)]
+    pub coupon: Account<'info, Coupon>,
+
+    pub terminal: Signer<'info>,
+}
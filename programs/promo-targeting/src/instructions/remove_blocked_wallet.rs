@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant lifts a block, closing the blocklist entry (rent back to the merchant).
+pub fn remove_blocked_wallet(_ctx: Context<RemoveBlockedWallet>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveBlockedWallet<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        close = merchant,
+        has_one = campaign @ PromoError::InvalidBlockedWallet,
+        seeds = [
+            b"blocked",
+            campaign.key().as_ref(),
+            blocked_wallet.wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub blocked_wallet: Account<'info, BlockedWallet>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant revokes a delegated manager.
+pub fn remove_manager(ctx: Context<RemoveManager>, manager: Pubkey) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    let position = campaign
+        .managers
+        .iter()
+        .position(|key| *key == manager)
+        .ok_or(PromoError::InvalidManager)?;
+
+    campaign.managers.remove(position);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveManager<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant rolls an ended campaign into a new period (subscription-style
+/// weekly promos), keeping the same campaign / vault PDAs and analytics.
+///
+/// - Only once the current period ended (by time, budget or completion), and never
+///   for a cancelled campaign.
+/// - Sets the new `expiration_timestamp`, bumps `period` and resets the
+///   coupon counters and budget pacing. Coupons of previous periods can no
+///   longer be redeemed nor sold; per-wallet caps and NFT claims start over
+///   (their PDAs are keyed by period).
+/// - `deposit_amount` (optional, 0 = none) tops up the vault.
+///
+/// Coupon PDAs of previous periods still exist, so new coupons need fresh
+/// `coupon_index` values; leftovers can be cleaned up with `expire_coupon`
+/// before renewing.
+pub fn renew_campaign(
+    ctx: Context<RenewCampaign>,
+    new_expiration_timestamp: i64,
+    deposit_amount: u64,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+    let vault = &mut ctx.accounts.vault;
+
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        campaign.is_expired(now) && campaign.cancelled_at == 0,
+        PromoError::CampaignNotRenewable
+    );
+    require!(
+        new_expiration_timestamp > now,
+        PromoError::InvalidExpirationExtension
+    );
+
+    if deposit_amount > 0 {
+        let cpi_accounts = system_program::Transfer {
+            from: ctx.accounts.merchant.to_account_info(),
+            to: vault.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new(ctx.accounts.system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, deposit_amount)?;
+
+        vault.total_deposit = vault
+            .total_deposit
+            .checked_add(deposit_amount)
+            .ok_or(PromoError::Overflow)?;
+    }
+
+    campaign.period = campaign.period.checked_add(1).ok_or(PromoError::Overflow)?;
+    campaign.expiration_timestamp = new_expiration_timestamp;
+    campaign.minted_coupons = 0;
+    campaign.used_coupons = 0;
+    campaign.budget_exhausted_at = 0;
+    campaign.completed_at = 0;
+    campaign.budget_day = 0;
+    campaign.budget_day_spent = 0;
+    campaign.redemption_day = 0;
+    campaign.redemptions_today = 0;
+
+    emit!(CampaignRenewed {
+        campaign: campaign.key(),
+        merchant: campaign.merchant,
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        period: campaign.period,
+        expiration_timestamp: new_expiration_timestamp,
+        deposit_amount,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when a campaign starts a new period.
+#[event]
+pub struct CampaignRenewed {
+    pub campaign: Pubkey,
+    pub merchant: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub period: u32,
+    pub expiration_timestamp: i64,
+    pub deposit_amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct RenewCampaign<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Merchant renewing the campaign and paying the optional deposit.
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Owner and a POS terminal lock a coupon for a checkout until `expiry_slot`.
+///
+/// - While reserved the coupon cannot be transferred, listed or swapped
+///   (like a `hold_coupon` hold), and only redemptions co-signed by the
+///   reserving terminal are accepted, so two checkouts can't race for it.
+/// - `expiry_slot` must be in the future and at most
+///   `Coupon::MAX_RESERVE_SLOTS` away; the reservation lapses on its own, or
+///   the terminal frees it earlier with `release_coupon`.
+/// - The same terminal can call again to extend its reservation.
+pub fn reserve_coupon(ctx: Context<ReserveCoupon>, expiry_slot: u64) -> Result<()> {
+    let coupon = &mut ctx.accounts.coupon;
+    let terminal = ctx.accounts.terminal.key();
+    let slot = Clock::get()?.slot;
+
+    require!(!coupon.used, PromoError::CouponAlreadyUsed);
+    require!(!coupon.listed, PromoError::CouponListed);
+    if let Some(reserved_by) = coupon.reserving_terminal(slot) {
+        require_keys_eq!(reserved_by, terminal, PromoError::CouponReservedElsewhere);
+    }
+    require!(
+        expiry_slot > slot && expiry_slot - slot <= Coupon::MAX_RESERVE_SLOTS,
+        PromoError::InvalidReservationExpiry
+    );
+
+    coupon.reserved_by = terminal;
+    coupon.reserved_until_slot = expiry_slot;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReserveCoupon<'info> {
+    #[account(
+        mut,
+        constraint = coupon.owner == owner.key() @ PromoError::NotCouponOwner
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    pub owner: Signer<'info>,
+
+    /// POS terminal processing the payment; must co-sign the redemption.
+    pub terminal: Signer<'info>,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant revokes an unclaimed `ClaimTicket`; the ticket rent returns to
+/// its payer. Tickets can be revoked at any time, including after the
+/// campaign expired.
+pub fn revoke_claim_ticket(_ctx: Context<RevokeClaimTicket>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeClaimTicket<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Ticket being revoked; closed to its payer.
+    #[account(
+        mut,
+        close = payer,
+        has_one = campaign @ PromoError::InvalidClaimTicket,
+        has_one = payer @ PromoError::InvalidClaimTicket,
+        seeds = [
+            b"ticket",
+            campaign.key().as_ref(),
+            ticket.wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub ticket: Account<'info, ClaimTicket>,
+
+    /// CHECK: Original payer of the ticket rent (checked via `has_one`).
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    pub merchant: Signer<'info>,
+}
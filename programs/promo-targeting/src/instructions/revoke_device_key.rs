@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant revokes a device key; the device can no longer mint coupons.
+pub fn revoke_device_key(ctx: Context<RevokeDeviceKey>) -> Result<()> {
+    ctx.accounts.device_key.revoked = true;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeDeviceKey<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"device",
+            merchant.key().as_ref(),
+            device_key.device.as_ref(),
+        ],
+        bump = device_key.bump,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub device_key: Account<'info, DeviceKey>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant makes the campaign run "until funds last".
+///
+/// When enabled, the campaign expires as soon as the vault's uncommitted
+/// balance (what is left once the fees and rebates of the outstanding coupons
+/// are set aside) can no longer pay for another coupon (checked after every
+/// mint and redemption), not only at `expiration_timestamp`. Outstanding
+/// coupons expire with it and the merchant can clean up early with
+/// `expire_coupon` / `close_campaign_vault`.
+///
+/// Has no effect once the budget is already exhausted.
+pub fn set_budget_expiration(ctx: Context<SetBudgetExpiration>, enabled: bool) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    campaign.expire_when_budget_exhausted = enabled;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBudgetExpiration<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant makes the max discount budget-aware: at redemption the discount
+/// is also capped at `budget_taper_bps` of the remaining (spendable) vault
+/// balance (`0` = static `max_discount_lamports` only).
+///
+/// Like the other pacing settings, it can be adjusted at any time.
+pub fn set_budget_taper(ctx: Context<SetBudgetTaper>, budget_taper_bps: u16) -> Result<()> {
+    require!(budget_taper_bps <= 10_000, PromoError::InvalidBudgetTaper);
+
+    ctx.accounts.campaign.budget_taper_bps = budget_taper_bps;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBudgetTaper<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
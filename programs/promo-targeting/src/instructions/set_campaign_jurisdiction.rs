@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant declares the jurisdiction a campaign is sold in.
+///
+/// Secondary sales of its coupons are subject to that jurisdiction's
+/// `WithholdingRule`. Pass 0 for campaigns outside withholding markets.
+///
+/// The jurisdiction is locked once the first coupon has been minted.
+pub fn set_campaign_jurisdiction(
+    ctx: Context<SetCampaignJurisdiction>,
+    jurisdiction_code: u16,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.jurisdiction_code = jurisdiction_code;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCampaignJurisdiction<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant records a co-marketing partner brand on the campaign
+/// (`Pubkey::default()` = no partner).
+///
+/// `partner` and `partner_share_bps` (the partner's share of analytics
+/// attribution, not of funds) are included in every campaign event, so
+/// co-branded results can be attributed straight from chain data.
+pub fn set_campaign_partner(
+    ctx: Context<SetCampaignPartner>,
+    partner: Pubkey,
+    partner_share_bps: u16,
+) -> Result<()> {
+    require!(partner_share_bps <= 10_000, PromoError::InvalidBps);
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.partner = partner;
+    campaign.partner_share_bps = if partner == Pubkey::default() {
+        0
+    } else {
+        partner_share_bps
+    };
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCampaignPartner<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin caps how many coupons a single wallet can receive per
+/// `category_code` per epoch, across all campaigns (0 = no cap).
+///
+/// Stops coupon farming across a merchant's many campaigns of the same
+/// category. Counted in per-(wallet, category) `CategoryClaimCount` PDAs.
+pub fn set_category_claim_cap(
+    ctx: Context<SetCategoryClaimCap>,
+    category_claims_per_epoch: u32,
+) -> Result<()> {
+    ctx.accounts.config.category_claims_per_epoch = category_claims_per_epoch;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCategoryClaimCap<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
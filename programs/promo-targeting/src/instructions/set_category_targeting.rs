@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant configures behavioral targeting by past category redemptions.
+///
+/// When `min_redemptions > 0`, `mint_coupon` only accepts recipients that
+/// present at least `min_redemptions` of their own redemption receipts in
+/// `category_code` (from any merchant in the protocol).
+///
+/// Targeting is locked once the first coupon has been minted.
+pub fn set_category_targeting(
+    ctx: Context<SetCategoryTargeting>,
+    category_code: u16,
+    min_redemptions: u8,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.required_category_code = category_code;
+    campaign.min_category_redemptions = min_redemptions;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCategoryTargeting<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
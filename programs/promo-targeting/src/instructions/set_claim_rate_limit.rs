@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant throttles self-service claims to `claims_per_slot_limit` per
+/// slot (0 = unlimited), smoothing out bot rushes at launch.
+///
+/// Applies to `claim_coupon`, `claim_coupon_with_cnft`, `claim_with_voucher`
+/// and `finalize_claim`; merchant-issued `mint_coupon` is not throttled.
+/// Can be changed at any time.
+pub fn set_claim_rate_limit(
+    ctx: Context<SetClaimRateLimit>,
+    claims_per_slot_limit: u16,
+) -> Result<()> {
+    ctx.accounts.campaign.claims_per_slot_limit = claims_per_slot_limit;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetClaimRateLimit<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
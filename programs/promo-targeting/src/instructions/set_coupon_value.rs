@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant turns the campaign's coupons into store credit: each coupon is
+/// minted with `coupon_value_lamports` of value, spent over one or more
+/// purchases with `redeem_partial` (`0` = regular single-use coupons).
+///
+/// The value counts as discount, so it can't exceed `max_discount_lamports`
+/// (which bounds the vault's service-fee obligations). Locked once coupons
+/// have been minted.
+pub fn set_coupon_value(ctx: Context<SetCouponValue>, coupon_value_lamports: u64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require!(
+        coupon_value_lamports <= campaign.max_discount_lamports,
+        PromoError::InvalidCouponValue
+    );
+
+    campaign.coupon_value_lamports = coupon_value_lamports;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCouponValue<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant sets a daily cap on the discount granted by redemptions
+/// (`0` = no cap).
+///
+/// Days follow the merchant-local time of `schedule_utc_offset_mins`. Pacing
+/// can be adjusted at any time; the current day's spend is kept.
+pub fn set_daily_budget(ctx: Context<SetDailyBudget>, daily_budget_lamports: u64) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    campaign.daily_budget_lamports = daily_budget_lamports;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetDailyBudget<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
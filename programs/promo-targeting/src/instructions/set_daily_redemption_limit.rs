@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant caps the number of redemptions per day (`0` = no cap), so a
+/// fraud wave can't drain the whole budget in minutes.
+///
+/// Days follow the merchant-local time of `schedule_utc_offset_mins`. The
+/// limit can be adjusted at any time; the current day's count is kept.
+pub fn set_daily_redemption_limit(
+    ctx: Context<SetDailyRedemptionLimit>,
+    max_redemptions_per_day: u32,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    campaign.max_redemptions_per_day = max_redemptions_per_day;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetDailyRedemptionLimit<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
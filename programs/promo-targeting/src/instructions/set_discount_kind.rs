@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant chooses between a percentage discount (`discount_bps`, the
+/// default) and a fixed amount of lamports off each purchase. Both are
+/// capped by `max_discount_lamports`.
+///
+/// Locked once the first coupon has been minted.
+pub fn set_discount_kind(ctx: Context<SetDiscountKind>, discount_kind: DiscountKind) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require!(
+        discount_kind != DiscountKind::FixedLamports(0),
+        PromoError::InvalidDiscountKind
+    );
+
+    campaign.discount_kind = discount_kind;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetDiscountKind<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant schedules discount steps, e.g. 30% the first week and 15%
+/// afterwards: `[(launch, 3_000), (launch + 7 days, 1_500)]`.
+///
+/// Redemptions use the latest phase whose `start_ts` has passed, and
+/// `discount_bps` before the first one. Phases must be in strictly
+/// increasing `start_ts` order; an empty list disables phasing.
+///
+/// Locked once the first coupon has been minted.
+pub fn set_discount_phases(
+    ctx: Context<SetDiscountPhases>,
+    phases: Vec<DiscountPhase>,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require!(
+        phases.len() <= Campaign::MAX_DISCOUNT_PHASES
+            && phases.iter().all(|phase| phase.discount_bps <= 10_000)
+            && phases.windows(2).all(|pair| pair[0].start_ts < pair[1].start_ts),
+        PromoError::InvalidDiscountPhases
+    );
+
+    campaign.discount_phases = phases;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetDiscountPhases<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant plugs a third-party eligibility program into the campaign
+/// (`Pubkey::default()` = none).
+///
+/// Before issuing a coupon, `mint_coupon` / `claim_coupon` CPI into its
+/// `check_eligibility(wallet, campaign)` instruction (Anchor discriminator
+/// `sha256("global:check_eligibility")[..8]`, then both pubkeys) and only
+/// proceed if it succeeds. The hook gets one optional read-only
+/// `eligibility_state` account of its choosing.
+///
+/// The hook is locked once the first coupon has been minted.
+pub fn set_eligibility_program(
+    ctx: Context<SetEligibilityProgram>,
+    eligibility_program: Pubkey,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require_keys_neq!(eligibility_program, crate::ID, PromoError::InvalidEligibilityProgram);
+
+    campaign.eligibility_program = eligibility_program;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetEligibilityProgram<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
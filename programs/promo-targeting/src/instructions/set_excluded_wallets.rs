@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant replaces the exclusion list of a campaign.
+///
+/// Excluded wallets (employees, known abusers, wallets already covered by
+/// another promo) are rejected by `mint_coupon` and `redeem_coupon`.
+/// The list is small and stored on the campaign itself; it can be changed at
+/// any time so newly detected abusers can be carved out mid-campaign.
+pub fn set_excluded_wallets(
+    ctx: Context<SetExcludedWallets>,
+    excluded_wallets: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        excluded_wallets.len() <= Campaign::MAX_EXCLUDED_WALLETS,
+        PromoError::TooManyExcludedWallets
+    );
+
+    ctx.accounts.campaign.excluded_wallets = excluded_wallets;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetExcludedWallets<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin enables or disables protocol fee collection per flow.
+///
+/// - `mint_fee_enabled = false`: `mint_coupon` no longer charges `mint_cost_lamports`.
+/// - `redemption_fee_enabled = false`: `redeem_coupon` no longer charges the service fee.
+/// - `marketplace_fee_enabled = false`: listing purchases no longer charge the
+///   marketplace fee (see `set_marketplace_fee`).
+///
+/// Used for fee holidays (e.g. a launch month).
+///
+/// Fails once the switches are frozen by `finalize_config`.
+pub fn set_fee_switches(
+    ctx: Context<SetFeeSwitches>,
+    mint_fee_enabled: bool,
+    redemption_fee_enabled: bool,
+    marketplace_fee_enabled: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(
+        !config.is_finalized(GlobalConfig::FINALIZE_FEE_SWITCHES),
+        PromoError::ConfigFieldFinalized
+    );
+
+    config.mint_fee_disabled = !mint_fee_enabled;
+    config.redemption_fee_disabled = !redemption_fee_enabled;
+    config.marketplace_fee_disabled = !marketplace_fee_enabled;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFeeSwitches<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
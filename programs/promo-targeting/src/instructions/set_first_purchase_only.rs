@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant reserves a campaign for first-time customers.
+///
+/// When enabled, recipients (at mint) and redeemers (at redeem) must have no
+/// prior redemption with this merchant, as recorded in `CustomerHistory`.
+///
+/// The restriction is locked once the first coupon has been minted.
+pub fn set_first_purchase_only(ctx: Context<SetFirstPurchaseOnly>, enabled: bool) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require!(
+        !enabled || campaign.min_prior_redemptions == 0,
+        PromoError::InvalidLoyaltyTier
+    );
+
+    campaign.first_purchase_only = enabled;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetFirstPurchaseOnly<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
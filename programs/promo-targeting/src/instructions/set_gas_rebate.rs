@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant refunds an estimated transaction fee to redeemers from the vault
+/// (`0` = no rebate, at most `Campaign::MAX_GAS_REBATE_LAMPORTS`).
+///
+/// Rebates stop silently once the vault can no longer afford them.
+pub fn set_gas_rebate(ctx: Context<SetGasRebate>, gas_rebate_lamports: u64) -> Result<()> {
+    require!(
+        gas_rebate_lamports <= Campaign::MAX_GAS_REBATE_LAMPORTS,
+        PromoError::InvalidGasRebate
+    );
+
+    ctx.accounts.campaign.gas_rebate_lamports = gas_rebate_lamports;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGasRebate<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
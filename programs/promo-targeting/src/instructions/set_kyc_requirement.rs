@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant requires an age / KYC attestation for regulated verticals
+/// (alcohol, gambling, ...). `attestation_program = Pubkey::default()` disables it.
+///
+/// Recipients must present an attestation issued by `attestation_program`
+/// (laid out like the Solana Attestation Service) under `credential` and
+/// `schema`, with the recipient as nonce, that has not expired. Unlike
+/// targeting rules, the requirement also applies to voucher claims.
+///
+/// The requirement is locked once the first coupon has been minted.
+pub fn set_kyc_requirement(
+    ctx: Context<SetKycRequirement>,
+    attestation_program: Pubkey,
+    credential: Pubkey,
+    schema: Pubkey,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.kyc_attestation_program = attestation_program;
+    campaign.kyc_credential = credential;
+    campaign.kyc_schema = schema;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetKycRequirement<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
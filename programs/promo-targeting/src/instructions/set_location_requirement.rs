@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant makes a campaign redeemable only in a given region / store.
+///
+/// `redeem_coupon` will require a `LocationAttestation` signed by `oracle`
+/// for the redeemer, in `region_code`, at most `max_age_secs` old.
+/// Pass `Pubkey::default()` as `oracle` to disable.
+///
+/// The requirement is locked once the first coupon has been minted.
+pub fn set_location_requirement(
+    ctx: Context<SetLocationRequirement>,
+    oracle: Pubkey,
+    region_code: u16,
+    max_age_secs: u32,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.location_oracle = oracle;
+    campaign.location_region_code = region_code;
+    campaign.location_max_age_secs = max_age_secs;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLocationRequirement<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant sets the vault balance below which mints and redemptions emit
+/// a `VaultLowBalance` alert (0 = no alert).
+///
+/// Vaults created before the threshold existed must run `upgrade_vault` first.
+pub fn set_low_balance_threshold(ctx: Context<SetLowBalanceThreshold>, threshold: u64) -> Result<()> {
+    ctx.accounts.vault.low_balance_threshold = threshold;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLowBalanceThreshold<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub merchant: Signer<'info>,
+}
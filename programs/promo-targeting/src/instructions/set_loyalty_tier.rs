@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant reserves a campaign for returning customers who already redeemed
+/// at least `min_prior_redemptions` coupons at this merchant (0 = disabled).
+///
+/// Checked against the recipient's `CustomerHistory` in `mint_coupon` /
+/// `claim_coupon`. Can't be combined with `first_purchase_only`.
+///
+/// The tier is locked once the first coupon has been minted.
+pub fn set_loyalty_tier(ctx: Context<SetLoyaltyTier>, min_prior_redemptions: u32) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require!(
+        min_prior_redemptions == 0 || !campaign.first_purchase_only,
+        PromoError::InvalidLoyaltyTier
+    );
+
+    campaign.min_prior_redemptions = min_prior_redemptions;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetLoyaltyTier<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
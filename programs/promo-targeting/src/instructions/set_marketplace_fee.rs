@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin sets the protocol fee on secondary sales (`buy_listed_coupon`,
+/// `buy_listed_coupons_batch`), in bps of the sale price (0 = no fee).
+///
+/// The fee is taken out of the seller proceeds and paid to the platform
+/// treasury; `set_fee_switches` can pause it for a fee holiday.
+///
+/// Fails once the fee is frozen by `finalize_config`.
+pub fn set_marketplace_fee(ctx: Context<SetMarketplaceFee>, marketplace_fee_bps: u16) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(marketplace_fee_bps <= 10_000, PromoError::InvalidBps);
+    require!(
+        !config.is_finalized(GlobalConfig::FINALIZE_MARKETPLACE_FEE_BPS),
+        PromoError::ConfigFieldFinalized
+    );
+
+    config.marketplace_fee_bps = marketplace_fee_bps;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetMarketplaceFee<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant sets the wallet receiving vault refunds
+/// (`Pubkey::default()` = the merchant authority itself).
+///
+/// Keeps large refunds (`close_campaign_vault`, `withdraw_vault_excess`) off
+/// the operational signing key, e.g. by pointing them at a cold wallet.
+pub fn set_payout_wallet(ctx: Context<SetPayoutWallet>, payout_wallet: Pubkey) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    campaign.payout_wallet = payout_wallet;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPayoutWallet<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin sets the platform treasury that receives mint costs and service fees.
+///
+/// Configs migrated through `upgrade_config` start with no treasury, and
+/// `mint_coupon` / `redeem_coupon` fail until one is set here.
+///
+/// Fails once the treasury is frozen by `finalize_config`.
+pub fn set_platform_treasury(
+    ctx: Context<SetPlatformTreasury>,
+    platform_treasury: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    require!(
+        !config.is_finalized(GlobalConfig::FINALIZE_PLATFORM_TREASURY),
+        PromoError::ConfigFieldFinalized
+    );
+    require!(
+        platform_treasury != Pubkey::default(),
+        PromoError::InvalidPlatformTreasury
+    );
+
+    config.platform_treasury = platform_treasury;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPlatformTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
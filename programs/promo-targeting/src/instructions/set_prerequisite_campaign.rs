@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant restricts a campaign to participants of an earlier campaign.
+///
+/// Recipients must present a coupon or redemption receipt from
+/// `prerequisite_campaign` at mint, enabling sequel campaigns without
+/// exporting wallet lists. Pass `Pubkey::default()` to disable.
+///
+/// Targeting is locked once the first coupon has been minted.
+pub fn set_prerequisite_campaign(
+    ctx: Context<SetPrerequisiteCampaign>,
+    prerequisite_campaign: Pubkey,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require!(
+        prerequisite_campaign != campaign.key(),
+        PromoError::InvalidPrerequisiteProof
+    );
+
+    campaign.prerequisite_campaign = prerequisite_campaign;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPrerequisiteCampaign<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
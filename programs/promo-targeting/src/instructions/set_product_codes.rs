@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant extends the campaign to a product family: coupons can be
+/// redeemed for `product_code` or any of `extra_product_codes`
+/// (at most `Campaign::MAX_EXTRA_PRODUCT_CODES`; empty = `product_code` only).
+///
+/// Locked once the first coupon has been minted.
+pub fn set_product_codes(
+    ctx: Context<SetProductCodes>,
+    extra_product_codes: Vec<u16>,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require!(
+        extra_product_codes.len() <= Campaign::MAX_EXTRA_PRODUCT_CODES,
+        PromoError::TooManyProductCodes
+    );
+
+    campaign.extra_product_codes = extra_product_codes;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetProductCodes<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant requires every redemption to consume a registered QR nonce
+/// (in-store only campaigns).
+pub fn set_qr_nonce_required(ctx: Context<SetQrNonceRequired>, required: bool) -> Result<()> {
+    ctx.accounts.campaign.requires_qr_nonce = required;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetQrNonceRequired<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant makes redemptions conditional on an oracle feed.
+///
+/// - Redemptions unlock once the `feed_id` feed of `oracle` satisfies
+///   `feed.value <op> value` (`Campaign::CONDITION_GTE`, `CONDITION_LTE`, `CONDITION_EQ`).
+/// - `oracle = Pubkey::default()` removes the condition.
+///
+/// The condition is locked once the first coupon has been minted.
+pub fn set_redemption_condition(
+    ctx: Context<SetRedemptionCondition>,
+    oracle: Pubkey,
+    feed_id: u64,
+    op: u8,
+    value: i64,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    if oracle == Pubkey::default() {
+        campaign.condition_oracle = Pubkey::default();
+        campaign.condition_feed_id = 0;
+        campaign.condition_op = 0;
+        campaign.condition_value = 0;
+        return Ok(());
+    }
+
+    require!(
+        (Campaign::CONDITION_GTE..=Campaign::CONDITION_EQ).contains(&op),
+        PromoError::InvalidRedemptionCondition
+    );
+
+    campaign.condition_oracle = oracle;
+    campaign.condition_feed_id = feed_id;
+    campaign.condition_op = op;
+    campaign.condition_value = value;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRedemptionCondition<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant pairs redemptions with a companion SPL token reward
+/// (`reward_mint = Pubkey::default()` disables it).
+///
+/// The merchant funds a token account of `reward_mint` owned by the campaign
+/// vault PDA; `redeem_coupon` pays `reward_amount` from it to each redeemer.
+pub fn set_redemption_reward(
+    ctx: Context<SetRedemptionReward>,
+    reward_mint: Pubkey,
+    reward_amount: u64,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    if reward_mint == Pubkey::default() {
+        campaign.reward_mint = Pubkey::default();
+        campaign.reward_amount = 0;
+    } else {
+        require!(reward_amount > 0, PromoError::InvalidRewardAccount);
+        campaign.reward_mint = reward_mint;
+        campaign.reward_amount = reward_amount;
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRedemptionReward<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
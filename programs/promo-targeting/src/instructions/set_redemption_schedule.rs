@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant restricts redemptions to given weekdays and hours.
+///
+/// - `days_mask`: bit 0 = Sunday ... bit 6 = Saturday (0 = any day).
+/// - `hours_mask`: bit h allows redemptions from h:00 to h:59 (0 = any hour).
+/// - `utc_offset_mins`: merchant local time offset (between -14h and +14h).
+///
+/// The schedule is locked once the first coupon has been minted.
+pub fn set_redemption_schedule(
+    ctx: Context<SetRedemptionSchedule>,
+    days_mask: u8,
+    hours_mask: u32,
+    utc_offset_mins: i16,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require!(days_mask < (1 << 7), PromoError::InvalidSchedule);
+    require!(hours_mask < (1 << 24), PromoError::InvalidSchedule);
+    require!(
+        (-14 * 60..=14 * 60).contains(&utc_offset_mins),
+        PromoError::InvalidSchedule
+    );
+
+    campaign.redeem_days_mask = days_mask;
+    campaign.redeem_hours_mask = hours_mask;
+    campaign.schedule_utc_offset_mins = utc_offset_mins;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRedemptionSchedule<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,28 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant requires a verifier key to co-sign every `redeem_coupon` of the
+/// campaign (`Pubkey::default()` = owner signature only).
+///
+/// Meant for high-value coupons confirmed by staff at the counter. The key
+/// can be rotated at any time.
+pub fn set_redemption_verifier(ctx: Context<SetRedemptionVerifier>, verifier: Pubkey) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    campaign.redemption_verifier = verifier;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRedemptionVerifier<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
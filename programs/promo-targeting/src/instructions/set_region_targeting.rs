@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant limits coupon issuance to wallets attested to a store region.
+///
+/// `mint_coupon` / `claim_coupon` will require an Ed25519 signature by
+/// `attestor` over `recipient || region_code (u16 LE)`, verified through an
+/// Ed25519 program instruction earlier in the same transaction.
+/// Pass `Pubkey::default()` as `attestor` to disable.
+///
+/// The requirement is locked once the first coupon has been minted.
+pub fn set_region_targeting(
+    ctx: Context<SetRegionTargeting>,
+    attestor: Pubkey,
+    region_code: u16,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.region_attestor = attestor;
+    campaign.region_code = region_code;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRegionTargeting<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant reserves a campaign for users who unlocked an achievement
+/// (`Pubkey::default()` = no requirement).
+///
+/// The requirement is locked once the first coupon has been minted.
+pub fn set_required_achievement(
+    ctx: Context<SetRequiredAchievement>,
+    achievement: Pubkey,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.required_achievement = achievement;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRequiredAchievement<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
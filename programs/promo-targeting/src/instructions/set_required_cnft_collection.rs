@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant reserves a campaign for holders of a verified Bubblegum
+/// compressed NFT collection (`Pubkey::default()` = no requirement).
+///
+/// Coupons of such campaigns can only be obtained through
+/// `claim_coupon_with_cnft`, one coupon per asset.
+///
+/// The requirement is locked once the first coupon has been minted.
+pub fn set_required_cnft_collection(
+    ctx: Context<SetRequiredCnftCollection>,
+    collection: Pubkey,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.required_cnft_collection = collection;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRequiredCnftCollection<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant reserves a campaign for holders of a verified Metaplex NFT
+/// collection (`Pubkey::default()` = no requirement).
+///
+/// Collection-gated campaigns can also be self-claimed by holders through
+/// `claim_coupon`, one coupon per NFT.
+///
+/// The requirement is locked once the first coupon has been minted.
+pub fn set_required_collection(
+    ctx: Context<SetRequiredCollection>,
+    collection: Pubkey,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.required_collection = collection;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRequiredCollection<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin sets the rounding applied to bps math (discounts, service fees,
+/// resale caps and withholding).
+///
+/// - `GlobalConfig::ROUNDING_FLOOR` (default), `ROUNDING_CEIL` or
+///   `ROUNDING_BANKERS` (round half to even).
+/// - Campaigns copy the mode at creation, like `service_fee_bps`, so existing
+///   campaigns keep the rounding they were created with.
+/// - Fails once the mode is frozen by `finalize_config`.
+pub fn set_rounding_mode(ctx: Context<SetRoundingMode>, rounding_mode: u8) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    require!(
+        !config.is_finalized(GlobalConfig::FINALIZE_ROUNDING_MODE),
+        PromoError::ConfigFieldFinalized
+    );
+    require!(
+        rounding_mode <= GlobalConfig::ROUNDING_BANKERS,
+        PromoError::InvalidRoundingMode
+    );
+
+    config.rounding_mode = rounding_mode;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRoundingMode<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    pub admin: Signer<'info>,
+}
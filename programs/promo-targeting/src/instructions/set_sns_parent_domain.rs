@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant reserves a campaign for owners of a Solana Name Service domain
+/// under `parent_domain` (the parent name account, e.g. a community `.sol`
+/// domain). `Pubkey::default()` = no requirement.
+///
+/// The requirement is locked once the first coupon has been minted.
+pub fn set_sns_parent_domain(
+    ctx: Context<SetSnsParentDomain>,
+    parent_domain: Pubkey,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.sns_parent_domain = parent_domain;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetSnsParentDomain<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant sets whether the campaign's coupons can be combined with coupons
+/// of other campaigns in one checkout (see `redeem_coupons_stacked`), and the
+/// cap on the combined discount of such a checkout, in bps of the purchase
+/// (`0` = no cap beyond each coupon's own caps).
+///
+/// Stacking changes what a minted coupon is worth, so it is locked once
+/// coupons have been minted.
+pub fn set_stacking_policy(
+    ctx: Context<SetStackingPolicy>,
+    stackable: bool,
+    max_stacked_discount_bps: u16,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require!(
+        max_stacked_discount_bps <= 10_000 && (stackable || max_stacked_discount_bps == 0),
+        PromoError::InvalidStackingPolicy
+    );
+
+    campaign.stackable = stackable;
+    campaign.max_stacked_discount_bps = max_stacked_discount_bps;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetStackingPolicy<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
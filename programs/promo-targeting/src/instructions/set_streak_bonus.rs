@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant grants escalating discounts to customers on a weekly streak.
+///
+/// At redemption the discount becomes
+/// `discount_bps + min(streak_bonus_bps * (streak - 1), max_streak_bonus_bps)`,
+/// where `streak` is the customer's consecutive-week count at this merchant.
+/// `streak_bonus_bps = 0` disables the bonus.
+pub fn set_streak_bonus(
+    ctx: Context<SetStreakBonus>,
+    streak_bonus_bps: u16,
+    max_streak_bonus_bps: u16,
+) -> Result<()> {
+    require!(streak_bonus_bps <= 10_000, PromoError::InvalidBps);
+    require!(max_streak_bonus_bps <= 10_000, PromoError::InvalidBps);
+
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.streak_bonus_bps = streak_bonus_bps;
+    campaign.max_streak_bonus_bps = max_streak_bonus_bps;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetStreakBonus<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
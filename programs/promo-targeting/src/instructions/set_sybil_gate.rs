@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant adds a sybil gate. Recipients qualify with either:
+/// - an active stake account they can withdraw from, delegating at least
+///   `min_stake_lamports` (0 = no stake path); each stake account qualifies
+///   one wallet per campaign, or
+/// - a `WalletRegistration` (see `register_wallet`) at least
+///   `min_wallet_age_epochs` epochs old (0 = no age path): wallet age itself
+///   isn't observable on-chain.
+///
+/// Both 0 = disabled.
+///
+/// The gate is locked once the first coupon has been minted.
+pub fn set_sybil_gate(
+    ctx: Context<SetSybilGate>,
+    min_stake_lamports: u64,
+    min_wallet_age_epochs: u64,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.sybil_min_stake_lamports = min_stake_lamports;
+    campaign.sybil_min_wallet_age_epochs = min_wallet_age_epochs;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetSybilGate<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant gives the targeted recipient of a `requires_wallet` campaign a
+/// claim window shorter than the campaign lifetime (0 = until expiration).
+///
+/// After `claim_deadline`, no new coupon can be issued to the target wallet,
+/// even though already issued coupons stay redeemable until the campaign
+/// expires. The deadline can't be later than `expiration_timestamp`.
+pub fn set_target_claim_deadline(
+    ctx: Context<SetTargetClaimDeadline>,
+    claim_deadline: i64,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(
+        claim_deadline >= 0 && claim_deadline <= campaign.expiration_timestamp,
+        PromoError::InvalidClaimDeadline
+    );
+    require!(
+        claim_deadline == 0 || campaign.requires_wallet,
+        PromoError::InvalidClaimDeadline
+    );
+
+    campaign.target_claim_deadline = claim_deadline;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTargetClaimDeadline<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant requires recipients to hold at least `min_amount` (base units)
+/// of the SPL mint `gate_mint`, e.g. a loyalty token.
+///
+/// `gate_mint = Pubkey::default()` removes the requirement (and
+/// `min_amount` must then be 0).
+///
+/// The gate is locked once the first coupon has been minted.
+pub fn set_token_gate(
+    ctx: Context<SetTokenGate>,
+    gate_mint: Pubkey,
+    min_amount: u64,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require!(
+        (gate_mint == Pubkey::default()) == (min_amount == 0),
+        PromoError::InvalidTokenGate
+    );
+
+    campaign.gate_mint = gate_mint;
+    campaign.gate_min_amount = min_amount;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTokenGate<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant limits how coupons change hands, to curb speculative flipping of
+/// scarce coupons. Direct transfers, secondary-market sales and swaps all
+/// count:
+/// - `max_transfers`: ownership changes per coupon (`0` = unlimited).
+/// - `transfer_cooldown_secs`: after an ownership change, the coupon can't
+///   change hands or be listed again before this delay (`0` = none).
+///
+/// Like `set_transferable`, locked once the first coupon has been minted.
+pub fn set_transfer_limits(
+    ctx: Context<SetTransferLimits>,
+    max_transfers: u16,
+    transfer_cooldown_secs: u32,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.max_transfers = max_transfers;
+    campaign.transfer_cooldown_secs = transfer_cooldown_secs;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTransferLimits<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant makes the campaign's coupons soulbound (`transferable = false`)
+/// or transferable again.
+///
+/// Soulbound coupons can't be transferred, listed, bought or swapped; they
+/// stay with the wallet they were minted to until redeemed or expired.
+///
+/// The mode is locked once the first coupon has been minted.
+pub fn set_transferable(ctx: Context<SetTransferable>, transferable: bool) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.transferable = transferable;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTransferable<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant caps the cumulative discount a single wallet can receive in the
+/// campaign (`0` = no cap).
+///
+/// Enforced at redeem against `UserStats.total_discount_lamports`, whoever
+/// the coupons were originally minted to.
+///
+/// The cap is locked once the first coupon has been minted.
+pub fn set_user_discount_cap(
+    ctx: Context<SetUserDiscountCap>,
+    max_discount_per_user: u64,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.max_discount_per_user = max_discount_per_user;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetUserDiscountCap<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
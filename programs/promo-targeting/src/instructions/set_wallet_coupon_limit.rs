@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant caps how many coupons a single wallet can receive from the
+/// campaign, through `mint_coupon` and `claim_coupon` combined (0 = unlimited).
+///
+/// Counts are kept in per-(campaign, wallet) `WalletCouponCount` PDAs, so the
+/// limit is locked once the first coupon has been minted.
+pub fn set_wallet_coupon_limit(
+    ctx: Context<SetWalletCouponLimit>,
+    max_coupons_per_wallet: u32,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.max_coupons_per_wallet = max_coupons_per_wallet;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetWalletCouponLimit<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,29 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant restricts the campaign to whitelisted wallets (`whitelist_wallet`).
+///
+/// The restriction is locked once the first coupon has been minted; entries
+/// can still be added and removed afterwards.
+pub fn set_whitelist_required(ctx: Context<SetWhitelistRequired>, required: bool) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+
+    campaign.requires_whitelist = required;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetWhitelistRequired<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Admin creates or updates the resale withholding rule of a jurisdiction.
+///
+/// Secondary sales of coupons from campaigns in `jurisdiction_code` priced
+/// above `threshold_lamports` route `rate_bps` of the proceeds to
+/// `compliance_account`. A `rate_bps` of 0 disables withholding.
+pub fn set_withholding_rule(
+    ctx: Context<SetWithholdingRule>,
+    jurisdiction_code: u16,
+    rate_bps: u16,
+    threshold_lamports: u64,
+    compliance_account: Pubkey,
+) -> Result<()> {
+    require!(jurisdiction_code != 0, PromoError::InvalidWithholdingRule);
+    require!(rate_bps <= 10_000, PromoError::InvalidBps);
+    require!(
+        compliance_account != Pubkey::default(),
+        PromoError::InvalidWithholdingRule
+    );
+
+    let rule = &mut ctx.accounts.withholding_rule;
+    rule.jurisdiction_code = jurisdiction_code;
+    rule.rate_bps = rate_bps;
+    rule.threshold_lamports = threshold_lamports;
+    rule.compliance_account = compliance_account;
+    rule.bump = ctx.bumps.withholding_rule;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(jurisdiction_code: u16)]
+pub struct SetWithholdingRule<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    /// Withholding rule PDA. One PDA per jurisdiction.
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = 8 + WithholdingRule::SIZE,
+        seeds = [
+            b"withholding".as_ref(),
+            &jurisdiction_code.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub withholding_rule: Account<'info, WithholdingRule>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
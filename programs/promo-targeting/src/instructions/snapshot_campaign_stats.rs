@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Emit a `CampaignStatsSnapshot` event with the current campaign and vault
+/// figures, and optionally store them in the `CampaignSnapshot` PDA of the
+/// current `epoch` (overwritten by later snapshots in the same epoch).
+///
+/// Read-only on the campaign, so anyone (e.g. a dashboard crank) can call it;
+/// the caller pays the snapshot rent.
+pub fn snapshot_campaign_stats(ctx: Context<SnapshotCampaignStats>, epoch: u64) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let vault = &ctx.accounts.vault;
+
+    let clock = Clock::get()?;
+    require!(epoch == clock.epoch, PromoError::InvalidSnapshotEpoch);
+
+    let vault_lamports = vault.to_account_info().lamports();
+
+    if let Some(snapshot) = ctx.accounts.snapshot.as_mut() {
+        snapshot.campaign = campaign.key();
+        snapshot.epoch = epoch;
+        snapshot.minted_coupons = campaign.minted_coupons;
+        snapshot.used_coupons = campaign.used_coupons;
+        snapshot.total_purchase_amount = campaign.total_purchase_amount;
+        snapshot.total_discount_lamports = campaign.total_discount_lamports;
+        snapshot.vault_lamports = vault_lamports;
+        snapshot.total_mint_spent = vault.total_mint_spent;
+        snapshot.total_service_spent = vault.total_service_spent;
+        snapshot.taken_at = clock.unix_timestamp;
+    }
+
+    emit!(CampaignStatsSnapshot {
+        campaign: campaign.key(),
+        merchant: campaign.merchant,
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        epoch,
+        total_coupons: campaign.total_coupons,
+        minted_coupons: campaign.minted_coupons,
+        used_coupons: campaign.used_coupons,
+        total_purchase_amount: campaign.total_purchase_amount,
+        total_discount_lamports: campaign.total_discount_lamports,
+        last_redeem_timestamp: campaign.last_redeem_timestamp,
+        expiration_timestamp: campaign.expiration_timestamp,
+        vault_lamports,
+        total_deposit: vault.total_deposit,
+        total_mint_spent: vault.total_mint_spent,
+        total_service_spent: vault.total_service_spent,
+        matched_lamports: vault.matched_lamports,
+        taken_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Event carrying a full statistics snapshot of a campaign.
+#[event]
+pub struct CampaignStatsSnapshot {
+    pub campaign: Pubkey,
+    pub merchant: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub epoch: u64,
+    pub total_coupons: u32,
+    pub minted_coupons: u32,
+    pub used_coupons: u32,
+    pub total_purchase_amount: u64,
+    pub total_discount_lamports: u64,
+    pub last_redeem_timestamp: i64,
+    pub expiration_timestamp: i64,
+    pub vault_lamports: u64,
+    pub total_deposit: u64,
+    pub total_mint_spent: u64,
+    pub total_service_spent: u64,
+    pub matched_lamports: u64,
+    pub taken_at: i64,
+}
+
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct SnapshotCampaignStats<'info> {
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    /// Snapshot PDA of `epoch` (optional; omit to only emit the event).
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + CampaignSnapshot::SIZE,
+        seeds = [
+            b"snapshot",
+            campaign.key().as_ref(),
+            &epoch.to_le_bytes(),
+        ],
+        bump
+    )]
+    pub snapshot: Option<Account<'info, CampaignSnapshot>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
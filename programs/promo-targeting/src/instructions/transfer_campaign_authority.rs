@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant proposes a new campaign authority (first step of a handoff).
+///
+/// The new key (e.g. a rotated key or a multisig) becomes the merchant once
+/// it signs `accept_campaign_authority`. Proposing `Pubkey::default()`
+/// cancels a pending handoff.
+pub fn transfer_campaign_authority(
+    ctx: Context<TransferCampaignAuthority>,
+    new_merchant: Pubkey,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    campaign.pending_merchant = new_merchant;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferCampaignAuthority<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
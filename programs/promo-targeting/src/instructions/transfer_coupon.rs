@@ -2,18 +2,50 @@ use anchor_lang::prelude::*;
 
 use crate::errors::*;
 use crate::states::*;
+use crate::utils::*;
 
 /// Transfer a coupon (P2P) from the current owner to a new owner.
 ///
 /// This is the primitive for off-market transfers.
 /// Any existing listing is cleared when the owner changes.
-pub fn transfer_coupon(ctx: Context<TransferCoupon>) -> Result<()> {
+/// Coupons on a checkout hold (`hold_coupon`) or of a soulbound campaign
+/// (`set_transferable`) cannot be transferred. For a two-step transfer the
+/// recipient has to accept, see `approve_transfer` / `claim_transfer`.
+///
+/// Gifts can be scheduled: with `not_redeemable_before`, the coupon cannot be
+/// redeemed before that timestamp (birthday drops, holiday unlocks). The
+/// unlock must not be later than the campaign expiration; it stays with the
+/// coupon and can only be pushed later, never earlier.
+pub fn transfer_coupon(
+    ctx: Context<TransferCoupon>,
+    not_redeemable_before: Option<i64>,
+) -> Result<()> {
     let coupon = &mut ctx.accounts.coupon;
     let new_owner = &ctx.accounts.new_owner;
 
+    require!(
+        !coupon.is_held(&Clock::get()?),
+        PromoError::CouponOnHold
+    );
+    require!(
+        ctx.accounts.campaign.transferable,
+        PromoError::CouponNotTransferable
+    );
+
+    record_coupon_transfer(&ctx.accounts.campaign, coupon)?;
+
     coupon.owner = new_owner.key();
     coupon.listed = false;
     coupon.sale_price_lamports = 0;
+    coupon.reserved_buyer = Pubkey::default();
+    coupon.held_until = 0;
+    if let Some(unlock_at) = not_redeemable_before {
+        require!(
+            unlock_at <= ctx.accounts.campaign.expiration_timestamp,
+            PromoError::InvalidGiftUnlock
+        );
+        coupon.not_redeemable_before = coupon.not_redeemable_before.max(unlock_at);
+    }
 
     Ok(())
 }
@@ -21,9 +53,14 @@ pub fn transfer_coupon(ctx: Context<TransferCoupon>) -> Result<()> {
 /// Accounts for transferring coupon ownership between users.
 #[derive(Accounts)]
 pub struct TransferCoupon<'info> {
+    /// Campaign of the coupon (transferability).
+    pub campaign: Account<'info, Campaign>,
+
+
     /// Coupon whose ownership is being transferred.
     #[account(
         mut,
+        has_one = campaign @ PromoError::InvalidCouponCampaign,
         constraint = coupon.owner == current_owner.key() @ PromoError::NotCouponOwner
     )]
     pub coupon: Account<'info, Coupon>,
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+use super::freeze_campaign::CampaignFreezeChanged;
+
+/// Admin lifts a compliance freeze (see `freeze_campaign`).
+pub fn unfreeze_campaign(ctx: Context<UnfreezeCampaign>) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    campaign.frozen = false;
+
+    emit!(CampaignFreezeChanged {
+        campaign: campaign.key(),
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        merchant: campaign.merchant,
+        frozen: false,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnfreezeCampaign<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(mut)]
+    pub campaign: Account<'info, Campaign>,
+
+    pub admin: Signer<'info>,
+}
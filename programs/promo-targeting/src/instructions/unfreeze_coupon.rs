@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+use super::freeze_coupon::CouponFreezeChanged;
+
+/// Merchant lifts a coupon freeze (see `freeze_coupon`).
+pub fn unfreeze_coupon(ctx: Context<UnfreezeCoupon>) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let coupon = &mut ctx.accounts.coupon;
+
+    coupon.frozen = false;
+
+    emit!(CouponFreezeChanged {
+        campaign: coupon.campaign,
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        coupon: coupon.key(),
+        coupon_index: coupon.coupon_index,
+        frozen: false,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnfreezeCoupon<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        has_one = campaign @ PromoError::InvalidCouponCampaign
+    )]
+    pub coupon: Account<'info, Coupon>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant removes a wallet from the campaign whitelist, closing its entry
+/// (rent back to the merchant). Coupons already issued are unaffected.
+pub fn unwhitelist_wallet(_ctx: Context<UnwhitelistWallet>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnwhitelistWallet<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        close = merchant,
+        has_one = campaign @ PromoError::InvalidWhitelistEntry,
+        seeds = [
+            b"whitelist",
+            campaign.key().as_ref(),
+            whitelist_entry.wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant fixes the economic parameters and labels of a campaign:
+/// discount, max discount, mint cost, name and category / product codes.
+///
+/// Same validation as `create_campaign`. Only allowed before the first
+/// coupon is minted, so issued coupons never change value.
+pub fn update_campaign(
+    ctx: Context<UpdateCampaign>,
+    discount_bps: u16,
+    max_discount_lamports: u64,
+    mint_cost_lamports: u64,
+    campaign_name: String,
+    category_code: u16,
+    product_code: u16,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    require!(discount_bps <= 10_000, PromoError::InvalidBps);
+    require!(mint_cost_lamports > 0, PromoError::InvalidMintCost);
+    require!(max_discount_lamports > 0, PromoError::InvalidMaxDiscount);
+    require!(
+        campaign_name.len() <= Campaign::MAX_NAME_LEN,
+        PromoError::NameTooLong
+    );
+    require!(
+        ctx.accounts.category_registry.is_active(category_code),
+        PromoError::UnknownCategory
+    );
+
+    campaign.discount_bps = discount_bps;
+    campaign.max_discount_lamports = max_discount_lamports;
+    campaign.mint_cost_lamports = mint_cost_lamports;
+    campaign.campaign_name = campaign_name;
+    campaign.category_code = category_code;
+    campaign.product_code = product_code;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCampaign<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Registry of the category codes campaigns can use.
+    #[account(
+        seeds = [b"category_registry"],
+        bump
+    )]
+    pub category_registry: Account<'info, CategoryRegistry>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant points the campaign to its off-chain metadata (images, terms
+/// and conditions, localized descriptions). An empty URI clears it.
+///
+/// Purely descriptive, so it can be changed at any time.
+pub fn update_campaign_metadata(
+    ctx: Context<UpdateCampaignMetadata>,
+    metadata_uri: String,
+) -> Result<()> {
+    require!(
+        metadata_uri.len() <= Campaign::MAX_METADATA_URI_LEN,
+        PromoError::UriTooLong
+    );
+
+    ctx.accounts.campaign.metadata_uri = metadata_uri;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCampaignMetadata<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    pub merchant: Signer<'info>,
+}
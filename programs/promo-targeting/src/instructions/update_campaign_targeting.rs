@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant swaps the base targeting of an underperforming campaign:
+/// `requires_wallet` / `target_wallet` and the audience `segment`
+/// (omit the account to clear it).
+///
+/// Same rules as `create_campaign`; the other targeting rules keep their
+/// own `set_*` instructions. Only allowed before the first coupon is
+/// minted, so issued coupons never change meaning.
+pub fn update_campaign_targeting(
+    ctx: Context<UpdateCampaignTargeting>,
+    requires_wallet: bool,
+    target_wallet: Pubkey,
+) -> Result<()> {
+    let campaign = &mut ctx.accounts.campaign;
+
+    require!(campaign.minted_coupons == 0, PromoError::CampaignConfigLocked);
+    if requires_wallet {
+        require!(
+            target_wallet != Pubkey::default(),
+            PromoError::TargetWalletRequired
+        );
+    }
+
+    campaign.requires_wallet = requires_wallet;
+    campaign.target_wallet = if requires_wallet {
+        target_wallet
+    } else {
+        Pubkey::default()
+    };
+    if !requires_wallet {
+        campaign.target_claim_deadline = 0;
+    }
+
+    campaign.segment = match &ctx.accounts.segment {
+        Some(segment) => {
+            require_keys_eq!(segment.merchant, campaign.merchant, PromoError::InvalidSegment);
+            segment.key()
+        }
+        None => Pubkey::default(),
+    };
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateCampaignTargeting<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub campaign: Account<'info, Campaign>,
+
+    /// New audience segment (must belong to the merchant); omit for none.
+    pub segment: Option<Account<'info, Segment>>,
+
+    pub merchant: Signer<'info>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Update the matching rate and per-campaign cap of the matching pool.
+///
+/// Matches already granted to vaults are not affected.
+pub fn update_matching_pool(
+    ctx: Context<UpdateMatchingPool>,
+    match_bps: u16,
+    max_match_lamports: u64,
+) -> Result<()> {
+    require!(match_bps <= 10_000, PromoError::InvalidBps);
+
+    let pool = &mut ctx.accounts.matching_pool;
+    pool.match_bps = match_bps;
+    pool.max_match_lamports = max_match_lamports;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateMatchingPool<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"matching_pool"],
+        bump = matching_pool.bump
+    )]
+    pub matching_pool: Account<'info, MatchingPool>,
+
+    pub admin: Signer<'info>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant replaces the audience of an existing segment.
+///
+/// Every campaign referencing the segment picks up the new audience
+/// for future mints; already minted coupons are not affected.
+pub fn update_segment(
+    ctx: Context<UpdateSegment>,
+    name: String,
+    merkle_root: [u8; 32],
+    wallets: Vec<Pubkey>,
+) -> Result<()> {
+    require!(name.len() <= Segment::MAX_NAME_LEN, PromoError::NameTooLong);
+    require!(
+        wallets.len() <= Segment::MAX_WALLETS,
+        PromoError::TooManySegmentWallets
+    );
+
+    let segment = &mut ctx.accounts.segment;
+    segment.name = name;
+    segment.merkle_root = merkle_root;
+    segment.wallets = wallets;
+    segment.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateSegment<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub segment: Account<'info, Segment>,
+
+    pub merchant: Signer<'info>,
+}
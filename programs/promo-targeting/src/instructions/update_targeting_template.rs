@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant replaces the parameters of one of their targeting templates.
+///
+/// Campaigns already created from the template keep their own copy.
+pub fn update_targeting_template(
+    ctx: Context<UpdateTargetingTemplate>,
+    params: TargetingParams,
+) -> Result<()> {
+    params.validate()?;
+
+    let merchant = ctx.accounts.merchant.key();
+    let template = &mut ctx.accounts.template;
+    template.segment = match &ctx.accounts.segment {
+        Some(segment) => {
+            require_keys_eq!(segment.merchant, merchant, PromoError::InvalidSegment);
+            segment.key()
+        }
+        None => Pubkey::default(),
+    };
+    template.params = params;
+    template.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateTargetingTemplate<'info> {
+    #[account(
+        mut,
+        has_one = merchant @ PromoError::NotMerchant
+    )]
+    pub template: Account<'info, TargetingTemplate>,
+
+    pub merchant: Signer<'info>,
+
+    /// Audience segment to include (must belong to the merchant); omit for none.
+    pub segment: Option<Account<'info, Segment>>,
+}
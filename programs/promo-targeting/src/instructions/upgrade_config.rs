@@ -8,8 +8,15 @@ use crate::states::*;
 /// Upgrade (or update) the global configuration.
     ///
     /// This instruction allows the admin to migrate legacy config accounts
-    /// that were created before `service_fee_bps` existed, as well as update
-    /// max_resale_bps / service_fee_bps in a single call.
+    /// that were created with an older `GlobalConfig` layout (e.g. before
+    /// `service_fee_bps` existed), as well as update max_resale_bps /
+    /// service_fee_bps in a single call.
+    ///
+    /// Fields are only ever appended to `GlobalConfig`, so a legacy account is
+    /// zero-padded to the latest size: existing fields are preserved and new
+    /// trailing fields start at their zero/default value.
+    ///
+    /// Fields frozen by `finalize_config` must be passed unchanged.
     pub fn upgrade_config(
         ctx: Context<UpgradeConfig>,
         max_resale_bps: u16,
@@ -19,26 +26,27 @@ use crate::states::*;
         require!(service_fee_bps <= 10_000, PromoError::InvalidBps);
 
         let config_info = &ctx.accounts.config;
-        let mut data = config_info.try_borrow_mut_data()?;
 
         const DISCRIMINATOR_LEN: usize = 8;
-        const ADMIN_OFFSET: usize = DISCRIMINATOR_LEN;
-        const ADMIN_END: usize = ADMIN_OFFSET + 32;
+        const ADMIN_END: usize = DISCRIMINATOR_LEN + 32;
 
+        let expected_len = DISCRIMINATOR_LEN + GlobalConfig::SIZE;
+
+        // Decode the current (possibly legacy) layout, zero-padded to the latest size
+        let mut buffer = config_info.try_borrow_data()?.to_vec();
         require!(
-            data.len() >= ADMIN_END + 2,
+            buffer.len() >= ADMIN_END + 2,
             PromoError::InvalidConfigAccount
         );
-
-        let admin_bytes: [u8; 32] = data[ADMIN_OFFSET..ADMIN_END]
-            .try_into()
+        if buffer.len() < expected_len {
+            buffer.resize(expected_len, 0);
+        }
+        let mut config = GlobalConfig::try_deserialize(&mut &buffer[..])
             .map_err(|_| PromoError::InvalidConfigAccount)?;
-        let existing_admin = Pubkey::new_from_array(admin_bytes);
 
-        require_keys_eq!(existing_admin, ctx.accounts.admin.key(), PromoError::NotAdmin);
+        require_keys_eq!(config.admin, ctx.accounts.admin.key(), PromoError::NotAdmin);
 
-        let expected_len = DISCRIMINATOR_LEN + GlobalConfig::SIZE;
-        if data.len() != expected_len {
+        if config_info.data_len() != expected_len {
             let rent = Rent::get()?;
             let min_balance = rent.minimum_balance(expected_len);
             let current_balance = config_info.lamports();
@@ -55,22 +63,28 @@ use crate::states::*;
                 system_program::transfer(cpi_ctx, diff)?;
             }
 
-            config_info.realloc(expected_len, false)?;
-            data = config_info.try_borrow_mut_data()?;
+            config_info.resize(expected_len)?;
         }
 
-        for byte in data[DISCRIMINATOR_LEN..].iter_mut() {
-            *byte = 0;
-        }
+        require!(
+            !config.is_finalized(GlobalConfig::FINALIZE_MAX_RESALE_BPS)
+                || config.max_resale_bps == max_resale_bps,
+            PromoError::ConfigFieldFinalized
+        );
+        require!(
+            !config.is_finalized(GlobalConfig::FINALIZE_SERVICE_FEE_BPS)
+                || config.service_fee_bps == service_fee_bps,
+            PromoError::ConfigFieldFinalized
+        );
 
-        let updated = GlobalConfig {
-            admin: existing_admin,
-            max_resale_bps,
-            service_fee_bps,
-        };
+        config.max_resale_bps = max_resale_bps;
+        config.service_fee_bps = service_fee_bps;
 
-        let mut cursor = Cursor::new(&mut data[DISCRIMINATOR_LEN..]);
-        updated.try_serialize(&mut cursor)?;
+        // Rewrite the whole account (discriminator included) with the latest layout
+        let mut data = config_info.try_borrow_mut_data()?;
+        data.fill(0);
+        let mut cursor = Cursor::new(&mut data[..]);
+        config.try_serialize(&mut cursor)?;
 
         Ok(())
     }
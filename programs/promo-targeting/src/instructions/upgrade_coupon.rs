@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use std::io::Cursor;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Migrate a coupon to the latest `Coupon` layout.
+///
+/// Fields are only ever appended to `Coupon`, so a legacy coupon is
+/// zero-padded to the latest size: existing fields are preserved and new
+/// trailing fields start at their zero/default value (public listing, no
+/// hold, not frozen, ...). Coupons minted before a field was added can't be
+/// loaded by the instructions reading it until they are upgraded.
+///
+/// Anyone can upgrade a coupon; the payer covers the rent of the extra
+/// space, which goes to the coupon owner when the coupon is burned.
+pub fn upgrade_coupon(ctx: Context<UpgradeCoupon>) -> Result<()> {
+    let coupon_info = &ctx.accounts.coupon;
+
+    const DISCRIMINATOR_LEN: usize = 8;
+
+    let expected_len = DISCRIMINATOR_LEN + Coupon::SIZE;
+
+    // Decode the current (possibly legacy) layout, zero-padded to the latest size
+    let mut buffer = coupon_info.try_borrow_data()?.to_vec();
+    if buffer.len() < expected_len {
+        buffer.resize(expected_len, 0);
+    }
+    let coupon = Coupon::try_deserialize(&mut &buffer[..])
+        .map_err(|_| PromoError::InvalidCouponAccount)?;
+
+    // Must be a coupon PDA of this program
+    let (expected_key, _) = Pubkey::find_program_address(
+        &[
+            b"coupon",
+            coupon.campaign.as_ref(),
+            &coupon.coupon_index.to_le_bytes(),
+        ],
+        &crate::ID,
+    );
+    require_keys_eq!(expected_key, coupon_info.key(), PromoError::InvalidCouponAccount);
+
+    if coupon_info.data_len() < expected_len {
+        let rent = Rent::get()?;
+        let diff = rent
+            .minimum_balance(expected_len)
+            .saturating_sub(rent.minimum_balance(coupon_info.data_len()));
+        if diff > 0 {
+            let transfer_accounts = system_program::Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: ctx.accounts.coupon.clone(),
+            };
+            let cpi_ctx =
+                CpiContext::new(ctx.accounts.system_program.to_account_info(), transfer_accounts);
+            system_program::transfer(cpi_ctx, diff)?;
+        }
+
+        coupon_info.resize(expected_len)?;
+    }
+
+    // Rewrite the whole account (discriminator included) with the latest layout
+    let mut data = coupon_info.try_borrow_mut_data()?;
+    data.fill(0);
+    let mut cursor = Cursor::new(&mut data[..]);
+    coupon.try_serialize(&mut cursor)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpgradeCoupon<'info> {
+    /// CHECK: Legacy coupons may not match the latest struct. Owner is checked
+    /// here; discriminator and PDA address are verified in the handler.
+    #[account(
+        mut,
+        owner = crate::ID @ PromoError::InvalidCouponAccount
+    )]
+    pub coupon: AccountInfo<'info>,
+
+    /// Pays the rent of the extra space.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use std::io::Cursor;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Migrate a campaign vault to the latest `Vault` layout.
+///
+/// Fields are only ever appended to `Vault`, so a legacy vault is zero-padded
+/// to the latest size: existing fields (and the lamports it holds) are
+/// preserved and new trailing fields start at their zero/default value.
+/// The merchant pays the rent of the extra space, so the campaign budget held
+/// by the vault is untouched.
+pub fn upgrade_vault(ctx: Context<UpgradeVault>) -> Result<()> {
+    let vault_info = &ctx.accounts.vault;
+
+    const DISCRIMINATOR_LEN: usize = 8;
+
+    let expected_len = DISCRIMINATOR_LEN + Vault::SIZE;
+
+    // Decode the current (possibly legacy) layout, zero-padded to the latest size
+    let mut buffer = vault_info.try_borrow_data()?.to_vec();
+    if buffer.len() < expected_len {
+        buffer.resize(expected_len, 0);
+    }
+    let mut vault = Vault::try_deserialize(&mut &buffer[..])
+        .map_err(|_| PromoError::InvalidVaultAccount)?;
+
+    // Must be the campaign vault PDA of this merchant
+    let expected_key = Pubkey::create_program_address(
+        &[b"vault", vault.campaign.as_ref(), &[vault.bump]],
+        &crate::ID,
+    )
+    .map_err(|_| PromoError::InvalidVaultAccount)?;
+    require_keys_eq!(expected_key, vault_info.key(), PromoError::InvalidVaultAccount);
+    require_keys_eq!(vault.merchant, ctx.accounts.merchant.key(), PromoError::NotMerchant);
+
+    if vault_info.data_len() != expected_len {
+        // The merchant pays the extra rent, so the campaign budget is untouched
+        let rent = Rent::get()?;
+        let diff = rent
+            .minimum_balance(expected_len)
+            .saturating_sub(rent.minimum_balance(vault_info.data_len()));
+        if diff > 0 {
+            let transfer_accounts = system_program::Transfer {
+                from: ctx.accounts.merchant.to_account_info(),
+                to: ctx.accounts.vault.clone(),
+            };
+            let cpi_ctx =
+                CpiContext::new(ctx.accounts.system_program.to_account_info(), transfer_accounts);
+            system_program::transfer(cpi_ctx, diff)?;
+        }
+
+        vault_info.resize(expected_len)?;
+    }
+
+    vault.version = Vault::CURRENT_VERSION;
+
+    // Rewrite the whole account (discriminator included) with the latest layout
+    let mut data = vault_info.try_borrow_mut_data()?;
+    data.fill(0);
+    let mut cursor = Cursor::new(&mut data[..]);
+    vault.try_serialize(&mut cursor)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpgradeVault<'info> {
+    /// CHECK: Legacy vaults may not match the latest struct. Owner is checked
+    /// here; discriminator, PDA address and merchant are verified in the handler.
+    #[account(
+        mut,
+        owner = crate::ID @ PromoError::InvalidVaultAccount
+    )]
+    pub vault: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant adds `wallet` to the campaign whitelist.
+///
+/// Whitelist entries are cheap per-(campaign, wallet) marker PDAs, for
+/// audiences too large for `target_wallet` but not worth a Merkle segment.
+/// They are only enforced once `set_whitelist_required` is enabled.
+/// `claim_deadline` optionally closes this entry's claim window early
+/// (0 = until the campaign expires).
+pub fn whitelist_wallet(
+    ctx: Context<WhitelistWallet>,
+    wallet: Pubkey,
+    claim_deadline: i64,
+) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    require!(
+        claim_deadline >= 0 && claim_deadline <= campaign.expiration_timestamp,
+        PromoError::InvalidClaimDeadline
+    );
+
+    let entry = &mut ctx.accounts.whitelist_entry;
+    entry.campaign = campaign.key();
+    entry.wallet = wallet;
+    entry.claim_deadline = claim_deadline;
+    entry.added_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct WhitelistWallet<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    /// Whitelist entry. One PDA per (campaign, wallet).
+    #[account(
+        init,
+        payer = merchant,
+        space = 8 + WhitelistEntry::SIZE,
+        seeds = [
+            b"whitelist",
+            campaign.key().as_ref(),
+            wallet.as_ref(),
+        ],
+        bump
+    )]
+    pub whitelist_entry: Account<'info, WhitelistEntry>,
+
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
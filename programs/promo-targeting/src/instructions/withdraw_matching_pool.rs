@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Withdraw idle lamports from the matching pool back to the admin.
+///
+/// The pool always keeps its rent-exempt minimum.
+pub fn withdraw_matching_pool(ctx: Context<WithdrawMatchingPool>, amount: u64) -> Result<()> {
+    let pool_info = ctx.accounts.matching_pool.to_account_info();
+
+    let rent_floor = Rent::get()?.minimum_balance(pool_info.data_len());
+    let available = pool_info.lamports().saturating_sub(rent_floor);
+    require!(
+        amount <= available,
+        PromoError::InsufficientMatchingPoolBalance
+    );
+
+    transfer_lamports(&pool_info, &ctx.accounts.admin.to_account_info(), amount)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawMatchingPool<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump,
+        has_one = admin @ PromoError::NotAdmin
+    )]
+    pub config: Account<'info, GlobalConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"matching_pool"],
+        bump = matching_pool.bump
+    )]
+    pub matching_pool: Account<'info, MatchingPool>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+}
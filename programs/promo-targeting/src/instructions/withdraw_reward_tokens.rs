@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, Token, TokenAccount, TransferChecked};
+
+use crate::errors::*;
+use crate::states::*;
+
+/// Merchant withdraws reward tokens from a vault-owned token account.
+///
+/// Lets the merchant recover unused companion rewards (see
+/// `set_redemption_reward`) while the campaign runs; `close_campaign_vault`
+/// sweeps whatever is left.
+pub fn withdraw_reward_tokens(ctx: Context<WithdrawRewardTokens>, amount: u64) -> Result<()> {
+    let campaign_key = ctx.accounts.campaign.key();
+    let vault = &ctx.accounts.vault;
+
+    let signer_seeds: &[&[&[u8]]] = &[&[b"vault", campaign_key.as_ref(), &[vault.bump]]];
+    let cpi_accounts = TransferChecked {
+        from: ctx.accounts.reward_source.to_account_info(),
+        mint: ctx.accounts.reward_mint.to_account_info(),
+        to: ctx.accounts.destination.to_account_info(),
+        authority: vault.to_account_info(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        cpi_accounts,
+        signer_seeds,
+    );
+    token::transfer_checked(cpi_ctx, amount, ctx.accounts.reward_mint.decimals)?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawRewardTokens<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub reward_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = reward_source.owner == vault.key() @ PromoError::InvalidRewardAccount,
+        constraint = reward_source.mint == reward_mint.key() @ PromoError::InvalidRewardAccount
+    )]
+    pub reward_source: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = destination.mint == reward_mint.key() @ PromoError::InvalidRewardAccount
+    )]
+    pub destination: Account<'info, TokenAccount>,
+
+    pub merchant: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::*;
+use crate::states::*;
+use crate::utils::*;
+
+/// Merchant withdraws surplus vault lamports before campaign expiration.
+///
+/// - Only lamports above the worst-case obligations of the campaign
+///   (`vault_obligations`: mint cost, max service fee and gas rebate of
+///   every unminted coupon, max service fee and gas rebate of outstanding
+///   ones) and the rent-exempt minimum can be withdrawn.
+/// - Not available on vaults topped up by the matching pool, whose match is
+///   settled pro-rata at `close_campaign_vault`.
+/// - Funds go to the campaign payout wallet.
+/// - `total_deposit` is reduced by the withdrawn amount.
+pub fn withdraw_vault_excess(ctx: Context<WithdrawVaultExcess>, amount: u64) -> Result<()> {
+    let campaign = &ctx.accounts.campaign;
+    let vault = &mut ctx.accounts.vault;
+
+    let clock = Clock::get()?;
+    require!(
+        !campaign.is_expired(clock.unix_timestamp),
+        PromoError::CampaignExpired
+    );
+    require!(vault.matched_lamports == 0, PromoError::MatchedVaultExcessLocked);
+    require!(amount > 0, PromoError::NoVaultExcess);
+
+    let vault_info = vault.to_account_info();
+    let excess = vault_spendable(&vault_info)?.saturating_sub(vault_obligations(campaign)?);
+    require!(amount <= excess, PromoError::NoVaultExcess);
+
+    transfer_lamports(&vault_info, &ctx.accounts.payout.to_account_info(), amount)?;
+
+    vault.total_deposit = vault.total_deposit.saturating_sub(amount);
+
+    emit!(VaultExcessWithdrawn {
+        campaign: campaign.key(),
+        partner: campaign.partner,
+        partner_share_bps: campaign.partner_share_bps,
+        merchant: campaign.merchant,
+        amount,
+        remaining_excess: excess - amount,
+    });
+
+    Ok(())
+}
+
+/// Event emitted when a merchant withdraws surplus vault funds.
+#[event]
+pub struct VaultExcessWithdrawn {
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub merchant: Pubkey,
+    pub amount: u64,
+    pub remaining_excess: u64,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawVaultExcess<'info> {
+    #[account(has_one = merchant @ PromoError::NotMerchant)]
+    pub campaign: Account<'info, Campaign>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"vault",
+            campaign.key().as_ref(),
+        ],
+        bump = vault.bump
+    )]
+    pub vault: Account<'info, Vault>,
+
+    pub merchant: Signer<'info>,
+
+    /// CHECK: Receives the withdrawn lamports; must be `campaign.payout_destination()`.
+    #[account(
+        mut,
+        constraint = payout.key() == campaign.payout_destination() @ PromoError::InvalidPayoutWallet
+    )]
+    pub payout: UncheckedAccount<'info>,
+}
@@ -1,9 +1,16 @@
+// `#[program]` generates the IDL instruction handlers next to the program
+// module, at the crate root, and they call `AccountInfo::realloc` (deprecated
+// since solana-program 2.3), so this allow can't be scoped any narrower.
+#![allow(deprecated)]
+
 use anchor_lang::prelude::*;
 
 pub mod errors;
 pub use errors::*;
 
 pub mod instructions;
+// `#[program]` re-exports the handler names at the crate root as well.
+#[allow(ambiguous_glob_reexports)]
 pub use instructions::*;
 
 pub mod states;
@@ -22,8 +29,9 @@ pub mod promo_targeting {
         ctx: Context<InitializeConfig>,
         max_resale_bps: u16,
         service_fee_bps: u16,
+        platform_treasury: Pubkey,
     ) -> Result<()> {
-        initialize_config::initialize_config(ctx, max_resale_bps, service_fee_bps)
+        initialize_config::initialize_config(ctx, max_resale_bps, service_fee_bps, platform_treasury)
     }
 
     pub fn upgrade_config(
@@ -34,6 +42,7 @@ pub mod promo_targeting {
         upgrade_config::upgrade_config(ctx, max_resale_bps, service_fee_bps)
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn create_campaign(
         ctx: Context<CreateCampaign>,
         campaign_id: u64,
@@ -49,6 +58,7 @@ pub mod promo_targeting {
         deposit_amount: u64,
         requires_wallet: bool,
         target_wallet: Pubkey,
+        draft: bool,
     ) -> Result<()> {
         create_campaign::create_campaign(
             ctx,
@@ -65,34 +75,43 @@ pub mod promo_targeting {
             deposit_amount,
             requires_wallet,
             target_wallet,
+            draft,
         )
     }
 
-    pub fn mint_coupon(
-        ctx: Context<MintCoupon>,
+    pub fn mint_coupon<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintCoupon<'info>>,
         campaign_id: u64,
-        coupon_index: u64,
+        coupon_index: Option<u64>,
+        segment_proof: Vec<[u8; 32]>,
+        metadata: CouponMetadata,
+        serial: [u8; 32],
     ) -> Result<()> {
-        mint_coupon::mint_coupon(ctx, campaign_id, coupon_index)
+        mint_coupon::mint_coupon(ctx, campaign_id, coupon_index, segment_proof, metadata, serial)
     }
 
     pub fn redeem_coupon(
         ctx: Context<RedeemCoupon>,
         purchase_amount: u64,
         product_code: u16,
+        qr_nonce: Option<[u8; 32]>,
     ) -> Result<()> {
-        redeem_coupon::redeem_coupon(ctx, purchase_amount, product_code)
+        redeem_coupon::redeem_coupon(ctx, purchase_amount, product_code, qr_nonce)
     }
 
-    pub fn transfer_coupon(ctx: Context<TransferCoupon>) -> Result<()> {
-        transfer_coupon::transfer_coupon(ctx)
+    pub fn transfer_coupon(
+        ctx: Context<TransferCoupon>,
+        not_redeemable_before: Option<i64>,
+    ) -> Result<()> {
+        transfer_coupon::transfer_coupon(ctx, not_redeemable_before)
     }
 
     pub fn list_coupon_for_sale(
         ctx: Context<ListCouponForSale>,
         sale_price_lamports: u64,
+        reserved_buyer: Option<Pubkey>,
     ) -> Result<()> {
-        list_coupon_for_sale::list_coupon_for_sale(ctx, sale_price_lamports)
+        list_coupon_for_sale::list_coupon_for_sale(ctx, sale_price_lamports, reserved_buyer)
     }
 
     pub fn buy_listed_coupon(ctx: Context<BuyListedCoupon>) -> Result<()> {
@@ -110,8 +129,836 @@ pub mod promo_targeting {
     pub fn check_treasury_balance(ctx: Context<CheckTreasuryBalance>) -> Result<()> {
         check_treasury_balance::check_treasury_balance(ctx)
     }
-}
-    
 
+    pub fn initialize_matching_pool(
+        ctx: Context<InitializeMatchingPool>,
+        match_bps: u16,
+        max_match_lamports: u64,
+    ) -> Result<()> {
+        initialize_matching_pool::initialize_matching_pool(ctx, match_bps, max_match_lamports)
+    }
+
+    pub fn update_matching_pool(
+        ctx: Context<UpdateMatchingPool>,
+        match_bps: u16,
+        max_match_lamports: u64,
+    ) -> Result<()> {
+        update_matching_pool::update_matching_pool(ctx, match_bps, max_match_lamports)
+    }
+
+    pub fn fund_matching_pool(ctx: Context<FundMatchingPool>, amount: u64) -> Result<()> {
+        fund_matching_pool::fund_matching_pool(ctx, amount)
+    }
+
+    pub fn withdraw_matching_pool(ctx: Context<WithdrawMatchingPool>, amount: u64) -> Result<()> {
+        withdraw_matching_pool::withdraw_matching_pool(ctx, amount)
+    }
+
+    pub fn match_campaign_deposit(ctx: Context<MatchCampaignDeposit>) -> Result<()> {
+        match_campaign_deposit::match_campaign_deposit(ctx)
+    }
+
+    pub fn create_segment(
+        ctx: Context<CreateSegment>,
+        segment_id: u64,
+        name: String,
+        merkle_root: [u8; 32],
+        wallets: Vec<Pubkey>,
+    ) -> Result<()> {
+        create_segment::create_segment(ctx, segment_id, name, merkle_root, wallets)
+    }
+
+    pub fn update_segment(
+        ctx: Context<UpdateSegment>,
+        name: String,
+        merkle_root: [u8; 32],
+        wallets: Vec<Pubkey>,
+    ) -> Result<()> {
+        update_segment::update_segment(ctx, name, merkle_root, wallets)
+    }
+
+    pub fn set_category_targeting(
+        ctx: Context<SetCategoryTargeting>,
+        category_code: u16,
+        min_redemptions: u8,
+    ) -> Result<()> {
+        set_category_targeting::set_category_targeting(ctx, category_code, min_redemptions)
+    }
+
+    pub fn set_excluded_wallets(
+        ctx: Context<SetExcludedWallets>,
+        excluded_wallets: Vec<Pubkey>,
+    ) -> Result<()> {
+        set_excluded_wallets::set_excluded_wallets(ctx, excluded_wallets)
+    }
+
+    pub fn set_prerequisite_campaign(
+        ctx: Context<SetPrerequisiteCampaign>,
+        prerequisite_campaign: Pubkey,
+    ) -> Result<()> {
+        set_prerequisite_campaign::set_prerequisite_campaign(ctx, prerequisite_campaign)
+    }
+
+    pub fn set_location_requirement(
+        ctx: Context<SetLocationRequirement>,
+        oracle: Pubkey,
+        region_code: u16,
+        max_age_secs: u32,
+    ) -> Result<()> {
+        set_location_requirement::set_location_requirement(ctx, oracle, region_code, max_age_secs)
+    }
+
+    pub fn attest_location(ctx: Context<AttestLocation>, region_code: u16) -> Result<()> {
+        attest_location::attest_location(ctx, region_code)
+    }
+
+    pub fn set_redemption_schedule(
+        ctx: Context<SetRedemptionSchedule>,
+        days_mask: u8,
+        hours_mask: u32,
+        utc_offset_mins: i16,
+    ) -> Result<()> {
+        set_redemption_schedule::set_redemption_schedule(ctx, days_mask, hours_mask, utc_offset_mins)
+    }
+
+    pub fn set_first_purchase_only(ctx: Context<SetFirstPurchaseOnly>, enabled: bool) -> Result<()> {
+        set_first_purchase_only::set_first_purchase_only(ctx, enabled)
+    }
+
+    pub fn propose_coupon_swap(ctx: Context<ProposeCouponSwap>, amount: u64) -> Result<()> {
+        propose_coupon_swap::propose_coupon_swap(ctx, amount)
+    }
+
+    pub fn accept_coupon_swap(ctx: Context<AcceptCouponSwap>) -> Result<()> {
+        accept_coupon_swap::accept_coupon_swap(ctx)
+    }
+
+    pub fn cancel_coupon_swap(ctx: Context<CancelCouponSwap>) -> Result<()> {
+        cancel_coupon_swap::cancel_coupon_swap(ctx)
+    }
+
+    pub fn buy_listed_coupons_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuyListedCouponsBatch<'info>>,
+        max_total_price: u64,
+    ) -> Result<()> {
+        buy_listed_coupons_batch::buy_listed_coupons_batch(ctx, max_total_price)
+    }
+
+    pub fn set_withholding_rule(
+        ctx: Context<SetWithholdingRule>,
+        jurisdiction_code: u16,
+        rate_bps: u16,
+        threshold_lamports: u64,
+        compliance_account: Pubkey,
+    ) -> Result<()> {
+        set_withholding_rule::set_withholding_rule(
+            ctx,
+            jurisdiction_code,
+            rate_bps,
+            threshold_lamports,
+            compliance_account,
+        )
+    }
+
+    pub fn set_campaign_jurisdiction(
+        ctx: Context<SetCampaignJurisdiction>,
+        jurisdiction_code: u16,
+    ) -> Result<()> {
+        set_campaign_jurisdiction::set_campaign_jurisdiction(ctx, jurisdiction_code)
+    }
+
+    pub fn propose_emergency_withdrawal(
+        ctx: Context<ProposeEmergencyWithdrawal>,
+        amount: u64,
+        destination: Pubkey,
+    ) -> Result<()> {
+        propose_emergency_withdrawal::propose_emergency_withdrawal(ctx, amount, destination)
+    }
+
+    pub fn execute_emergency_withdrawal(ctx: Context<ExecuteEmergencyWithdrawal>) -> Result<()> {
+        execute_emergency_withdrawal::execute_emergency_withdrawal(ctx)
+    }
+
+    pub fn cancel_emergency_withdrawal(ctx: Context<CancelEmergencyWithdrawal>) -> Result<()> {
+        cancel_emergency_withdrawal::cancel_emergency_withdrawal(ctx)
+    }
+
+    pub fn propose_config_admin(
+        ctx: Context<ProposeConfigAdmin>,
+        new_admin: Pubkey,
+        governance_program: Option<Pubkey>,
+    ) -> Result<()> {
+        propose_config_admin::propose_config_admin(ctx, new_admin, governance_program)
+    }
+
+    pub fn accept_config_admin(ctx: Context<AcceptConfigAdmin>) -> Result<()> {
+        accept_config_admin::accept_config_admin(ctx)
+    }
+
+    pub fn set_fee_switches(
+        ctx: Context<SetFeeSwitches>,
+        mint_fee_enabled: bool,
+        redemption_fee_enabled: bool,
+        marketplace_fee_enabled: bool,
+    ) -> Result<()> {
+        set_fee_switches::set_fee_switches(
+            ctx,
+            mint_fee_enabled,
+            redemption_fee_enabled,
+            marketplace_fee_enabled,
+        )
+    }
+
+    pub fn finalize_config(ctx: Context<FinalizeConfig>, fields: u8) -> Result<()> {
+        finalize_config::finalize_config(ctx, fields)
+    }
+
+    pub fn hold_coupon(ctx: Context<HoldCoupon>, expiry: i64) -> Result<()> {
+        hold_coupon::hold_coupon(ctx, expiry)
+    }
+
+    pub fn set_user_discount_cap(
+        ctx: Context<SetUserDiscountCap>,
+        max_discount_per_user: u64,
+    ) -> Result<()> {
+        set_user_discount_cap::set_user_discount_cap(ctx, max_discount_per_user)
+    }
+
+    pub fn set_daily_budget(ctx: Context<SetDailyBudget>, daily_budget_lamports: u64) -> Result<()> {
+        set_daily_budget::set_daily_budget(ctx, daily_budget_lamports)
+    }
+
+    pub fn set_budget_expiration(ctx: Context<SetBudgetExpiration>, enabled: bool) -> Result<()> {
+        set_budget_expiration::set_budget_expiration(ctx, enabled)
+    }
+
+    pub fn withdraw_vault_excess(ctx: Context<WithdrawVaultExcess>, amount: u64) -> Result<()> {
+        withdraw_vault_excess::withdraw_vault_excess(ctx, amount)
+    }
+
+    pub fn set_payout_wallet(ctx: Context<SetPayoutWallet>, payout_wallet: Pubkey) -> Result<()> {
+        set_payout_wallet::set_payout_wallet(ctx, payout_wallet)
+    }
+
+    pub fn transfer_campaign_authority(
+        ctx: Context<TransferCampaignAuthority>,
+        new_merchant: Pubkey,
+    ) -> Result<()> {
+        transfer_campaign_authority::transfer_campaign_authority(ctx, new_merchant)
+    }
+
+    pub fn accept_campaign_authority(ctx: Context<AcceptCampaignAuthority>) -> Result<()> {
+        accept_campaign_authority::accept_campaign_authority(ctx)
+    }
+
+    pub fn register_device_key(
+        ctx: Context<RegisterDeviceKey>,
+        device: Pubkey,
+        daily_quota: u32,
+    ) -> Result<()> {
+        register_device_key::register_device_key(ctx, device, daily_quota)
+    }
+
+    pub fn revoke_device_key(ctx: Context<RevokeDeviceKey>) -> Result<()> {
+        revoke_device_key::revoke_device_key(ctx)
+    }
+
+    pub fn set_redemption_verifier(
+        ctx: Context<SetRedemptionVerifier>,
+        verifier: Pubkey,
+    ) -> Result<()> {
+        set_redemption_verifier::set_redemption_verifier(ctx, verifier)
+    }
+
+    pub fn set_streak_bonus(
+        ctx: Context<SetStreakBonus>,
+        streak_bonus_bps: u16,
+        max_streak_bonus_bps: u16,
+    ) -> Result<()> {
+        set_streak_bonus::set_streak_bonus(ctx, streak_bonus_bps, max_streak_bonus_bps)
+    }
+
+    pub fn create_achievement(
+        ctx: Context<CreateAchievement>,
+        achievement_id: u64,
+        category_code: u16,
+        required_redemptions: u32,
+    ) -> Result<()> {
+        create_achievement::create_achievement(ctx, achievement_id, category_code, required_redemptions)
+    }
+
+    pub fn join_achievement(ctx: Context<JoinAchievement>) -> Result<()> {
+        join_achievement::join_achievement(ctx)
+    }
+
+    pub fn set_required_achievement(
+        ctx: Context<SetRequiredAchievement>,
+        achievement: Pubkey,
+    ) -> Result<()> {
+        set_required_achievement::set_required_achievement(ctx, achievement)
+    }
+
+    pub fn create_lottery(
+        ctx: Context<CreateLottery>,
+        randomness_oracle: Pubkey,
+        prize_lamports: u64,
+        total_winners: u8,
+    ) -> Result<()> {
+        create_lottery::create_lottery(ctx, randomness_oracle, prize_lamports, total_winners)
+    }
+
+    pub fn commit_draw(
+        ctx: Context<CommitDraw>,
+        commitment: [u8; 32],
+        ticket_root: [u8; 32],
+        ticket_count: u32,
+    ) -> Result<()> {
+        commit_draw::commit_draw(ctx, commitment, ticket_root, ticket_count)
+    }
+
+    pub fn draw_winner(
+        ctx: Context<DrawWinner>,
+        secret: [u8; 32],
+        coupon_index: u64,
+        ticket_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        draw_winner::draw_winner(ctx, secret, coupon_index, ticket_proof)
+    }
+
+    pub fn close_lottery(ctx: Context<CloseLottery>) -> Result<()> {
+        close_lottery::close_lottery(ctx)
+    }
+
+    pub fn publish_oracle_feed(ctx: Context<PublishOracleFeed>, feed_id: u64, value: i64) -> Result<()> {
+        publish_oracle_feed::publish_oracle_feed(ctx, feed_id, value)
+    }
+
+    pub fn set_redemption_condition(
+        ctx: Context<SetRedemptionCondition>,
+        oracle: Pubkey,
+        feed_id: u64,
+        op: u8,
+        value: i64,
+    ) -> Result<()> {
+        set_redemption_condition::set_redemption_condition(ctx, oracle, feed_id, op, value)
+    }
+
+    pub fn set_redemption_reward(
+        ctx: Context<SetRedemptionReward>,
+        reward_mint: Pubkey,
+        reward_amount: u64,
+    ) -> Result<()> {
+        set_redemption_reward::set_redemption_reward(ctx, reward_mint, reward_amount)
+    }
+
+    pub fn withdraw_reward_tokens(ctx: Context<WithdrawRewardTokens>, amount: u64) -> Result<()> {
+        withdraw_reward_tokens::withdraw_reward_tokens(ctx, amount)
+    }
+
+    pub fn set_gas_rebate(ctx: Context<SetGasRebate>, gas_rebate_lamports: u64) -> Result<()> {
+        set_gas_rebate::set_gas_rebate(ctx, gas_rebate_lamports)
+    }
+
+    pub fn set_campaign_partner(
+        ctx: Context<SetCampaignPartner>,
+        partner: Pubkey,
+        partner_share_bps: u16,
+    ) -> Result<()> {
+        set_campaign_partner::set_campaign_partner(ctx, partner, partner_share_bps)
+    }
+
+    pub fn set_rounding_mode(ctx: Context<SetRoundingMode>, rounding_mode: u8) -> Result<()> {
+        set_rounding_mode::set_rounding_mode(ctx, rounding_mode)
+    }
+
+    pub fn create_export_checkpoint(
+        ctx: Context<CreateExportCheckpoint>,
+        exporter: Pubkey,
+    ) -> Result<()> {
+        create_export_checkpoint::create_export_checkpoint(ctx, exporter)
+    }
+
+    pub fn ack_export(ctx: Context<AckExport>, sequence: u32) -> Result<()> {
+        ack_export::ack_export(ctx, sequence)
+    }
+
+    pub fn register_qr_nonce(ctx: Context<RegisterQrNonce>, nonce_hash: [u8; 32]) -> Result<()> {
+        register_qr_nonce::register_qr_nonce(ctx, nonce_hash)
+    }
+
+    pub fn set_qr_nonce_required(ctx: Context<SetQrNonceRequired>, required: bool) -> Result<()> {
+        set_qr_nonce_required::set_qr_nonce_required(ctx, required)
+    }
+
+    pub fn upgrade_vault(ctx: Context<UpgradeVault>) -> Result<()> {
+        upgrade_vault::upgrade_vault(ctx)
+    }
+
+    pub fn archive_campaign(ctx: Context<ArchiveCampaign>) -> Result<()> {
+        archive_campaign::archive_campaign(ctx)
+    }
+
+    pub fn set_platform_treasury(
+        ctx: Context<SetPlatformTreasury>,
+        platform_treasury: Pubkey,
+    ) -> Result<()> {
+        set_platform_treasury::set_platform_treasury(ctx, platform_treasury)
+    }
+
+    pub fn set_required_collection(
+        ctx: Context<SetRequiredCollection>,
+        collection: Pubkey,
+    ) -> Result<()> {
+        set_required_collection::set_required_collection(ctx, collection)
+    }
+
+    pub fn claim_coupon<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimCoupon<'info>>,
+        campaign_id: u64,
+        coupon_index: Option<u64>,
+        segment_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        claim_coupon::claim_coupon(ctx, campaign_id, coupon_index, segment_proof)
+    }
+
+    pub fn set_token_gate(
+        ctx: Context<SetTokenGate>,
+        gate_mint: Pubkey,
+        min_amount: u64,
+    ) -> Result<()> {
+        set_token_gate::set_token_gate(ctx, gate_mint, min_amount)
+    }
+
+    pub fn set_wallet_coupon_limit(
+        ctx: Context<SetWalletCouponLimit>,
+        max_coupons_per_wallet: u32,
+    ) -> Result<()> {
+        set_wallet_coupon_limit::set_wallet_coupon_limit(ctx, max_coupons_per_wallet)
+    }
+
+    pub fn set_region_targeting(
+        ctx: Context<SetRegionTargeting>,
+        attestor: Pubkey,
+        region_code: u16,
+    ) -> Result<()> {
+        set_region_targeting::set_region_targeting(ctx, attestor, region_code)
+    }
+
+    pub fn set_transferable(ctx: Context<SetTransferable>, transferable: bool) -> Result<()> {
+        set_transferable::set_transferable(ctx, transferable)
+    }
+
+    pub fn set_loyalty_tier(ctx: Context<SetLoyaltyTier>, min_prior_redemptions: u32) -> Result<()> {
+        set_loyalty_tier::set_loyalty_tier(ctx, min_prior_redemptions)
+    }
+
+    pub fn claim_with_voucher(
+        ctx: Context<ClaimWithVoucher>,
+        campaign_id: u64,
+        coupon_index: u64,
+        nonce: u64,
+    ) -> Result<()> {
+        claim_with_voucher::claim_with_voucher(ctx, campaign_id, coupon_index, nonce)
+    }
+
+    pub fn add_blocked_wallet(ctx: Context<AddBlockedWallet>, wallet: Pubkey) -> Result<()> {
+        add_blocked_wallet::add_blocked_wallet(ctx, wallet)
+    }
+
+    pub fn remove_blocked_wallet(ctx: Context<RemoveBlockedWallet>) -> Result<()> {
+        remove_blocked_wallet::remove_blocked_wallet(ctx)
+    }
+
+    pub fn set_required_cnft_collection(
+        ctx: Context<SetRequiredCnftCollection>,
+        collection: Pubkey,
+    ) -> Result<()> {
+        set_required_cnft_collection::set_required_cnft_collection(ctx, collection)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn claim_coupon_with_cnft<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimCouponWithCnft<'info>>,
+        campaign_id: u64,
+        coupon_index: u64,
+        segment_proof: Vec<[u8; 32]>,
+        receipt_count: u8,
+        root: [u8; 32],
+        nonce: u64,
+        delegate: Pubkey,
+        creator_hash: [u8; 32],
+        metadata_args: Vec<u8>,
+    ) -> Result<()> {
+        claim_coupon_with_cnft::claim_coupon_with_cnft(
+            ctx,
+            campaign_id,
+            coupon_index,
+            segment_proof,
+            receipt_count,
+            root,
+            nonce,
+            delegate,
+            creator_hash,
+            metadata_args,
+        )
+    }
+
+    pub fn set_sns_parent_domain(
+        ctx: Context<SetSnsParentDomain>,
+        parent_domain: Pubkey,
+    ) -> Result<()> {
+        set_sns_parent_domain::set_sns_parent_domain(ctx, parent_domain)
+    }
+
+    pub fn set_eligibility_program(
+        ctx: Context<SetEligibilityProgram>,
+        eligibility_program: Pubkey,
+    ) -> Result<()> {
+        set_eligibility_program::set_eligibility_program(ctx, eligibility_program)
+    }
+
+    pub fn set_kyc_requirement(
+        ctx: Context<SetKycRequirement>,
+        attestation_program: Pubkey,
+        credential: Pubkey,
+        schema: Pubkey,
+    ) -> Result<()> {
+        set_kyc_requirement::set_kyc_requirement(ctx, attestation_program, credential, schema)
+    }
 
+    pub fn set_target_claim_deadline(
+        ctx: Context<SetTargetClaimDeadline>,
+        claim_deadline: i64,
+    ) -> Result<()> {
+        set_target_claim_deadline::set_target_claim_deadline(ctx, claim_deadline)
+    }
+
+    pub fn issue_claim_ticket(ctx: Context<IssueClaimTicket>, wallet: Pubkey) -> Result<()> {
+        issue_claim_ticket::issue_claim_ticket(ctx, wallet)
+    }
+
+    pub fn finalize_claim(
+        ctx: Context<FinalizeClaim>,
+        campaign_id: u64,
+        coupon_index: u64,
+    ) -> Result<()> {
+        finalize_claim::finalize_claim(ctx, campaign_id, coupon_index)
+    }
+
+    pub fn whitelist_wallet(
+        ctx: Context<WhitelistWallet>,
+        wallet: Pubkey,
+        claim_deadline: i64,
+    ) -> Result<()> {
+        whitelist_wallet::whitelist_wallet(ctx, wallet, claim_deadline)
+    }
+
+    pub fn unwhitelist_wallet(ctx: Context<UnwhitelistWallet>) -> Result<()> {
+        unwhitelist_wallet::unwhitelist_wallet(ctx)
+    }
+
+    pub fn set_whitelist_required(ctx: Context<SetWhitelistRequired>, required: bool) -> Result<()> {
+        set_whitelist_required::set_whitelist_required(ctx, required)
+    }
+
+    pub fn update_campaign_targeting(
+        ctx: Context<UpdateCampaignTargeting>,
+        requires_wallet: bool,
+        target_wallet: Pubkey,
+    ) -> Result<()> {
+        update_campaign_targeting::update_campaign_targeting(ctx, requires_wallet, target_wallet)
+    }
+
+    pub fn set_category_claim_cap(
+        ctx: Context<SetCategoryClaimCap>,
+        category_claims_per_epoch: u32,
+    ) -> Result<()> {
+        set_category_claim_cap::set_category_claim_cap(ctx, category_claims_per_epoch)
+    }
+
+    pub fn set_claim_rate_limit(
+        ctx: Context<SetClaimRateLimit>,
+        claims_per_slot_limit: u16,
+    ) -> Result<()> {
+        set_claim_rate_limit::set_claim_rate_limit(ctx, claims_per_slot_limit)
+    }
+
+    pub fn set_sybil_gate(
+        ctx: Context<SetSybilGate>,
+        min_stake_lamports: u64,
+        min_wallet_age_epochs: u64,
+    ) -> Result<()> {
+        set_sybil_gate::set_sybil_gate(ctx, min_stake_lamports, min_wallet_age_epochs)
+    }
+
+    pub fn create_targeting_template(
+        ctx: Context<CreateTargetingTemplate>,
+        template_id: u64,
+        params: TargetingParams,
+    ) -> Result<()> {
+        create_targeting_template::create_targeting_template(ctx, template_id, params)
+    }
+
+    pub fn update_targeting_template(
+        ctx: Context<UpdateTargetingTemplate>,
+        params: TargetingParams,
+    ) -> Result<()> {
+        update_targeting_template::update_targeting_template(ctx, params)
+    }
+
+    pub fn cancel_campaign(ctx: Context<CancelCampaign>) -> Result<()> {
+        cancel_campaign::cancel_campaign(ctx)
+    }
+
+    pub fn extend_campaign(
+        ctx: Context<ExtendCampaign>,
+        new_expiration_timestamp: i64,
+        top_up_amount: u64,
+    ) -> Result<()> {
+        extend_campaign::extend_campaign(ctx, new_expiration_timestamp, top_up_amount)
+    }
+
+    pub fn update_campaign(
+        ctx: Context<UpdateCampaign>,
+        discount_bps: u16,
+        max_discount_lamports: u64,
+        mint_cost_lamports: u64,
+        campaign_name: String,
+        category_code: u16,
+        product_code: u16,
+    ) -> Result<()> {
+        update_campaign::update_campaign(
+            ctx,
+            discount_bps,
+            max_discount_lamports,
+            mint_cost_lamports,
+            campaign_name,
+            category_code,
+            product_code,
+        )
+    }
+
+    pub fn activate_campaign(ctx: Context<ActivateCampaign>) -> Result<()> {
+        activate_campaign::activate_campaign(ctx)
+    }
+
+    pub fn create_campaigns_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CreateCampaignsBatch<'info>>,
+        campaigns: Vec<CampaignParams>,
+    ) -> Result<()> {
+        create_campaigns_batch::create_campaigns_batch(ctx, campaigns)
+    }
+
+    pub fn clone_campaign(
+        ctx: Context<CloneCampaign>,
+        new_campaign_id: u64,
+        new_expiration: i64,
+        deposit_amount: u64,
+    ) -> Result<()> {
+        clone_campaign::clone_campaign(ctx, new_campaign_id, new_expiration, deposit_amount)
+    }
+
+    pub fn close_campaign(ctx: Context<CloseCampaign>, retention_secs: i64) -> Result<()> {
+        close_campaign::close_campaign(ctx, retention_secs)
+    }
 
+    pub fn set_discount_phases(
+        ctx: Context<SetDiscountPhases>,
+        phases: Vec<DiscountPhase>,
+    ) -> Result<()> {
+        set_discount_phases::set_discount_phases(ctx, phases)
+    }
+
+    pub fn renew_campaign(
+        ctx: Context<RenewCampaign>,
+        new_expiration_timestamp: i64,
+        deposit_amount: u64,
+    ) -> Result<()> {
+        renew_campaign::renew_campaign(ctx, new_expiration_timestamp, deposit_amount)
+    }
+
+    pub fn set_low_balance_threshold(
+        ctx: Context<SetLowBalanceThreshold>,
+        threshold: u64,
+    ) -> Result<()> {
+        set_low_balance_threshold::set_low_balance_threshold(ctx, threshold)
+    }
+
+    pub fn add_manager(ctx: Context<AddManager>, manager: Pubkey) -> Result<()> {
+        add_manager::add_manager(ctx, manager)
+    }
+
+    pub fn remove_manager(ctx: Context<RemoveManager>, manager: Pubkey) -> Result<()> {
+        remove_manager::remove_manager(ctx, manager)
+    }
+
+    pub fn register_merchant(
+        ctx: Context<RegisterMerchant>,
+        name: String,
+        metadata_uri: String,
+    ) -> Result<()> {
+        register_merchant::register_merchant(ctx, name, metadata_uri)
+    }
+
+    pub fn set_daily_redemption_limit(
+        ctx: Context<SetDailyRedemptionLimit>,
+        max_redemptions_per_day: u32,
+    ) -> Result<()> {
+        set_daily_redemption_limit::set_daily_redemption_limit(ctx, max_redemptions_per_day)
+    }
+
+    pub fn register_category(
+        ctx: Context<RegisterCategory>,
+        code: u16,
+        name: String,
+    ) -> Result<()> {
+        register_category::register_category(ctx, code, name)
+    }
+
+    pub fn deprecate_category(ctx: Context<DeprecateCategory>, code: u16) -> Result<()> {
+        deprecate_category::deprecate_category(ctx, code)
+    }
+
+    pub fn update_campaign_metadata(
+        ctx: Context<UpdateCampaignMetadata>,
+        metadata_uri: String,
+    ) -> Result<()> {
+        update_campaign_metadata::update_campaign_metadata(ctx, metadata_uri)
+    }
+
+    pub fn migrate_campaign(ctx: Context<MigrateCampaign>) -> Result<()> {
+        migrate_campaign::migrate_campaign(ctx)
+    }
+
+    pub fn expire_coupons_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ExpireCouponsBatch<'info>>,
+    ) -> Result<()> {
+        expire_coupons_batch::expire_coupons_batch(ctx)
+    }
+
+    pub fn set_product_codes(
+        ctx: Context<SetProductCodes>,
+        extra_product_codes: Vec<u16>,
+    ) -> Result<()> {
+        set_product_codes::set_product_codes(ctx, extra_product_codes)
+    }
+
+    pub fn set_budget_taper(ctx: Context<SetBudgetTaper>, budget_taper_bps: u16) -> Result<()> {
+        set_budget_taper::set_budget_taper(ctx, budget_taper_bps)
+    }
+
+    pub fn freeze_campaign(ctx: Context<FreezeCampaign>) -> Result<()> {
+        freeze_campaign::freeze_campaign(ctx)
+    }
+
+    pub fn unfreeze_campaign(ctx: Context<UnfreezeCampaign>) -> Result<()> {
+        unfreeze_campaign::unfreeze_campaign(ctx)
+    }
+
+    pub fn snapshot_campaign_stats(ctx: Context<SnapshotCampaignStats>, epoch: u64) -> Result<()> {
+        snapshot_campaign_stats::snapshot_campaign_stats(ctx, epoch)
+    }
+
+    pub fn set_discount_kind(
+        ctx: Context<SetDiscountKind>,
+        discount_kind: DiscountKind,
+    ) -> Result<()> {
+        set_discount_kind::set_discount_kind(ctx, discount_kind)
+    }
+
+    pub fn set_stacking_policy(
+        ctx: Context<SetStackingPolicy>,
+        stackable: bool,
+        max_stacked_discount_bps: u16,
+    ) -> Result<()> {
+        set_stacking_policy::set_stacking_policy(ctx, stackable, max_stacked_discount_bps)
+    }
+
+    pub fn redeem_coupons_stacked<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RedeemCouponsStacked<'info>>,
+        purchase_amount: u64,
+        product_code: u16,
+    ) -> Result<()> {
+        redeem_coupons_stacked::redeem_coupons_stacked(ctx, purchase_amount, product_code)
+    }
+
+    pub fn mint_coupons_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, MintCouponsBatch<'info>>,
+        coupon_indexes: Vec<u64>,
+        metadata: CouponMetadata,
+        serials: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        mint_coupons_batch::mint_coupons_batch(ctx, coupon_indexes, metadata, serials)
+    }
+
+    pub fn set_coupon_value(
+        ctx: Context<SetCouponValue>,
+        coupon_value_lamports: u64,
+    ) -> Result<()> {
+        set_coupon_value::set_coupon_value(ctx, coupon_value_lamports)
+    }
+
+    pub fn redeem_partial(
+        ctx: Context<RedeemPartial>,
+        purchase_amount: u64,
+        amount: u64,
+        product_code: u16,
+    ) -> Result<()> {
+        redeem_partial::redeem_partial(ctx, purchase_amount, amount, product_code)
+    }
+
+    pub fn reserve_coupon(ctx: Context<ReserveCoupon>, expiry_slot: u64) -> Result<()> {
+        reserve_coupon::reserve_coupon(ctx, expiry_slot)
+    }
+
+    pub fn release_coupon(ctx: Context<ReleaseCoupon>) -> Result<()> {
+        release_coupon::release_coupon(ctx)
+    }
+
+    pub fn set_transfer_limits(
+        ctx: Context<SetTransferLimits>,
+        max_transfers: u16,
+        transfer_cooldown_secs: u32,
+    ) -> Result<()> {
+        set_transfer_limits::set_transfer_limits(ctx, max_transfers, transfer_cooldown_secs)
+    }
+
+    pub fn approve_transfer(ctx: Context<ApproveTransfer>, new_owner: Pubkey) -> Result<()> {
+        approve_transfer::approve_transfer(ctx, new_owner)
+    }
+
+    pub fn claim_transfer(ctx: Context<ClaimTransfer>) -> Result<()> {
+        claim_transfer::claim_transfer(ctx)
+    }
+
+    pub fn freeze_coupon(ctx: Context<FreezeCoupon>) -> Result<()> {
+        freeze_coupon::freeze_coupon(ctx)
+    }
+
+    pub fn unfreeze_coupon(ctx: Context<UnfreezeCoupon>) -> Result<()> {
+        unfreeze_coupon::unfreeze_coupon(ctx)
+    }
+
+    pub fn upgrade_coupon(ctx: Context<UpgradeCoupon>) -> Result<()> {
+        upgrade_coupon::upgrade_coupon(ctx)
+    }
+
+    pub fn register_wallet(ctx: Context<RegisterWallet>) -> Result<()> {
+        register_wallet::register_wallet(ctx)
+    }
+
+    pub fn set_marketplace_fee(
+        ctx: Context<SetMarketplaceFee>,
+        marketplace_fee_bps: u16,
+    ) -> Result<()> {
+        set_marketplace_fee::set_marketplace_fee(ctx, marketplace_fee_bps)
+    }
+
+    pub fn revoke_claim_ticket(ctx: Context<RevokeClaimTicket>) -> Result<()> {
+        revoke_claim_ticket::revoke_claim_ticket(ctx)
+    }
+
+    pub fn abandon_draw(ctx: Context<AbandonDraw>) -> Result<()> {
+        abandon_draw::abandon_draw(ctx)
+    }
+}
@@ -1,4 +1,7 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::errors::PromoError;
 
 // ---------------------------
 // Accounts: State
@@ -10,10 +13,42 @@ pub struct GlobalConfig {
     pub admin: Pubkey,       // 32 bytes - who is allowed to update config / call admin helpers
     pub max_resale_bps: u16, // 2 bytes  - maximum resale_bps allowed per campaign
     pub service_fee_bps: u16, // 2 bytes  - global protocol fee applied to all campaigns
+    pub pending_admin: Pubkey, // 32 bytes - admin proposed by the current admin, until accepted
+    pub mint_fee_disabled: bool,       // 1 byte - fee holiday: mint_coupon skips the mint cost
+    pub redemption_fee_disabled: bool, // 1 byte - fee holiday: redeem_coupon skips the service fee
+    pub finalized_fields: u8,          // 1 byte - bitmask of permanently frozen fee parameters
+    pub rounding_mode: u8,             // 1 byte - rounding of bps math (see ROUNDING_*), copied into new campaigns
+    pub platform_treasury: Pubkey,     // 32 bytes - only account allowed to receive mint costs and service fees
+    pub category_claims_per_epoch: u32, // 4 bytes - coupons a wallet can receive per category per epoch (0 = no cap)
+    pub marketplace_fee_bps: u16,      // 2 bytes - protocol fee on secondary sales, out of the seller proceeds
+    pub marketplace_fee_disabled: bool, // 1 byte - fee holiday: listing purchases skip the marketplace fee
 }
 
 impl GlobalConfig {
-    pub const SIZE: usize = 32 + 2 + 2;
+    pub const SIZE: usize = 32 + 2 + 2 + 32 + 1 + 1 + 1 + 1 + 32 + 4 + 2 + 1;
+
+    /// `rounding_mode` values.
+    pub const ROUNDING_FLOOR: u8 = 0;
+    pub const ROUNDING_CEIL: u8 = 1;
+    pub const ROUNDING_BANKERS: u8 = 2;
+
+    /// `finalized_fields` bits (see `finalize_config`).
+    pub const FINALIZE_MAX_RESALE_BPS: u8 = 1 << 0;
+    pub const FINALIZE_SERVICE_FEE_BPS: u8 = 1 << 1;
+    pub const FINALIZE_FEE_SWITCHES: u8 = 1 << 2;
+    pub const FINALIZE_ROUNDING_MODE: u8 = 1 << 3;
+    pub const FINALIZE_PLATFORM_TREASURY: u8 = 1 << 4;
+    pub const FINALIZE_MARKETPLACE_FEE_BPS: u8 = 1 << 5;
+    pub const FINALIZE_ALL: u8 = Self::FINALIZE_MAX_RESALE_BPS
+        | Self::FINALIZE_SERVICE_FEE_BPS
+        | Self::FINALIZE_FEE_SWITCHES
+        | Self::FINALIZE_ROUNDING_MODE
+        | Self::FINALIZE_PLATFORM_TREASURY
+        | Self::FINALIZE_MARKETPLACE_FEE_BPS;
+
+    pub fn is_finalized(&self, field: u8) -> bool {
+        self.finalized_fields & field != 0
+    }
 }
 
 /// Campaign account: stores all campaign parameters and summary stats.
@@ -41,10 +76,178 @@ pub struct Campaign {
     pub total_purchase_amount: u64,      // 8 bytes - sum of all purchase_amount in redeem
     pub total_discount_lamports: u64,    // 8 bytes - sum of all discount_value in redeem
     pub last_redeem_timestamp: i64,      // 8 bytes - last time a coupon was redeemed
+    // Audience
+    pub segment: Pubkey,                 // 32 bytes - reusable audience segment (default = none)
+    // Behavioral targeting
+    pub required_category_code: u16,     // 2 bytes - category the claimer must have redeemed in
+    pub min_category_redemptions: u8,    // 1 byte  - receipts required in that category (0 = off)
+    // Exclusions
+    pub excluded_wallets: Vec<Pubkey>,   // 4 + MAX_EXCLUDED_WALLETS * 32 bytes - wallets carved out of the campaign
+    // Retargeting
+    pub prerequisite_campaign: Pubkey,   // 32 bytes - earlier campaign whose coupon/receipt grants eligibility (default = none)
+    // Geofenced redemption
+    pub location_oracle: Pubkey,         // 32 bytes - oracle that must attest the redeemer location (default = none)
+    pub location_region_code: u16,       // 2 bytes  - region the attestation must match
+    pub location_max_age_secs: u32,      // 4 bytes  - maximum age of the attestation at redeem
+    // Redemption schedule
+    pub redeem_days_mask: u8,            // 1 byte  - allowed weekdays, bit 0 = Sunday (0 = any day)
+    pub redeem_hours_mask: u32,          // 4 bytes - allowed hours, bit h = h:00-h:59 (0 = any hour)
+    pub schedule_utc_offset_mins: i16,   // 2 bytes - local time offset used to evaluate the schedule
+    // New-customer acquisition
+    pub first_purchase_only: bool,       // 1 byte  - only wallets with no prior redemption at this merchant
+    // Compliance
+    pub jurisdiction_code: u16,          // 2 bytes - jurisdiction for resale withholding (0 = none)
+    // Per-user limits
+    pub max_discount_per_user: u64,      // 8 bytes - cumulative discount cap per wallet (0 = no cap)
+    // Budget pacing
+    pub daily_budget_lamports: u64,      // 8 bytes - max discount granted per day (0 = no cap)
+    pub budget_day: i64,                 // 8 bytes - day index of the current spend window
+    pub budget_day_spent: u64,           // 8 bytes - discount granted during `budget_day`
+    // Budget-based expiration
+    pub expire_when_budget_exhausted: bool,// 1 byte  - "until funds last": expire once the vault runs dry
+    pub budget_exhausted_at: i64,        // 8 bytes - when the vault ran dry (0 = not exhausted)
+    // Payouts
+    pub payout_wallet: Pubkey,           // 32 bytes - destination of merchant refunds (default = merchant)
+    // Authority handoff
+    pub creator: Pubkey,                 // 32 bytes - merchant that created the campaign (PDA seed, never changes)
+    pub pending_merchant: Pubkey,        // 32 bytes - authority proposed by the merchant, until accepted
+    // Dual approval
+    pub redemption_verifier: Pubkey,     // 32 bytes - key that must co-sign redemptions (default = none)
+    // Streak bonus
+    pub streak_bonus_bps: u16,           // 2 bytes - extra discount bps per streak week beyond the first
+    pub max_streak_bonus_bps: u16,       // 2 bytes - cap on the total streak bonus
+    // Exclusive campaigns
+    pub required_achievement: Pubkey,    // 32 bytes - achievement recipients must have unlocked (default = none)
+    // Oracle-conditional redemption
+    pub condition_oracle: Pubkey,        // 32 bytes - oracle publishing the unlock feed (default = unconditional)
+    pub condition_feed_id: u64,          // 8 bytes - feed of `condition_oracle` to read
+    pub condition_op: u8,                // 1 byte  - comparison (see CONDITION_*)
+    pub condition_value: i64,            // 8 bytes - feed value compared against
+    // Companion token reward
+    pub reward_mint: Pubkey,             // 32 bytes - SPL token paid to redeemers (default = none)
+    pub reward_amount: u64,              // 8 bytes - reward per redemption, in base units
+    // Gas rebate
+    pub gas_rebate_lamports: u64,        // 8 bytes - lamports refunded to the redeemer by the vault
+    // Co-branding
+    pub partner: Pubkey,                 // 32 bytes - co-marketing partner brand (default = none)
+    pub partner_share_bps: u16,          // 2 bytes - share of attribution credited to the partner
+    // Rounding
+    pub rounding_mode: u8,               // 1 byte  - GlobalConfig.rounding_mode at creation
+    // In-store QR
+    pub requires_qr_nonce: bool,         // 1 byte  - redemptions must consume a registered QR nonce
+    // Archival
+    pub receipts_hash: [u8; 32],         // 32 bytes - running sha256 over the receipt PDAs, in redemption order
+    // Token gating
+    pub required_collection: Pubkey,     // 32 bytes - verified Metaplex collection recipients must hold an NFT of (default = none)
+    pub gate_mint: Pubkey,               // 32 bytes - SPL mint recipients must hold (default = none)
+    pub gate_min_amount: u64,            // 8 bytes  - minimum gate_mint balance, in base units
+    // Per-wallet limit
+    pub max_coupons_per_wallet: u32,     // 4 bytes - max coupons one wallet can receive (0 = unlimited)
+    // Region targeting
+    pub region_attestor: Pubkey,         // 32 bytes - key signing (wallet, region) attestations (default = none)
+    pub region_code: u16,                // 2 bytes  - region recipients must be attested to
+    // Soulbound mode
+    pub transferable: bool,              // 1 byte  - false = coupons cannot change hands (soulbound)
+    // Loyalty tier
+    pub min_prior_redemptions: u32,      // 4 bytes - recipients need this many prior redemptions at the merchant (0 = none)
+    // Compressed NFT gating
+    pub required_cnft_collection: Pubkey, // 32 bytes - verified Bubblegum cNFT collection claimants must hold (default = none)
+    // SNS gating
+    pub sns_parent_domain: Pubkey,       // 32 bytes - recipients must own an SNS domain under this parent (default = none)
+    // Eligibility hook
+    pub eligibility_program: Pubkey,     // 32 bytes - program whose check_eligibility must pass (default = none)
+    // KYC / age gate
+    pub kyc_attestation_program: Pubkey, // 32 bytes - attestation program (default = no KYC requirement)
+    pub kyc_credential: Pubkey,          // 32 bytes - issuer credential the attestation must be under
+    pub kyc_schema: Pubkey,              // 32 bytes - attestation schema (e.g. "over 21")
+    // Claim window
+    pub target_claim_deadline: i64,      // 8 bytes  - last time target_wallet can receive a coupon (0 = until expiration)
+    // Whitelist
+    pub requires_whitelist: bool,        // 1 byte  - recipients need a WhitelistEntry PDA
+    // Claim throttling
+    pub claims_per_slot_limit: u16,      // 2 bytes  - max self-service claims per slot (0 = unlimited)
+    pub claim_slot: u64,                 // 8 bytes  - slot `claims_in_slot` refers to
+    pub claims_in_slot: u16,             // 2 bytes  - claims in `claim_slot`
+    // Sybil gate
+    pub sybil_min_stake_lamports: u64,   // 8 bytes  - active stake qualifying the recipient (0 = no stake path)
+    pub sybil_min_wallet_age_epochs: u64, // 8 bytes - WalletRegistration age qualifying the recipient (0 = no age path)
+    // Early cancellation
+    pub cancelled_at: i64,               // 8 bytes  - when the merchant cancelled the campaign (0 = active)
+    // Draft mode
+    pub draft: bool,                     // 1 byte  - inactive until activate_campaign (no mint / claim)
+    // Discount phases
+    pub discount_phases: Vec<DiscountPhase>, // 4 + MAX_DISCOUNT_PHASES * 10 bytes - scheduled discount steps, by start time
+    // Recurring periods
+    pub period: u32,                     // 4 bytes  - current period, bumped by renew_campaign
+    // Delegated managers
+    pub managers: Vec<Pubkey>,           // 4 + MAX_MANAGERS * 32 bytes - keys allowed to mint / expire coupons (no fund access)
+    // Redemption rate limit
+    pub max_redemptions_per_day: u32,    // 4 bytes  - max redemptions per (merchant-local) day (0 = unlimited)
+    pub redemption_day: i64,             // 8 bytes  - day index `redemptions_today` refers to
+    pub redemptions_today: u32,          // 4 bytes  - redemptions during `redemption_day`
+    // Off-chain metadata
+    pub metadata_uri: String,            // 4 + MAX_METADATA_URI_LEN bytes - images, terms, localized copy (empty = none)
+    // Layout versioning
+    pub version: u8,                     // 1 byte  - layout version (see migrate_campaign)
+    // Completion
+    pub completed_at: i64,               // 8 bytes  - when the last coupon was redeemed (0 = not completed)
+    // Product family
+    pub extra_product_codes: Vec<u16>,   // 4 + MAX_EXTRA_PRODUCT_CODES * 2 bytes - other product codes redeemable besides product_code
+    // Budget taper
+    pub budget_taper_bps: u16,           // 2 bytes  - max discount as a share of the remaining vault budget (0 = off)
+    // Compliance freeze
+    pub frozen: bool,                    // 1 byte  - frozen by the platform admin (no mint / redeem / trading)
+    // Discount type
+    pub discount_kind: DiscountKind,     // 9 bytes - percentage (discount_bps) or fixed lamports off
+    // Coupon stacking
+    pub stackable: bool,                 // 1 byte  - coupons can be combined with other campaigns' coupons in one checkout
+    pub max_stacked_discount_bps: u16,   // 2 bytes  - cap on the combined discount of a stacked checkout, in bps of the purchase (0 = none)
+    // Store credit
+    pub coupon_value_lamports: u64,      // 8 bytes  - value of each coupon, redeemed in parts with redeem_partial (0 = regular coupons)
+    // Program-assigned coupon indexes
+    pub next_coupon_index: u64,          // 8 bytes  - lowest coupon index above every index minted so far (used when no index is supplied)
+    // Transfer limits
+    pub max_transfers: u16,              // 2 bytes  - max ownership changes per coupon (transfers, sales, swaps; 0 = unlimited)
+    pub transfer_cooldown_secs: u32,     // 4 bytes  - min time between ownership changes / listing after one (0 = none)
+    // Coupon accounts still open
+    pub outstanding_coupons: u32,        // 4 bytes  - coupons issued and not yet redeemed or expired, across periods
 }
 
 impl Campaign {
     pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_EXCLUDED_WALLETS: usize = 10;
+    pub const MAX_METADATA_URI_LEN: usize = 200;
+    pub const MAX_EXTRA_PRODUCT_CODES: usize = 16;
+
+    /// Layout version written by `create_campaign` / `migrate_campaign`.
+    /// Campaigns created before versioning read as version 0.
+    pub const CURRENT_VERSION: u8 = 12;
+
+    /// `condition_op` values: redemption unlocks when `feed.value <op> condition_value`.
+    pub const CONDITION_GTE: u8 = 1;
+    pub const CONDITION_LTE: u8 = 2;
+    pub const CONDITION_EQ: u8 = 3;
+
+    /// Upper bound of `gas_rebate_lamports` (a few transaction fees).
+    pub const MAX_GAS_REBATE_LAMPORTS: u64 = 50_000;
+
+    /// Minimum time a campaign account is kept after it ended (see `close_campaign`).
+    pub const MIN_RETENTION_SECS: i64 = 90 * 24 * 60 * 60;
+
+    /// Max entries of `managers`.
+    pub const MAX_MANAGERS: usize = 5;
+
+    /// Max entries of `discount_phases`.
+    pub const MAX_DISCOUNT_PHASES: usize = 4;
+
+    /// Max campaigns created by one `create_campaigns_batch`.
+    pub const MAX_BATCH_CREATE: usize = 5;
+
+    /// Max coupons combined by one `redeem_coupons_stacked`.
+    pub const MAX_STACKED_COUPONS: usize = 3;
+
+    /// Max coupons minted by one `mint_coupons_batch`.
+    pub const MAX_BATCH_MINT: usize = 10;
 
     /// Space calculation:
     /// - merchant: 32
@@ -66,9 +269,143 @@ impl Campaign {
     /// - total_purchase_amount: 8
     /// - total_discount_lamports: 8
     /// - last_redeem_timestamp: 8
+    /// - segment: 32
+    /// - required_category_code: 2
+    /// - min_category_redemptions: 1
+    /// - excluded_wallets: 4 (len) + MAX_EXCLUDED_WALLETS * 32
+    /// - prerequisite_campaign: 32
+    /// - location_oracle: 32
+    /// - location_region_code: 2
+    /// - location_max_age_secs: 4
+    /// - redeem_days_mask: 1
+    /// - redeem_hours_mask: 4
+    /// - schedule_utc_offset_mins: 2
+    /// - first_purchase_only: 1
+    /// - jurisdiction_code: 2
+    /// - max_discount_per_user: 8
+    /// - daily_budget_lamports: 8
+    /// - budget_day: 8
+    /// - budget_day_spent: 8
+    /// - expire_when_budget_exhausted: 1
+    /// - budget_exhausted_at: 8
+    /// - payout_wallet: 32
+    /// - creator: 32
+    /// - pending_merchant: 32
+    /// - redemption_verifier: 32
+    /// - streak_bonus_bps: 2
+    /// - max_streak_bonus_bps: 2
+    /// - required_achievement: 32
+    /// - condition_oracle: 32
+    /// - condition_feed_id: 8
+    /// - condition_op: 1
+    /// - condition_value: 8
+    /// - reward_mint: 32
+    /// - reward_amount: 8
+    /// - gas_rebate_lamports: 8
+    /// - partner: 32
+    /// - partner_share_bps: 2
+    /// - rounding_mode: 1
+    /// - requires_qr_nonce: 1
+    /// - receipts_hash: 32
+    /// - required_collection: 32
+    /// - gate_mint: 32
+    /// - gate_min_amount: 8
+    /// - max_coupons_per_wallet: 4
+    /// - region_attestor: 32
+    /// - region_code: 2
+    /// - transferable: 1
+    /// - min_prior_redemptions: 4
+    /// - required_cnft_collection: 32
+    /// - sns_parent_domain: 32
+    /// - eligibility_program: 32
+    /// - kyc_attestation_program: 32
+    /// - kyc_credential: 32
+    /// - kyc_schema: 32
+    /// - target_claim_deadline: 8
+    /// - requires_whitelist: 1
+    /// - claims_per_slot_limit: 2
+    /// - claim_slot: 8
+    /// - claims_in_slot: 2
+    /// - sybil_min_stake_lamports: 8
+    /// - sybil_min_wallet_age_epochs: 8
+    /// - cancelled_at: 8
+    /// - draft: 1
+    /// - discount_phases: 4 + MAX_DISCOUNT_PHASES * DiscountPhase::SIZE
+    /// - period: 4
+    /// - managers: 4 + MAX_MANAGERS * 32
+    /// - max_redemptions_per_day: 4
+    /// - redemption_day: 8
+    /// - redemptions_today: 4
+    /// - metadata_uri: 4 + MAX_METADATA_URI_LEN
+    /// - version: 1
+    /// - completed_at: 8
+    /// - extra_product_codes: 4 + MAX_EXTRA_PRODUCT_CODES * 2
+    /// - budget_taper_bps: 2
+    /// - frozen: 1
+    /// - discount_kind: DiscountKind::SIZE
+    /// - stackable: 1
+    /// - max_stacked_discount_bps: 2
+    /// - coupon_value_lamports: 8
+    /// - next_coupon_index: 8
+    /// - max_transfers: 2
+    /// - transfer_cooldown_secs: 4
+    /// - outstanding_coupons: 4
     ///
     /// Total = 32 + 8 + 2 + 2 + 2 + 8 + 4 + 4 + 4 + 8 + 8
     ///       + 2 + 2 + 4 + MAX_NAME_LEN + 1 + 32 + 8 + 8 + 8
+    ///       + 32 + 2 + 1 + 4 + MAX_EXCLUDED_WALLETS * 32 + 32
+    ///       + 32 + 2 + 4
+    ///       + 1 + 4 + 2
+    ///       + 1
+    ///       + 2
+    ///       + 8
+    ///       + 8 + 8 + 8
+    ///       + 1 + 8
+    ///       + 32
+    ///       + 32 + 32
+    ///       + 32
+    ///       + 2 + 2
+    ///       + 32
+    ///       + 32 + 8 + 1 + 8
+    ///       + 32 + 8
+    ///       + 8
+    ///       + 32 + 2
+    ///       + 1
+    ///       + 1
+    ///       + 32
+    ///       + 32
+    ///       + 32 + 8
+    ///       + 4
+    ///       + 32 + 2
+    ///       + 1
+    ///       + 4
+    ///       + 32
+    ///       + 32
+    ///       + 32
+    ///       + 32 + 32 + 32
+    ///       + 8
+    ///       + 1
+    ///       + 2 + 8 + 2
+    ///       + 8 + 8
+    ///       + 8
+    ///       + 1
+    ///       + 4 + MAX_DISCOUNT_PHASES * DiscountPhase::SIZE
+    ///       + 4
+    ///       + 4 + MAX_MANAGERS * 32
+    ///       + 4 + 8 + 4
+    ///       + 4 + MAX_METADATA_URI_LEN
+    ///       + 1
+    ///       + 8
+    ///       + 4 + MAX_EXTRA_PRODUCT_CODES * 2
+    ///       + 2
+    ///       + 1
+    ///       + DiscountKind::SIZE
+    ///       + 1 + 2
+    ///       + 8
+    ///       + 8
+    ///       + 2
+    ///       + 4
+    ///       + 4
     pub const SIZE: usize = 32
         + 8
         + 2
@@ -88,7 +425,152 @@ impl Campaign {
         + 32
         + 8
         + 8
-        + 8;
+        + 8
+        + 32
+        + 2
+        + 1
+        + 4
+        + Self::MAX_EXCLUDED_WALLETS * 32
+        + 32
+        + 32
+        + 2
+        + 4
+        + 1
+        + 4
+        + 2
+        + 1
+        + 2
+        + 8
+        + 8
+        + 8
+        + 8
+        + 1
+        + 8
+        + 32
+        + 32
+        + 32
+        + 32
+        + 2
+        + 2
+        + 32
+        + 32
+        + 8
+        + 1
+        + 8
+        + 32
+        + 8
+        + 8
+        + 32
+        + 2
+        + 1
+        + 1
+        + 32
+        + 32
+        + 32
+        + 8
+        + 4
+        + 32
+        + 2
+        + 1
+        + 4
+        + 32
+        + 32
+        + 32
+        + 32
+        + 32
+        + 32
+        + 8
+        + 1
+        + 2
+        + 8
+        + 2
+        + 8
+        + 8
+        + 8
+        + 1
+        + 4 + Self::MAX_DISCOUNT_PHASES * DiscountPhase::SIZE
+        + 4
+        + 4 + Self::MAX_MANAGERS * 32
+        + 4
+        + 8
+        + 4
+        + 4 + Self::MAX_METADATA_URI_LEN
+        + 1
+        + 8
+        + 4 + Self::MAX_EXTRA_PRODUCT_CODES * 2
+        + 2
+        + 1
+        + DiscountKind::SIZE
+        + 1
+        + 2
+        + 8
+        + 8
+        + 2
+        + 4
+        + 4;
+
+    /// A campaign is expired after `expiration_timestamp`, as soon as its
+    /// budget ran out when `expire_when_budget_exhausted` is enabled, once
+    /// every coupon has been redeemed, or once the merchant cancelled it.
+    pub fn is_expired(&self, now: i64) -> bool {
+        now > self.expiration_timestamp
+            || self.budget_exhausted_at != 0
+            || self.completed_at != 0
+            || self.cancelled_at != 0
+    }
+
+    /// When the campaign ended: cancellation, completion, budget exhaustion
+    /// or `expiration_timestamp`, whichever applies.
+    pub fn ended_at(&self) -> i64 {
+        if self.cancelled_at != 0 {
+            self.cancelled_at
+        } else if self.completed_at != 0 {
+            self.completed_at
+        } else if self.budget_exhausted_at != 0 {
+            self.budget_exhausted_at
+        } else {
+            self.expiration_timestamp
+        }
+    }
+
+    /// Whether coupons can be redeemed for `product_code`.
+    pub fn accepts_product(&self, product_code: u16) -> bool {
+        product_code == self.product_code || self.extra_product_codes.contains(&product_code)
+    }
+
+    /// Whether `key` can operate the campaign: the merchant or a delegated manager.
+    pub fn is_operator(&self, key: &Pubkey) -> bool {
+        *key == self.merchant || self.managers.contains(key)
+    }
+
+    /// Base discount at `now`: the latest `discount_phases` entry that has
+    /// started, or `discount_bps` before the first phase (or without phases).
+    pub fn discount_bps_at(&self, now: i64) -> u16 {
+        self.discount_phases
+            .iter()
+            .rev()
+            .find(|phase| phase.start_ts <= now)
+            .map_or(self.discount_bps, |phase| phase.discount_bps)
+    }
+
+    /// Whether a feed value satisfies the campaign's redemption condition.
+    pub fn condition_met(&self, value: i64) -> bool {
+        match self.condition_op {
+            Self::CONDITION_GTE => value >= self.condition_value,
+            Self::CONDITION_LTE => value <= self.condition_value,
+            Self::CONDITION_EQ => value == self.condition_value,
+            _ => false,
+        }
+    }
+
+    /// Destination of vault refunds: `payout_wallet`, or the merchant if unset.
+    pub fn payout_destination(&self) -> Pubkey {
+        if self.payout_wallet == Pubkey::default() {
+            self.merchant
+        } else {
+            self.payout_wallet
+        }
+    }
 }
 
 /// Vault account: holds the campaign budget and accounting.
@@ -100,11 +582,33 @@ pub struct Vault {
     pub total_deposit: u64,       // 8 bytes
     pub total_mint_spent: u64,    // 8 bytes (real lamports moved out)
     pub total_service_spent: u64, // 8 bytes (real lamports moved out)
+    pub matched_lamports: u64,    // 8 bytes (top-up granted by the matching pool)
+    pub version: u8,              // 1 byte  (layout version, see `upgrade_vault`)
+    pub low_balance_threshold: u64, // 8 bytes (VaultLowBalance alert below this, 0 = off)
 }
 
 impl Vault {
-    /// Space = 32 + 32 + 1 + 8 + 8 + 8 = 89 bytes
-    pub const SIZE: usize = 32 + 32 + 1 + 8 + 8 + 8;
+    /// Space = 32 + 32 + 1 + 8 + 8 + 8 + 8 + 1 + 8 = 106 bytes
+    pub const SIZE: usize = 32 + 32 + 1 + 8 + 8 + 8 + 8 + 1 + 8;
+
+    /// Layout version written by `create_campaign` / `upgrade_vault`.
+    /// Vaults created before versioning read as version 0.
+    pub const CURRENT_VERSION: u8 = 2;
+}
+
+/// Platform matching pool: admin-funded lamports used to top up
+/// qualifying campaign vaults by a percentage of the merchant deposit.
+/// Managed by the platform admin (`GlobalConfig.admin`).
+#[account]
+pub struct MatchingPool {
+    pub match_bps: u16,          // 2 bytes  - share of the merchant deposit that is matched
+    pub max_match_lamports: u64, // 8 bytes  - per-campaign cap on the match
+    pub total_matched: u64,      // 8 bytes  - lamports currently granted to live vaults
+    pub bump: u8,                // 1 byte
+}
+
+impl MatchingPool {
+    pub const SIZE: usize = 2 + 8 + 8 + 1; // 19 bytes
 }
 
 /// Coupon account: represents a single "logical NFT" coupon
@@ -117,13 +621,812 @@ pub struct Coupon {
     pub used: bool,                // 1 byte   - whether the coupon is already redeemed
     pub listed: bool,              // 1 byte   - whether coupon is listed for sale
     pub sale_price_lamports: u64,  // 8 bytes  - listing price in lamports
+    pub reserved_buyer: Pubkey,    // 32 bytes - only buyer allowed for a private listing (default = public)
+    pub held_until: i64,           // 8 bytes  - checkout hold: no transfer/listing before this time (0 = none)
+    pub not_redeemable_before: i64, // 8 bytes - scheduled gift unlock: no redemption before this time (0 = none)
+    pub period: u32,               // 4 bytes  - campaign period the coupon was issued in
+    pub metadata: CouponMetadata,  // CouponMetadata::SIZE bytes - SKU / memo / URI set at mint
+    pub serial: [u8; 32],          // 32 bytes - merchant serial (hash of its ERP coupon code, zero = none)
+    pub remaining_value: u64,      // 8 bytes  - store credit left (value-bearing campaigns only)
+    pub reserved_by: Pubkey,       // 32 bytes - POS terminal holding a checkout reservation (default = none)
+    pub reserved_until_slot: u64,  // 8 bytes  - reservation lapses after this slot
+    pub transfer_count: u16,       // 2 bytes  - ownership changes so far (see Campaign.max_transfers)
+    pub last_transfer_ts: i64,     // 8 bytes  - last ownership change (0 = still with the first owner)
+    pub pending_owner: Pubkey,     // 32 bytes - recipient approved to claim the coupon (default = none)
+    pub frozen: bool,              // 1 byte   - frozen by the merchant (no redemption / transfer / listing)
 }
 
 impl Coupon {
-    pub const SIZE: usize = 32 + 8 + 32 + 1 + 1 + 8; // 82 bytes
+    pub const SIZE: usize =
+        32 + 8 + 32 + 1 + 1 + 8 + 32 + 8 + 8 + 4 + CouponMetadata::SIZE + 32 + 8 + 32 + 8 + 2 + 8 + 32 + 1; // 365 bytes
+
+    /// Maximum checkout hold duration (15 minutes).
+    pub const MAX_HOLD_SECS: i64 = 15 * 60;
+
+    /// Maximum POS reservation length (~15 minutes of 400ms slots).
+    pub const MAX_RESERVE_SLOTS: u64 = 2_250;
+
+    /// Whether a checkout hold (`hold_coupon`) or a POS reservation
+    /// (`reserve_coupon`) currently locks the coupon.
+    pub fn is_held(&self, clock: &Clock) -> bool {
+        clock.unix_timestamp < self.held_until || self.reserving_terminal(clock.slot).is_some()
+    }
+
+    /// POS terminal holding an active reservation on the coupon, if any.
+    pub fn reserving_terminal(&self, slot: u64) -> Option<Pubkey> {
+        (self.reserved_by != Pubkey::default() && slot <= self.reserved_until_slot)
+            .then_some(self.reserved_by)
+    }
+
+    /// Redemption is allowed on free coupons, and on reserved ones only with
+    /// the reserving terminal's signature.
+    pub fn check_reservation(&self, slot: u64, terminal: Option<Pubkey>) -> Result<()> {
+        if let Some(reserved_by) = self.reserving_terminal(slot) {
+            require!(
+                terminal == Some(reserved_by),
+                PromoError::CouponReservedElsewhere
+            );
+        }
+        Ok(())
+    }
+}
+
+/// Redemption receipt: permanent record of a redeemed coupon, kept after the
+/// coupon account is burned. Receipts are protocol-wide and can be presented
+/// as proof of past behavior by other campaigns.
+///
+/// Receipts double as the user's proof-of-redemption collectible: no
+/// instruction changes `user`, so they are non-transferable, and merchants can
+/// find them by `user` / `merchant` (memcmp filters) for retargeting.
+#[account]
+pub struct RedemptionReceipt {
+    pub user: Pubkey,          // 32 bytes - wallet that redeemed the coupon
+    pub merchant: Pubkey,      // 32 bytes
+    pub campaign: Pubkey,      // 32 bytes
+    pub coupon_index: u64,     // 8 bytes
+    pub category_code: u16,    // 2 bytes
+    pub product_code: u16,     // 2 bytes
+    pub purchase_amount: u64,  // 8 bytes
+    pub discount_value: u64,   // 8 bytes
+    pub redeemed_at: i64,      // 8 bytes
+    pub sequence: u32,         // 4 bytes - redemption sequence number in the campaign (1-based)
+    pub period: u32,           // 4 bytes - campaign period the coupon was issued in
+}
+
+impl RedemptionReceipt {
+    pub const SIZE: usize = 32 + 32 + 32 + 8 + 2 + 2 + 8 + 8 + 8 + 4 + 4; // 140 bytes
+}
+
+/// Customer history: per-(merchant, user) redemption history, updated by
+/// every `redeem_coupon` across all campaigns of the merchant.
+#[account]
+pub struct CustomerHistory {
+    pub merchant: Pubkey,          // 32 bytes
+    pub user: Pubkey,              // 32 bytes
+    pub redemption_count: u32,     // 4 bytes - coupons redeemed by this user at this merchant
+    pub first_redeemed_at: i64,    // 8 bytes
+    pub last_redeemed_at: i64,     // 8 bytes
+    pub streak: u16,               // 2 bytes - consecutive weeks with at least one redemption
+    pub streak_period: i64,        // 8 bytes - week index of the last redemption
+}
+
+impl CustomerHistory {
+    pub const SIZE: usize = 32 + 32 + 4 + 8 + 8 + 2 + 8; // 94 bytes
+
+    /// Streak period length (one week).
+    pub const STREAK_PERIOD_SECS: i64 = 7 * 86_400;
+
+    /// Streak after a redemption at `now`: unchanged within the same week,
+    /// +1 for the following week, reset to 1 after a missed week.
+    pub fn next_streak(&self, now: i64) -> (u16, i64) {
+        let period = now.div_euclid(Self::STREAK_PERIOD_SECS);
+        let streak = if self.redemption_count == 0 {
+            1
+        } else if period == self.streak_period {
+            self.streak
+        } else if period == self.streak_period + 1 {
+            self.streak.saturating_add(1)
+        } else {
+            1
+        };
+        (streak, period)
+    }
+}
+
+/// User stats: per-(campaign, user) redemption totals, updated by every
+/// `redeem_coupon` and used to enforce `Campaign.max_discount_per_user`.
+#[account]
+pub struct UserStats {
+    pub campaign: Pubkey,              // 32 bytes
+    pub user: Pubkey,                  // 32 bytes
+    pub redeemed_coupons: u32,         // 4 bytes
+    pub total_discount_lamports: u64,  // 8 bytes - cumulative discount received in this campaign
+}
+
+impl UserStats {
+    pub const SIZE: usize = 32 + 32 + 4 + 8; // 76 bytes
+}
+
+/// Wallet coupon count: coupons a wallet received from a campaign, used to
+/// enforce `Campaign.max_coupons_per_wallet`. One PDA per (campaign, wallet,
+/// period), so the cap starts over when `renew_campaign` opens a new period.
+#[account]
+pub struct WalletCouponCount {
+    pub campaign: Pubkey,  // 32 bytes
+    pub wallet: Pubkey,    // 32 bytes
+    pub received: u32,     // 4 bytes - coupons minted / claimed to this wallet (transfers not counted)
+}
+
+impl WalletCouponCount {
+    pub const SIZE: usize = 32 + 32 + 4; // 68 bytes
+}
+
+/// Category claim count: coupons a wallet received in a category during
+/// `epoch`, used to enforce `GlobalConfig.category_claims_per_epoch`.
+/// One PDA per (wallet, category); reset when a new epoch starts.
+#[account]
+pub struct CategoryClaimCount {
+    pub wallet: Pubkey,       // 32 bytes
+    pub category_code: u16,   // 2 bytes
+    pub epoch: u64,           // 8 bytes - epoch `claimed` refers to
+    pub claimed: u32,         // 4 bytes
+}
+
+impl CategoryClaimCount {
+    pub const SIZE: usize = 32 + 2 + 8 + 4; // 46 bytes
+}
+
+/// Device key: an in-store terminal key allowed to mint coupons for all
+/// campaigns of a merchant, up to a daily quota. One PDA per (merchant, device).
+#[account]
+pub struct DeviceKey {
+    pub merchant: Pubkey,   // 32 bytes
+    pub device: Pubkey,     // 32 bytes - terminal signing key
+    pub daily_quota: u32,   // 4 bytes  - max coupons minted per UTC day
+    pub day: i64,           // 8 bytes  - day index of `minted_today`
+    pub minted_today: u32,  // 4 bytes
+    pub revoked: bool,      // 1 byte
+    pub bump: u8,           // 1 byte
+}
+
+impl DeviceKey {
+    pub const SIZE: usize = 32 + 32 + 4 + 8 + 4 + 1 + 1; // 82 bytes
+}
+
+/// Achievement definition: a merchant milestone such as "redeem 5 coupons in
+/// category X". One PDA per (merchant, achievement_id).
+#[account]
+pub struct Achievement {
+    pub merchant: Pubkey,           // 32 bytes
+    pub achievement_id: u64,        // 8 bytes
+    pub category_code: u16,         // 2 bytes - counted category (0 = any category)
+    pub required_redemptions: u32,  // 4 bytes - redemptions needed to unlock
+    pub bump: u8,                   // 1 byte
+}
+
+impl Achievement {
+    pub const SIZE: usize = 32 + 8 + 2 + 4 + 1; // 47 bytes
+}
+
+/// Achievement progress: per-(achievement, user) counter, updated by
+/// `redeem_coupon` when passed as a remaining account.
+#[account]
+pub struct AchievementProgress {
+    pub achievement: Pubkey,   // 32 bytes
+    pub user: Pubkey,          // 32 bytes
+    pub redemptions: u32,      // 4 bytes - matching redemptions since joining
+    pub unlocked_at: i64,      // 8 bytes - 0 while locked
+    pub bump: u8,              // 1 byte
+}
+
+impl AchievementProgress {
+    pub const SIZE: usize = 32 + 32 + 4 + 8 + 1; // 77 bytes
+
+    pub fn is_unlocked(&self) -> bool {
+        self.unlocked_at != 0
+    }
+}
+
+/// Lottery: sweepstakes add-on of a campaign. Every coupon issued in the
+/// campaign period of a draw is a ticket (see `commit_draw`) and the prize
+/// pot is held by this PDA. One PDA per campaign.
+#[account]
+pub struct Lottery {
+    pub campaign: Pubkey,            // 32 bytes
+    pub randomness_oracle: Pubkey,   // 32 bytes - authority committing to / revealing the draw secrets
+    pub prize_lamports: u64,         // 8 bytes  - prize paid to each winner
+    pub total_winners: u8,           // 1 byte
+    pub winners_drawn: u8,           // 1 byte
+    pub bump: u8,                    // 1 byte
+}
+
+impl Lottery {
+    pub const SIZE: usize = 32 + 32 + 8 + 1 + 1 + 1; // 75 bytes
+}
+
+/// Pending lottery draw: the randomness oracle's commitment to its secret and
+/// the ticket snapshot it draws from, revealed by `draw_winner`. One PDA per
+/// lottery, closed by the draw, `abandon_draw` or `close_lottery`.
+#[account]
+pub struct DrawCommitment {
+    pub lottery: Pubkey,             // 32 bytes
+    pub commitment: [u8; 32],        // 32 bytes - sha256 of the oracle secret
+    pub slot: u64,                   // 8 bytes  - slot of the commitment, whose hash seeds the draw
+    pub draw_number: u8,             // 1 byte   - lottery.winners_drawn when committed
+    pub period: u32,                 // 4 bytes  - campaign period the tickets were issued in
+    pub ticket_root: [u8; 32],       // 32 bytes - Merkle root of the ticket list (see `draw_ticket_leaf`)
+    pub ticket_count: u32,           // 4 bytes  - number of tickets in the list
+}
+
+impl DrawCommitment {
+    pub const SIZE: usize = 32 + 32 + 8 + 1 + 4 + 32 + 4; // 113 bytes
+
+    /// Slots a commitment's slot hash stays in the `SlotHashes` sysvar; the
+    /// draw must be revealed within this window.
+    pub const REVEAL_WINDOW_SLOTS: u64 = 512;
+}
+
+/// Export checkpoint: last redemption sequence number an external ETL
+/// pipeline acknowledged for a campaign. One PDA per campaign.
+#[account]
+pub struct ExportCheckpoint {
+    pub campaign: Pubkey,     // 32 bytes
+    pub exporter: Pubkey,     // 32 bytes - key allowed to acknowledge exports
+    pub last_sequence: u32,   // 4 bytes  - last exported `RedemptionReceipt.sequence`
+    pub updated_at: i64,      // 8 bytes
+    pub bump: u8,             // 1 byte
+}
+
+impl ExportCheckpoint {
+    pub const SIZE: usize = 32 + 32 + 4 + 8 + 1; // 77 bytes
+}
+
+/// QR nonce: one-time-use code printed on an in-store QR. Consumed by
+/// `redeem_coupon` so the same QR can't be redeemed twice. Only the hash of
+/// the nonce is on-chain, so the printed code can't be read off the account.
+/// One PDA per (campaign, nonce hash).
+#[account]
+pub struct QrNonce {
+    pub campaign: Pubkey,      // 32 bytes
+    pub nonce_hash: [u8; 32],  // 32 bytes - sha256 of the value encoded in the QR
+    pub consumed: bool,        // 1 byte
+    pub consumed_by: Pubkey,   // 32 bytes - redeemer (default until consumed)
+    pub consumed_at: i64,      // 8 bytes
+    pub bump: u8,              // 1 byte
+}
+
+impl QrNonce {
+    pub const SIZE: usize = 32 + 32 + 1 + 32 + 8 + 1; // 106 bytes
+}
+
+/// One secondary-market sale in a `TradeHistory`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct TradeEntry {
+    pub price_lamports: u64,   // 8 bytes
+    pub timestamp: i64,        // 8 bytes
+    pub parties_hash: [u8; 8], // 8 bytes - truncated sha256(buyer || seller)
+}
+
+impl TradeEntry {
+    pub const SIZE: usize = 8 + 8 + 8; // 24 bytes
+}
+
+/// Trade history: ring buffer of the last `MAX_TRADES` secondary sales of a
+/// campaign, for pricing widgets. One PDA per campaign.
+#[account]
+pub struct TradeHistory {
+    pub campaign: Pubkey,                                // 32 bytes
+    pub head: u8,                                        // 1 byte  - slot of the next write
+    pub len: u8,                                         // 1 byte  - number of filled slots
+    pub trades: [TradeEntry; TradeHistory::MAX_TRADES],  // MAX_TRADES * 24 bytes
+}
+
+impl TradeHistory {
+    pub const MAX_TRADES: usize = 16;
+    pub const SIZE: usize = 32 + 1 + 1 + Self::MAX_TRADES * TradeEntry::SIZE; // 418 bytes
+
+    /// Append a sale, overwriting the oldest one once the buffer is full.
+    pub fn record(&mut self, price_lamports: u64, timestamp: i64, buyer: &Pubkey, seller: &Pubkey) {
+        let digest = hashv(&[buyer.as_ref(), seller.as_ref()]).to_bytes();
+        let mut parties_hash = [0u8; 8];
+        parties_hash.copy_from_slice(&digest[..8]);
+
+        self.trades[self.head as usize] = TradeEntry {
+            price_lamports,
+            timestamp,
+            parties_hash,
+        };
+        self.head = ((self.head as usize + 1) % Self::MAX_TRADES) as u8;
+        if (self.len as usize) < Self::MAX_TRADES {
+            self.len += 1;
+        }
+    }
+}
+
+/// Campaign archive: compact, immutable summary of a finished campaign,
+/// meant to outlive the campaign, vault and coupon accounts.
+/// One PDA per campaign.
+#[account]
+pub struct CampaignArchive {
+    pub campaign: Pubkey,              // 32 bytes
+    pub merchant: Pubkey,              // 32 bytes
+    pub campaign_id: u64,              // 8 bytes
+    pub total_coupons: u32,            // 4 bytes
+    pub minted_coupons: u32,           // 4 bytes
+    pub used_coupons: u32,             // 4 bytes
+    pub total_purchase_amount: u64,    // 8 bytes
+    pub total_discount_lamports: u64,  // 8 bytes
+    pub total_deposit: u64,            // 8 bytes - budget breakdown (from the vault)
+    pub total_mint_spent: u64,         // 8 bytes
+    pub total_service_spent: u64,      // 8 bytes
+    pub matched_lamports: u64,         // 8 bytes
+    pub receipts_hash: [u8; 32],       // 32 bytes - `Campaign.receipts_hash` at archival
+    pub archived_at: i64,              // 8 bytes
+}
+
+impl CampaignArchive {
+    pub const SIZE: usize = 32 + 32 + 8 + 4 + 4 + 4 + 8 + 8 + 8 + 8 + 8 + 8 + 32 + 8; // 172 bytes
+}
+
+/// NFT claim marker: the NFT `nft_mint` already claimed its coupon of
+/// `campaign` through `claim_coupon` (or, for a compressed NFT, its asset id
+/// through `claim_coupon_with_cnft`). One PDA per (campaign, NFT mint,
+/// period): each NFT claims again in every period of a renewed campaign.
+#[account]
+pub struct NftClaim {
+    pub campaign: Pubkey,   // 32 bytes
+    pub nft_mint: Pubkey,   // 32 bytes
+    pub claimant: Pubkey,   // 32 bytes - holder at claim time
+    pub coupon: Pubkey,     // 32 bytes - coupon issued for this claim
+    pub claimed_at: i64,    // 8 bytes
+}
+
+impl NftClaim {
+    pub const SIZE: usize = 32 + 32 + 32 + 32 + 8; // 136 bytes
+}
+
+/// Voucher nonce: a merchant voucher already consumed by `claim_with_voucher`.
+/// One PDA per (campaign, nonce).
+#[account]
+pub struct VoucherNonce {
+    pub campaign: Pubkey,    // 32 bytes
+    pub nonce: u64,          // 8 bytes
+    pub claimant: Pubkey,    // 32 bytes
+    pub consumed_at: i64,    // 8 bytes
+}
+
+impl VoucherNonce {
+    pub const SIZE: usize = 32 + 8 + 32 + 8; // 80 bytes
+}
+
+/// Blocklist entry: `wallet` can't receive coupons of `campaign`.
+/// One PDA per (campaign, wallet); closed to unblock.
+#[account]
+pub struct BlockedWallet {
+    pub campaign: Pubkey,    // 32 bytes
+    pub wallet: Pubkey,      // 32 bytes
+    pub blocked_at: i64,     // 8 bytes
+}
+
+impl BlockedWallet {
+    pub const SIZE: usize = 32 + 32 + 8; // 72 bytes
+}
+
+/// Claim ticket: merchant pre-authorization for `wallet` to claim one coupon
+/// of `campaign` with `finalize_claim`. One PDA per (campaign, wallet).
+#[account]
+pub struct ClaimTicket {
+    pub campaign: Pubkey,    // 32 bytes
+    pub wallet: Pubkey,      // 32 bytes - only wallet that can finalize the claim
+    pub payer: Pubkey,       // 32 bytes - receives the ticket rent back
+    pub issued_at: i64,      // 8 bytes
+}
+
+impl ClaimTicket {
+    pub const SIZE: usize = 32 + 32 + 32 + 8; // 104 bytes
+}
+
+/// Wallet registration: the epoch a wallet registered with the program, its
+/// on-chain "account age" for the sybil gate. One PDA per wallet.
+#[account]
+pub struct WalletRegistration {
+    pub wallet: Pubkey,          // 32 bytes
+    pub registered_epoch: u64,   // 8 bytes
 }
 
+impl WalletRegistration {
+    pub const SIZE: usize = 32 + 8; // 40 bytes
+}
+
+/// Whitelist entry: `wallet` may receive coupons of a `requires_whitelist`
+/// campaign. One PDA per (campaign, wallet); closed to unwhitelist.
+#[account]
+pub struct WhitelistEntry {
+    pub campaign: Pubkey,      // 32 bytes
+    pub wallet: Pubkey,        // 32 bytes
+    pub claim_deadline: i64,   // 8 bytes - last time this wallet can receive a coupon (0 = until expiration)
+    pub added_at: i64,         // 8 bytes
+}
 
+impl WhitelistEntry {
+    pub const SIZE: usize = 32 + 32 + 8 + 8; // 80 bytes
+}
 
+/// Withholding rule: per-jurisdiction withholding on secondary sale
+/// proceeds, set by the admin. One PDA per jurisdiction code.
+#[account]
+pub struct WithholdingRule {
+    pub jurisdiction_code: u16,      // 2 bytes
+    pub rate_bps: u16,               // 2 bytes  - share of proceeds withheld
+    pub threshold_lamports: u64,     // 8 bytes  - sales at or below this price are not withheld
+    pub compliance_account: Pubkey,  // 32 bytes - destination of withheld lamports
+    pub bump: u8,                    // 1 byte
+}
 
+impl WithholdingRule {
+    pub const SIZE: usize = 2 + 2 + 8 + 32 + 1; // 45 bytes
+}
 
+/// Location attestation: latest region in which an oracle saw a wallet.
+/// One PDA per (oracle, user), overwritten by each new attestation.
+#[account]
+pub struct LocationAttestation {
+    pub oracle: Pubkey,     // 32 bytes - oracle that signed the attestation
+    pub user: Pubkey,       // 32 bytes - attested wallet
+    pub region_code: u16,   // 2 bytes  - region / store the wallet was seen in
+    pub attested_at: i64,   // 8 bytes  - clock timestamp of the attestation
+}
+
+impl LocationAttestation {
+    pub const SIZE: usize = 32 + 32 + 2 + 8; // 74 bytes
+}
+
+/// Oracle feed: latest value an oracle published for a feed (sports result,
+/// weather reading, ...). One PDA per (oracle, feed_id), overwritten by each
+/// publication.
+#[account]
+pub struct OracleFeed {
+    pub oracle: Pubkey,    // 32 bytes - oracle that publishes the feed
+    pub feed_id: u64,      // 8 bytes
+    pub value: i64,        // 8 bytes  - latest published value
+    pub updated_at: i64,   // 8 bytes
+}
+
+impl OracleFeed {
+    pub const SIZE: usize = 32 + 8 + 8 + 8; // 56 bytes
+}
+
+/// Entry of the `CategoryRegistry`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct CategoryEntry {
+    pub code: u16,        // 2 bytes
+    pub name: String,     // 4 + MAX_NAME_LEN bytes
+    pub deprecated: bool, // 1 byte - no new campaigns in this category
+}
+
+impl CategoryEntry {
+    pub const MAX_NAME_LEN: usize = 32;
+
+    pub const SIZE: usize = 2 + 4 + Self::MAX_NAME_LEN + 1;
+}
+
+/// Admin-curated list of the `category_code`s campaigns can use, so
+/// analytics aggregate consistently across merchants.
+#[account]
+pub struct CategoryRegistry {
+    pub categories: Vec<CategoryEntry>, // 4 + MAX_CATEGORIES * CategoryEntry::SIZE bytes
+}
+
+impl CategoryRegistry {
+    pub const MAX_CATEGORIES: usize = 64;
+
+    pub const SIZE: usize = 4 + Self::MAX_CATEGORIES * CategoryEntry::SIZE;
+
+    /// Whether `code` is registered and not deprecated.
+    pub fn is_active(&self, code: u16) -> bool {
+        self.categories
+            .iter()
+            .any(|entry| entry.code == code && !entry.deprecated)
+    }
+}
+
+/// Campaign statistics at one epoch, written by `snapshot_campaign_stats`
+/// so dashboards get a time series without indexing every redemption.
+#[account]
+pub struct CampaignSnapshot {
+    pub campaign: Pubkey,             // 32 bytes
+    pub epoch: u64,                   // 8 bytes - epoch of the snapshot (PDA seed)
+    pub minted_coupons: u32,          // 4 bytes
+    pub used_coupons: u32,            // 4 bytes
+    pub total_purchase_amount: u64,   // 8 bytes
+    pub total_discount_lamports: u64, // 8 bytes
+    pub vault_lamports: u64,          // 8 bytes - vault balance (rent included)
+    pub total_mint_spent: u64,        // 8 bytes
+    pub total_service_spent: u64,     // 8 bytes
+    pub taken_at: i64,                // 8 bytes - last write within the epoch
+}
+
+impl CampaignSnapshot {
+    pub const SIZE: usize = 32 + 8 + 4 + 4 + 8 + 8 + 8 + 8 + 8 + 8; // 96 bytes
+}
+
+/// Merchant profile: canonical on-chain identity of a merchant, created once
+/// with `register_merchant` and required to create campaigns.
+#[account]
+pub struct MerchantProfile {
+    pub merchant: Pubkey,     // 32 bytes - merchant wallet
+    pub name: String,         // 4 + MAX_NAME_LEN bytes - display name
+    pub metadata_uri: String, // 4 + MAX_URI_LEN bytes - off-chain metadata (logo, website, ...)
+    pub registered_at: i64,   // 8 bytes
+}
+
+impl MerchantProfile {
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_URI_LEN: usize = 200;
+
+    pub const SIZE: usize = 32 + 4 + Self::MAX_NAME_LEN + 4 + Self::MAX_URI_LEN + 8;
+}
+
+/// Segment account: a reusable audience (wallet list and/or Merkle root)
+/// that a merchant creates once and references from multiple campaigns.
+#[account]
+pub struct Segment {
+    pub merchant: Pubkey,      // 32 bytes - merchant that owns this segment
+    pub segment_id: u64,       // 8 bytes  - merchant-chosen id
+    pub name: String,          // 4 + MAX_NAME_LEN bytes - e.g. "VIP customers Q3"
+    pub merkle_root: [u8; 32], // 32 bytes - root of a wallet Merkle tree (zeroed = unused)
+    pub wallets: Vec<Pubkey>,  // 4 + MAX_WALLETS * 32 bytes - explicit wallet list
+    pub updated_at: i64,       // 8 bytes  - last time the audience was set
+}
+
+impl Segment {
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_WALLETS: usize = 32;
+
+    pub const SIZE: usize = 32 + 8 + 4 + Self::MAX_NAME_LEN + 32 + 4 + Self::MAX_WALLETS * 32 + 8;
+
+    /// Whether `wallet` belongs to this segment, either through the explicit
+    /// wallet list or through a Merkle `proof` against `merkle_root`.
+    pub fn contains(&self, wallet: &Pubkey, proof: &[[u8; 32]]) -> bool {
+        if self.wallets.contains(wallet) {
+            return true;
+        }
+        self.merkle_root != [0u8; 32]
+            && crate::utils::verify_wallet_proof(wallet, proof, &self.merkle_root)
+    }
+}
+
+/// How a campaign computes the discount at redemption (always capped by
+/// `max_discount_lamports`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiscountKind {
+    /// `discount_bps` of the purchase (including discount phases / streak bonus).
+    #[default]
+    Percentage,
+    /// Fixed lamports off the purchase, never more than the purchase itself.
+    FixedLamports(u64),
+}
+
+impl DiscountKind {
+    pub const SIZE: usize = 1 + 8; // tag + largest variant
+}
+
+/// What a coupon is for, set by the merchant at mint so wallets and POS
+/// systems can render it without a separate lookup (all fields optional).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct CouponMetadata {
+    pub sku: u32,     // 4 bytes - merchant SKU (0 = none)
+    pub memo: String, // 4 + MAX_MEMO_LEN bytes - short human-readable note
+    pub uri: String,  // 4 + MAX_URI_LEN bytes - link to details / artwork
+}
+
+impl CouponMetadata {
+    pub const MAX_MEMO_LEN: usize = 32;
+    pub const MAX_URI_LEN: usize = 64;
+    pub const SIZE: usize = 4 + 4 + Self::MAX_MEMO_LEN + 4 + Self::MAX_URI_LEN; // 108 bytes
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.memo.len() <= Self::MAX_MEMO_LEN && self.uri.len() <= Self::MAX_URI_LEN,
+            PromoError::InvalidCouponMetadata
+        );
+        Ok(())
+    }
+}
+
+/// Scheduled discount step of a campaign: `discount_bps` applies to
+/// redemptions from `start_ts` until the next phase starts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct DiscountPhase {
+    pub start_ts: i64,     // 8 bytes
+    pub discount_bps: u16, // 2 bytes
+}
+
+impl DiscountPhase {
+    pub const SIZE: usize = 8 + 2; // 10 bytes
+}
+
+/// Parameters of a new campaign (see `create_campaign` for their meaning).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CampaignParams {
+    pub campaign_id: u64,
+    pub discount_bps: u16,
+    pub resale_bps: u16,
+    pub expiration_timestamp: i64,
+    pub total_coupons: u32,
+    pub mint_cost_lamports: u64,
+    pub max_discount_lamports: u64,
+    pub category_code: u16,
+    pub product_code: u16,
+    pub campaign_name: String,
+    pub deposit_amount: u64,
+    pub requires_wallet: bool,
+    pub target_wallet: Pubkey,
+    pub draft: bool,
+}
+
+/// Targeting parameters shared by a `TargetingTemplate` and copied into a
+/// campaign at creation. Same semantics (and "0 / default = off") as the
+/// matching `Campaign` fields.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct TargetingParams {
+    pub required_category_code: u16,      // 2 bytes
+    pub min_category_redemptions: u8,     // 1 byte
+    pub required_collection: Pubkey,      // 32 bytes
+    pub required_cnft_collection: Pubkey, // 32 bytes
+    pub gate_mint: Pubkey,                // 32 bytes
+    pub gate_min_amount: u64,             // 8 bytes
+    pub region_attestor: Pubkey,          // 32 bytes
+    pub region_code: u16,                 // 2 bytes
+    pub first_purchase_only: bool,        // 1 byte
+    pub min_prior_redemptions: u32,       // 4 bytes
+    pub max_coupons_per_wallet: u32,      // 4 bytes
+    pub sns_parent_domain: Pubkey,        // 32 bytes
+    pub eligibility_program: Pubkey,      // 32 bytes
+    pub kyc_attestation_program: Pubkey,  // 32 bytes
+    pub kyc_credential: Pubkey,           // 32 bytes
+    pub kyc_schema: Pubkey,               // 32 bytes
+    pub requires_whitelist: bool,         // 1 byte
+    pub sybil_min_stake_lamports: u64,    // 8 bytes
+    pub sybil_min_wallet_age_epochs: u64, // 8 bytes
+}
+
+impl TargetingParams {
+    pub const SIZE: usize = 2 + 1 + 32 + 32 + 32 + 8 + 32 + 2 + 1 + 4 + 4 + 32 + 32 + 32 + 32 + 32
+        + 1 + 8 + 8;
+
+    /// Same consistency rules as the individual campaign setters.
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            (self.gate_mint == Pubkey::default()) == (self.gate_min_amount == 0),
+            PromoError::InvalidTokenGate
+        );
+        require!(
+            self.min_prior_redemptions == 0 || !self.first_purchase_only,
+            PromoError::InvalidLoyaltyTier
+        );
+        require_keys_neq!(
+            self.eligibility_program,
+            crate::ID,
+            PromoError::InvalidEligibilityProgram
+        );
+        Ok(())
+    }
+
+    /// Targeting parameters currently set on `campaign`.
+    pub fn of(campaign: &Campaign) -> Self {
+        Self {
+            required_category_code: campaign.required_category_code,
+            min_category_redemptions: campaign.min_category_redemptions,
+            required_collection: campaign.required_collection,
+            required_cnft_collection: campaign.required_cnft_collection,
+            gate_mint: campaign.gate_mint,
+            gate_min_amount: campaign.gate_min_amount,
+            region_attestor: campaign.region_attestor,
+            region_code: campaign.region_code,
+            first_purchase_only: campaign.first_purchase_only,
+            min_prior_redemptions: campaign.min_prior_redemptions,
+            max_coupons_per_wallet: campaign.max_coupons_per_wallet,
+            sns_parent_domain: campaign.sns_parent_domain,
+            eligibility_program: campaign.eligibility_program,
+            kyc_attestation_program: campaign.kyc_attestation_program,
+            kyc_credential: campaign.kyc_credential,
+            kyc_schema: campaign.kyc_schema,
+            requires_whitelist: campaign.requires_whitelist,
+            sybil_min_stake_lamports: campaign.sybil_min_stake_lamports,
+            sybil_min_wallet_age_epochs: campaign.sybil_min_wallet_age_epochs,
+        }
+    }
+
+    /// Copy the parameters into a campaign being created.
+    pub fn apply_to(&self, campaign: &mut Campaign) {
+        campaign.required_category_code = self.required_category_code;
+        campaign.min_category_redemptions = self.min_category_redemptions;
+        campaign.required_collection = self.required_collection;
+        campaign.required_cnft_collection = self.required_cnft_collection;
+        campaign.gate_mint = self.gate_mint;
+        campaign.gate_min_amount = self.gate_min_amount;
+        campaign.region_attestor = self.region_attestor;
+        campaign.region_code = self.region_code;
+        campaign.first_purchase_only = self.first_purchase_only;
+        campaign.min_prior_redemptions = self.min_prior_redemptions;
+        campaign.max_coupons_per_wallet = self.max_coupons_per_wallet;
+        campaign.sns_parent_domain = self.sns_parent_domain;
+        campaign.eligibility_program = self.eligibility_program;
+        campaign.kyc_attestation_program = self.kyc_attestation_program;
+        campaign.kyc_credential = self.kyc_credential;
+        campaign.kyc_schema = self.kyc_schema;
+        campaign.requires_whitelist = self.requires_whitelist;
+        campaign.sybil_min_stake_lamports = self.sybil_min_stake_lamports;
+        campaign.sybil_min_wallet_age_epochs = self.sybil_min_wallet_age_epochs;
+    }
+}
+
+/// Targeting template: a merchant-owned set of targeting parameters that
+/// `create_campaign` can copy from, instead of configuring every gate on
+/// each new campaign. Campaigns keep their own copy, so later template
+/// updates only affect campaigns created afterwards.
+#[account]
+pub struct TargetingTemplate {
+    pub merchant: Pubkey,         // 32 bytes - merchant that owns this template
+    pub template_id: u64,         // 8 bytes  - merchant-chosen id
+    pub segment: Pubkey,          // 32 bytes - audience segment (default = none)
+    pub params: TargetingParams,  // TargetingParams::SIZE bytes
+    pub updated_at: i64,          // 8 bytes
+}
+
+impl TargetingTemplate {
+    pub const SIZE: usize = 32 + 8 + 32 + TargetingParams::SIZE + 8;
+}
+
+/// OTC swap offer: the coupon owner proposes to hand over a coupon to a
+/// specific counterparty for a fixed lamport amount, within the campaign's
+/// resale cap.
+/// Both legs settle atomically in `accept_coupon_swap`.
+#[account]
+pub struct SwapOffer {
+    pub coupon: Pubkey,        // 32 bytes - coupon offered
+    pub seller: Pubkey,        // 32 bytes - coupon owner at proposal time
+    pub counterparty: Pubkey,  // 32 bytes - only wallet allowed to accept
+    pub amount: u64,           // 8 bytes  - lamports asked
+    pub created_at: i64,       // 8 bytes
+    pub bump: u8,              // 1 byte
+}
+
+impl SwapOffer {
+    pub const SIZE: usize = 32 + 32 + 32 + 8 + 8 + 1; // 113 bytes
+}
+
+/// Emergency withdrawal proposal: published by the admin for a specific vault
+/// and executable only after a mandatory delay, so merchants and users can
+/// observe it on-chain and react before any funds move.
+#[account]
+pub struct EmergencyProposal {
+    pub vault: Pubkey,        // 32 bytes - vault the funds are taken from
+    pub destination: Pubkey,  // 32 bytes - recipient of the withdrawn lamports
+    pub amount: u64,          // 8 bytes
+    pub created_at: i64,      // 8 bytes
+    pub executable_at: i64,   // 8 bytes  - created_at + TIMELOCK_SECS
+    pub bump: u8,             // 1 byte
+}
+
+impl EmergencyProposal {
+    /// Mandatory delay between proposal and execution (48 hours).
+    pub const TIMELOCK_SECS: i64 = 48 * 60 * 60;
+
+    pub const SIZE: usize = 32 + 32 + 8 + 8 + 8 + 1; // 89 bytes
+}
+
+/// Tombstone of a closed campaign: keeps its `campaign_id` retired, so the
+/// campaign PDA can't be re-created on top of the child accounts (receipts,
+/// wallet counters, claim markers, ...) that outlive it.
+/// One PDA per closed campaign: `["closed_campaign", campaign]`.
+#[account]
+pub struct ClosedCampaign {
+    pub campaign_id: u64,     // 8 bytes
+    pub closed_at: i64,       // 8 bytes
+}
+
+impl ClosedCampaign {
+    pub const SIZE: usize = 8 + 8; // 16 bytes
+}
@@ -1,6 +1,21 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use anchor_lang::system_program;
+use anchor_spl::token::TokenAccount;
+use solana_keccak_hasher as keccak;
 
 use crate::errors::PromoError;
+use crate::states::{
+    Achievement, AchievementProgress, Campaign, CategoryClaimCount, Coupon, CouponMetadata, CustomerHistory,
+    DiscountKind, GlobalConfig, RedemptionReceipt, Segment, UserStats, Vault, WalletCouponCount,
+    WalletRegistration, WhitelistEntry, WithholdingRule,
+};
 
 pub fn transfer_lamports<'info>(
     from: &AccountInfo<'info>,
@@ -26,4 +41,1905 @@ pub fn transfer_lamports<'info>(
     **to.try_borrow_mut_lamports()? = new_to;
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Whether `timestamp` falls inside a weekday / hour schedule.
+///
+/// - `days_mask`: bit 0 = Sunday ... bit 6 = Saturday (0 = any day).
+/// - `hours_mask`: bit h = hour h of the day (0 = any hour).
+/// - `utc_offset_mins`: local time offset applied before evaluating the masks.
+pub fn is_within_schedule(
+    timestamp: i64,
+    days_mask: u8,
+    hours_mask: u32,
+    utc_offset_mins: i16,
+) -> bool {
+    const SECONDS_PER_DAY: i64 = 86_400;
+
+    let local = timestamp.saturating_add(utc_offset_mins as i64 * 60);
+    // 1970-01-01 was a Thursday (weekday 4 with Sunday = 0)
+    let weekday = (local.div_euclid(SECONDS_PER_DAY) + 4).rem_euclid(7);
+    let hour = local.rem_euclid(SECONDS_PER_DAY) / 3_600;
+
+    let day_ok = days_mask == 0 || days_mask & (1 << weekday) != 0;
+    let hour_ok = hours_mask == 0 || hours_mask & (1 << hour) != 0;
+    day_ok && hour_ok
+}
+
+/// Vault lamports above the rent-exempt minimum.
+pub fn vault_spendable(vault: &AccountInfo) -> Result<u64> {
+    let rent_floor = Rent::get()?.minimum_balance(vault.data_len());
+    Ok(vault.lamports().saturating_sub(rent_floor))
+}
+
+/// `amount * bps / 10_000`, rounded per `rounding_mode`
+/// (`GlobalConfig::ROUNDING_*`; unknown modes floor).
+pub fn apply_bps(amount: u64, bps: u64, rounding_mode: u8) -> Result<u64> {
+    let product = (amount as u128)
+        .checked_mul(bps as u128)
+        .ok_or(PromoError::Overflow)?;
+    let quotient = product / 10_000;
+    let remainder = product % 10_000;
+
+    let round_up = match rounding_mode {
+        GlobalConfig::ROUNDING_CEIL => remainder > 0,
+        GlobalConfig::ROUNDING_BANKERS => {
+            remainder > 5_000 || (remainder == 5_000 && quotient % 2 == 1)
+        }
+        _ => false,
+    };
+
+    u64::try_from(quotient + round_up as u128).map_err(|_| PromoError::Overflow.into())
+}
+
+/// Worst-case lamports the vault still owes: the mint cost, maximum service
+/// fee and gas rebate of every coupon not minted yet, plus what it owes for
+/// the coupons already minted (see `outstanding_obligations`).
+pub fn vault_obligations(campaign: &Campaign) -> Result<u64> {
+    let unminted = campaign.total_coupons.saturating_sub(campaign.minted_coupons) as u64;
+    let per_coupon = campaign
+        .mint_cost_lamports
+        .checked_add(max_redemption_cost(campaign)?)
+        .ok_or(PromoError::Overflow)?;
+    let unminted_costs = unminted
+        .checked_mul(per_coupon)
+        .ok_or(PromoError::Overflow)?;
+
+    Ok(unminted_costs
+        .checked_add(outstanding_obligations(campaign)?)
+        .ok_or(PromoError::Overflow)?)
+}
+
+/// Worst-case lamports the vault owes for the minted coupons not redeemed
+/// yet: the maximum service fee (rounded up) and gas rebate of each.
+pub fn outstanding_obligations(campaign: &Campaign) -> Result<u64> {
+    let outstanding = campaign.minted_coupons.saturating_sub(campaign.used_coupons) as u64;
+
+    Ok(outstanding
+        .checked_mul(max_redemption_cost(campaign)?)
+        .ok_or(PromoError::Overflow)?)
+}
+
+/// Worst-case lamports one redemption costs the vault: the maximum service
+/// fee (rounded up) plus the gas rebate.
+pub fn max_redemption_cost(campaign: &Campaign) -> Result<u64> {
+    let max_service_fee = apply_bps(
+        campaign.max_discount_lamports,
+        campaign.service_fee_bps as u64,
+        GlobalConfig::ROUNDING_CEIL,
+    )?;
+
+    Ok(max_service_fee
+        .checked_add(campaign.gas_rebate_lamports)
+        .ok_or(PromoError::Overflow)?)
+}
+
+/// Flag an "until funds last" campaign as expired once the uncommitted vault
+/// balance (spendable lamports minus `outstanding_obligations`) can no longer
+/// pay for another coupon.
+pub fn check_budget_exhaustion(
+    campaign_key: Pubkey,
+    campaign: &mut Campaign,
+    vault: &AccountInfo,
+    now: i64,
+) -> Result<()> {
+    if !campaign.expire_when_budget_exhausted || campaign.budget_exhausted_at != 0 {
+        return Ok(());
+    }
+    let uncommitted = vault_spendable(vault)?.saturating_sub(outstanding_obligations(campaign)?);
+    if uncommitted < campaign.mint_cost_lamports.max(1) {
+        campaign.budget_exhausted_at = now;
+        emit!(CampaignBudgetExhausted {
+            campaign: campaign_key,
+            partner: campaign.partner,
+            partner_share_bps: campaign.partner_share_bps,
+            exhausted_at: now,
+        });
+    }
+    Ok(())
+}
+
+/// Event emitted when an "until funds last" campaign expires because its
+/// vault ran dry.
+#[event]
+pub struct CampaignBudgetExhausted {
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub exhausted_at: i64,
+}
+
+/// Emit `VaultLowBalance` while the spendable vault lamports are below the
+/// merchant's `low_balance_threshold` (0 = no alert).
+pub fn check_low_balance(
+    campaign_key: Pubkey,
+    campaign: &Campaign,
+    vault: &Account<Vault>,
+) -> Result<()> {
+    if vault.low_balance_threshold == 0 {
+        return Ok(());
+    }
+    let remaining = vault_spendable(&vault.to_account_info())?;
+    if remaining < vault.low_balance_threshold {
+        emit!(VaultLowBalance {
+            campaign: campaign_key,
+            partner: campaign.partner,
+            partner_share_bps: campaign.partner_share_bps,
+            vault: vault.key(),
+            merchant: vault.merchant,
+            remaining_lamports: remaining,
+            threshold: vault.low_balance_threshold,
+        });
+    }
+    Ok(())
+}
+
+/// Event emitted after a mint / redemption left the vault below its
+/// low-balance threshold, so dashboards can prompt a top-up.
+#[event]
+pub struct VaultLowBalance {
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub vault: Pubkey,
+    pub merchant: Pubkey,
+    pub remaining_lamports: u64,
+    pub threshold: u64,
+}
+
+/// Day index (days since epoch) of `timestamp` in the merchant's local time.
+pub fn local_day_index(timestamp: i64, utc_offset_mins: i16) -> i64 {
+    timestamp
+        .saturating_add(utc_offset_mins as i64 * 60)
+        .div_euclid(86_400)
+}
+
+/// Verify that `wallet` is a leaf of the Merkle tree with root `root`.
+///
+/// Leaves are `sha256(wallet)` and each parent is `sha256(min(a, b) || max(a, b))`,
+/// so the proof does not need to encode left/right positions.
+pub fn verify_wallet_proof(wallet: &Pubkey, proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+    verify_merkle_leaf(hashv(&[wallet.as_ref()]).to_bytes(), proof, root)
+}
+
+/// Leaf of a lottery ticket list: `sha256(position || coupon_index)`
+/// (little-endian), so each position of the list holds exactly one ticket.
+pub fn draw_ticket_leaf(position: u32, coupon_index: u64) -> [u8; 32] {
+    hashv(&[&position.to_le_bytes(), &coupon_index.to_le_bytes()]).to_bytes()
+}
+
+/// Verify that `leaf` is in the Merkle tree with root `root` (sorted-pair
+/// hashing, as in `verify_wallet_proof`).
+pub fn verify_merkle_leaf(leaf: [u8; 32], proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+    let mut node = leaf;
+    for sibling in proof {
+        node = if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        };
+    }
+    node == *root
+}
+
+/// Count distinct redemption receipts owned by `user` in `category_code`.
+///
+/// Every account must be a `RedemptionReceipt` owned by this program;
+/// duplicated accounts are rejected so a single receipt cannot be counted twice.
+pub fn count_category_receipts(
+    receipts: &[AccountInfo],
+    user: &Pubkey,
+    category_code: u16,
+) -> Result<u32> {
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(receipts.len());
+    let mut count: u32 = 0;
+
+    for info in receipts {
+        require_keys_eq!(*info.owner, crate::ID, PromoError::InvalidReceipt);
+        require!(!seen.contains(info.key), PromoError::InvalidReceipt);
+        seen.push(*info.key);
+
+        let data = info.try_borrow_data()?;
+        let receipt = RedemptionReceipt::try_deserialize(&mut &data[..])?;
+        if receipt.user == *user && receipt.category_code == category_code {
+            count = count.checked_add(1).ok_or(PromoError::Overflow)?;
+        }
+    }
+
+    Ok(count)
+}
+
+/// Advance the user's achievement progress for a redemption in `campaign`.
+///
+/// `accounts` is a flat list of `(achievement, progress)` pairs; progress
+/// accounts must be writable. Achievements of other merchants, other
+/// categories, or already unlocked are rejected / left untouched.
+pub fn record_achievement_progress(
+    accounts: &[AccountInfo],
+    campaign_key: &Pubkey,
+    campaign: &Campaign,
+    user: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    require!(
+        accounts.chunks_exact(2).remainder().is_empty(),
+        PromoError::InvalidAchievement
+    );
+
+    let mut seen: Vec<Pubkey> = Vec::with_capacity(accounts.len() / 2);
+
+    for pair in accounts.chunks(2) {
+        let (achievement_info, progress_info) = (&pair[0], &pair[1]);
+        require_keys_eq!(*achievement_info.owner, crate::ID, PromoError::InvalidAchievement);
+        require_keys_eq!(*progress_info.owner, crate::ID, PromoError::InvalidAchievement);
+        require!(!seen.contains(progress_info.key), PromoError::InvalidAchievement);
+        seen.push(*progress_info.key);
+
+        let achievement = Achievement::try_deserialize(&mut &achievement_info.try_borrow_data()?[..])?;
+        require_keys_eq!(achievement.merchant, campaign.merchant, PromoError::InvalidAchievement);
+        require!(
+            achievement.category_code == 0 || achievement.category_code == campaign.category_code,
+            PromoError::InvalidAchievement
+        );
+
+        let mut data = progress_info.try_borrow_mut_data()?;
+        let mut progress = AchievementProgress::try_deserialize(&mut &data[..])?;
+        require_keys_eq!(progress.achievement, *achievement_info.key, PromoError::InvalidAchievement);
+        require_keys_eq!(progress.user, *user, PromoError::InvalidAchievement);
+
+        if progress.is_unlocked() {
+            continue;
+        }
+
+        progress.redemptions = progress
+            .redemptions
+            .checked_add(1)
+            .ok_or(PromoError::Overflow)?;
+        if progress.redemptions >= achievement.required_redemptions {
+            progress.unlocked_at = now;
+            emit!(AchievementUnlocked {
+                achievement: *achievement_info.key,
+                user: *user,
+                campaign: *campaign_key,
+                partner: campaign.partner,
+                partner_share_bps: campaign.partner_share_bps,
+                unlocked_at: now,
+            });
+        }
+
+        progress.try_serialize(&mut &mut data[..])?;
+    }
+
+    Ok(())
+}
+
+/// Event emitted when a user unlocks an achievement.
+#[event]
+pub struct AchievementUnlocked {
+    pub achievement: Pubkey,
+    pub user: Pubkey,
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub unlocked_at: i64,
+}
+
+/// Verify that `proof` shows `wallet` took part in `campaign`.
+///
+/// Accepted proofs, both owned by this program:
+/// - a `Coupon` of `campaign` currently owned by `wallet` (unredeemed);
+/// - a `RedemptionReceipt` of `campaign` redeemed by `wallet`.
+pub fn verify_campaign_participation(
+    proof: &AccountInfo,
+    campaign: &Pubkey,
+    wallet: &Pubkey,
+) -> Result<()> {
+    let (proof_campaign, _, holder, _) =
+        coupon_participant(proof)?.ok_or(PromoError::InvalidPrerequisiteProof)?;
+
+    require_keys_eq!(proof_campaign, *campaign, PromoError::InvalidPrerequisiteProof);
+    require_keys_eq!(holder, *wallet, PromoError::InvalidPrerequisiteProof);
+
+    Ok(())
+}
+
+/// Read `(campaign, coupon_index, holder, period)` from a `Coupon` (its
+/// owner) or a `RedemptionReceipt` (its redeemer) owned by this program.
+///
+/// Returns `None` for any other account.
+pub fn coupon_participant(account: &AccountInfo) -> Result<Option<(Pubkey, u64, Pubkey, u32)>> {
+    if *account.owner != crate::ID {
+        return Ok(None);
+    }
+
+    let data = account.try_borrow_data()?;
+    if data.starts_with(Coupon::DISCRIMINATOR) {
+        let coupon = Coupon::try_deserialize(&mut &data[..])?;
+        Ok(Some((coupon.campaign, coupon.coupon_index, coupon.owner, coupon.period)))
+    } else if data.starts_with(RedemptionReceipt::DISCRIMINATOR) {
+        let receipt = RedemptionReceipt::try_deserialize(&mut &data[..])?;
+        Ok(Some((receipt.campaign, receipt.coupon_index, receipt.user, receipt.period)))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Number of coupons a user already redeemed at a merchant, read from its
+/// `CustomerHistory` PDA. An account that was never initialized counts as 0.
+///
+/// The caller is responsible for checking the PDA address (seeds constraint).
+pub fn prior_redemptions(history: &AccountInfo) -> Result<u32> {
+    if history.data_is_empty() {
+        return Ok(0);
+    }
+    require_keys_eq!(*history.owner, crate::ID, PromoError::InvalidCustomerHistory);
+
+    let data = history.try_borrow_data()?;
+    let history = CustomerHistory::try_deserialize(&mut &data[..])?;
+    Ok(history.redemption_count)
+}
+
+/// Validate a secondary-market purchase of a listed coupon and return its price.
+///
+/// - Coupon must belong to `campaign_key`, be listed and owned by `seller`.
+/// - The campaign must be transferable (not soulbound).
+/// - Buyer cannot be the seller, and must be the reserved buyer of a private listing.
+/// - Price must be > 0, <= `max_discount_lamports` and
+///   <= `max_discount_lamports * resale_bps / 10_000`.
+pub fn validate_listing_purchase(
+    campaign_key: &Pubkey,
+    campaign: &Campaign,
+    coupon: &Coupon,
+    seller: &Pubkey,
+    buyer: &Pubkey,
+) -> Result<u64> {
+    // Coupon must belong to this campaign (safety)
+    require_keys_eq!(coupon.campaign, *campaign_key, PromoError::InvalidCouponCampaign);
+
+    // Must be listed
+    require!(coupon.listed, PromoError::CouponNotListed);
+
+    // Soulbound campaigns never settle a sale, frozen ones not for now
+    require!(campaign.transferable, PromoError::CouponNotTransferable);
+    require!(!campaign.frozen, PromoError::CampaignFrozen);
+
+    // Coupons of a previous period can't be redeemed any more, so never sell one
+    require!(coupon.period == campaign.period, PromoError::CouponFromPreviousPeriod);
+
+    // Listings can't be created while held, but never sell a held coupon
+    require!(
+        !coupon.is_held(&Clock::get()?),
+        PromoError::CouponOnHold
+    );
+
+    // Seller must be current owner
+    require_keys_eq!(coupon.owner, *seller, PromoError::NotCouponOwner);
+
+    // Cannot buy your own coupon
+    require!(buyer != seller, PromoError::InvalidBuyer);
+
+    // Private listing: only the reserved buyer can execute it
+    if coupon.reserved_buyer != Pubkey::default() {
+        require_keys_eq!(*buyer, coupon.reserved_buyer, PromoError::BuyerNotReserved);
+    }
+
+    // Validate sale price is within allowed bounds
+    let sale_price = coupon.sale_price_lamports;
+    require_resale_price_allowed(campaign, sale_price)?;
+
+    Ok(sale_price)
+}
+
+/// Reject a secondary-market price (listing or swap) of 0, above
+/// `max_discount_lamports` or above `max_discount_lamports * resale_bps / 10_000`.
+pub fn require_resale_price_allowed(campaign: &Campaign, price: u64) -> Result<()> {
+    require!(price > 0, PromoError::InvalidResalePrice);
+
+    require!(
+        price <= campaign.max_discount_lamports,
+        PromoError::InvalidResalePrice
+    );
+
+    let max_allowed = apply_bps(
+        campaign.max_discount_lamports,
+        campaign.resale_bps as u64,
+        campaign.rounding_mode,
+    )?;
+    require!(price <= max_allowed, PromoError::InvalidResalePrice);
+
+    Ok(())
+}
+
+/// Reject a new ownership change (or an offer of one) while the coupon is
+/// frozen by the merchant, once it reached the campaign's `max_transfers`,
+/// or while its last ownership change is more recent than
+/// `transfer_cooldown_secs`.
+pub fn require_transfer_allowed(campaign: &Campaign, coupon: &Coupon, now: i64) -> Result<()> {
+    require!(!coupon.frozen, PromoError::CouponFrozen);
+    require!(
+        campaign.max_transfers == 0 || coupon.transfer_count < campaign.max_transfers,
+        PromoError::TransferLimitReached
+    );
+    if campaign.transfer_cooldown_secs > 0 && coupon.last_transfer_ts > 0 {
+        let elapsed = now.saturating_sub(coupon.last_transfer_ts);
+        require!(
+            elapsed >= campaign.transfer_cooldown_secs as i64,
+            PromoError::TransferCooldownActive
+        );
+    }
+    Ok(())
+}
+
+/// Count an ownership change of `coupon` (transfer, sale or swap) against
+/// the campaign's transfer limits. Any pending `approve_transfer` lapses.
+pub fn record_coupon_transfer(campaign: &Campaign, coupon: &mut Coupon) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    require_transfer_allowed(campaign, coupon, now)?;
+    coupon.transfer_count = coupon
+        .transfer_count
+        .checked_add(1)
+        .ok_or(PromoError::Overflow)?;
+    coupon.last_transfer_ts = now;
+    coupon.pending_owner = Pubkey::default();
+    Ok(())
+}
+
+/// Lamports to withhold from a secondary sale of a coupon of `campaign`.
+///
+/// - Campaigns without a jurisdiction are never withheld.
+/// - Otherwise the matching `rule` must be provided, and `rate_bps` applies to
+///   the full proceeds of sales priced above `threshold_lamports`.
+/// - When something is withheld, `compliance` must be the rule's compliance account.
+pub fn withholding_amount(
+    campaign: &Campaign,
+    rule: Option<&WithholdingRule>,
+    compliance: Option<&Pubkey>,
+    proceeds: u64,
+) -> Result<u64> {
+    if campaign.jurisdiction_code == 0 {
+        return Ok(0);
+    }
+
+    let rule = rule.ok_or(PromoError::WithholdingRuleRequired)?;
+    require!(
+        rule.jurisdiction_code == campaign.jurisdiction_code,
+        PromoError::InvalidWithholdingRule
+    );
+
+    if proceeds <= rule.threshold_lamports {
+        return Ok(0);
+    }
+
+    let withheld = apply_bps(proceeds, rule.rate_bps as u64, campaign.rounding_mode)?;
+
+    if withheld > 0 {
+        let compliance = compliance.ok_or(PromoError::WithholdingRuleRequired)?;
+        require_keys_eq!(
+            *compliance,
+            rule.compliance_account,
+            PromoError::InvalidWithholdingRule
+        );
+    }
+
+    Ok(withheld)
+}
+
+/// Protocol fee on a secondary sale of `proceeds` lamports, paid out of the
+/// seller proceeds (none during a marketplace fee holiday).
+pub fn marketplace_fee(config: &GlobalConfig, proceeds: u64) -> Result<u64> {
+    if config.marketplace_fee_disabled {
+        return Ok(0);
+    }
+    apply_bps(proceeds, config.marketplace_fee_bps as u64, config.rounding_mode)
+}
+
+/// SPL Governance program (Realms), the only governance accepted as config admin.
+pub const SPL_GOVERNANCE_PROGRAM_ID: Pubkey =
+    pubkey!("GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw");
+
+/// Metaplex Token Metadata program.
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Verify that `holder` owns an NFT that is a verified member of `collection`.
+///
+/// `token_account` must hold the NFT; `metadata` must be its Metaplex
+/// metadata PDA (`["metadata", program, mint]`). Only the verified
+/// `collection` field is trusted, never name/symbol/creators.
+pub fn verify_collection_nft(
+    token_account: &TokenAccount,
+    metadata: &AccountInfo,
+    holder: &Pubkey,
+    collection: &Pubkey,
+) -> Result<()> {
+    require_keys_eq!(token_account.owner, *holder, PromoError::NotCollectionMember);
+    require!(token_account.amount >= 1, PromoError::NotCollectionMember);
+
+    require_keys_eq!(
+        *metadata.owner,
+        TOKEN_METADATA_PROGRAM_ID,
+        PromoError::InvalidCollectionMetadata
+    );
+    let (expected, _) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            token_account.mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+    require_keys_eq!(metadata.key(), expected, PromoError::InvalidCollectionMetadata);
+
+    let data = metadata.try_borrow_data()?;
+    let (verified, key) =
+        metadata_collection(&data).ok_or(PromoError::InvalidCollectionMetadata)?;
+    require!(
+        verified && key == *collection,
+        PromoError::NotCollectionMember
+    );
+
+    Ok(())
+}
+
+// Minimal borsh readers for Metaplex layouts (no mpl dependency).
+fn take<'a>(data: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if data.len() < len {
+        return None;
+    }
+    let (head, tail) = data.split_at(len);
+    *data = tail;
+    Some(head)
+}
+
+fn take_u32(data: &mut &[u8]) -> Option<usize> {
+    let bytes = take(data, 4)?;
+    Some(u32::from_le_bytes(bytes.try_into().ok()?) as usize)
+}
+
+fn skip_option(data: &mut &[u8], len: usize) -> Option<()> {
+    if take(data, 1)?[0] != 0 {
+        take(data, len)?;
+    }
+    Some(())
+}
+
+/// Read the `collection` field (`(verified, key)`) of a Metaplex `MetadataV1`
+/// account. Returns `None` if the layout can't be parsed or no collection is set.
+fn metadata_collection(data: &[u8]) -> Option<(bool, Pubkey)> {
+    const KEY_METADATA_V1: u8 = 4;
+
+    let mut data = data;
+    if take(&mut data, 1)?[0] != KEY_METADATA_V1 {
+        return None;
+    }
+    // update_authority + mint
+    take(&mut data, 32 + 32)?;
+    // name, symbol, uri
+    for _ in 0..3 {
+        let len = take_u32(&mut data)?;
+        take(&mut data, len)?;
+    }
+    // seller_fee_basis_points
+    take(&mut data, 2)?;
+    // creators: Option<Vec<Creator { address, verified, share }>>
+    if take(&mut data, 1)?[0] != 0 {
+        let count = take_u32(&mut data)?;
+        take(&mut data, count.checked_mul(32 + 1 + 1)?)?;
+    }
+    // primary_sale_happened + is_mutable
+    take(&mut data, 2)?;
+    // edition_nonce, token_standard
+    skip_option(&mut data, 1)?;
+    skip_option(&mut data, 1)?;
+    // collection: Option<Collection { verified, key }>
+    if take(&mut data, 1)?[0] == 0 {
+        return None;
+    }
+    let verified = take(&mut data, 1)?[0] != 0;
+    let key = Pubkey::try_from(take(&mut data, 32)?).ok()?;
+    Some((verified, key))
+}
+
+/// Hash of `slot` in the `SlotHashes` sysvar, while it is still retained
+/// (the last 512 slots). Read in place: the sysvar is too large to
+/// deserialize on-chain.
+pub fn slot_hash(slot_hashes: &AccountInfo, slot: u64) -> Result<Option<[u8; 32]>> {
+    let data = slot_hashes.try_borrow_data()?;
+    Ok(find_slot_hash(&data, slot))
+}
+
+fn find_slot_hash(mut data: &[u8], slot: u64) -> Option<[u8; 32]> {
+    let len = u64::from_le_bytes(take(&mut data, 8)?.try_into().ok()?);
+    for _ in 0..len {
+        let entry_slot = u64::from_le_bytes(take(&mut data, 8)?.try_into().ok()?);
+        let hash = take(&mut data, 32)?;
+        if entry_slot == slot {
+            return hash.try_into().ok();
+        }
+        // Entries are newest first: past `slot`, it is no longer retained
+        if entry_slot < slot {
+            return None;
+        }
+    }
+    None
+}
+
+/// Verify that an Ed25519 program instruction earlier in this transaction
+/// checked a signature by `signer` over exactly `message`.
+///
+/// Only single-signature instructions whose key / message live in their own
+/// data are accepted, so offsets can't point into another instruction.
+pub fn verify_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<bool> {
+    const HEADER_LEN: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+    const THIS_INSTRUCTION: u16 = u16::MAX;
+
+    fn read_u16(data: &[u8], at: usize) -> u16 {
+        u16::from_le_bytes([data[at], data[at + 1]])
+    }
+
+    let current = load_current_index_checked(instructions_sysvar)? as usize;
+    for index in 0..current {
+        let ix = load_instruction_at_checked(index, instructions_sysvar)?;
+        let data = &ix.data;
+        if ix.program_id != ed25519_program::ID
+            || data.len() < HEADER_LEN + OFFSETS_LEN
+            || data[0] != 1
+        {
+            continue;
+        }
+
+        let offsets = &data[HEADER_LEN..HEADER_LEN + OFFSETS_LEN];
+        let signature_ix = read_u16(offsets, 2);
+        let pubkey_offset = read_u16(offsets, 4) as usize;
+        let pubkey_ix = read_u16(offsets, 6);
+        let message_offset = read_u16(offsets, 8) as usize;
+        let message_len = read_u16(offsets, 10) as usize;
+        let message_ix = read_u16(offsets, 12);
+        if signature_ix != THIS_INSTRUCTION
+            || pubkey_ix != THIS_INSTRUCTION
+            || message_ix != THIS_INSTRUCTION
+        {
+            continue;
+        }
+
+        let signed_key = data.get(pubkey_offset..pubkey_offset + 32);
+        let signed_message = data.get(message_offset..message_offset + message_len);
+        if signed_key == Some(signer.as_ref()) && signed_message == Some(message) {
+            return Ok(true);
+        }
+    }
+
+    Ok(false)
+}
+
+/// Reject a recipient whose blocklist PDA (`["blocked", campaign, wallet]`)
+/// exists. The caller is responsible for checking the PDA address.
+pub fn require_not_blocked(blocked_wallet: &AccountInfo) -> Result<()> {
+    require!(
+        blocked_wallet.data_is_empty() || *blocked_wallet.owner != crate::ID,
+        PromoError::WalletBlocked
+    );
+    Ok(())
+}
+
+/// Metaplex Bubblegum program (compressed NFTs).
+pub const BUBBLEGUM_PROGRAM_ID: Pubkey = pubkey!("BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY");
+
+/// SPL Account Compression program (concurrent Merkle trees).
+pub const ACCOUNT_COMPRESSION_PROGRAM_ID: Pubkey =
+    pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+/// A Bubblegum compressed NFT leaf, as submitted by its owner.
+pub struct CnftLeaf<'a> {
+    /// Tree root the proof was built against (must still be in the tree's changelog).
+    pub root: [u8; 32],
+    /// Leaf nonce, which is also its index in the tree.
+    pub nonce: u64,
+    pub delegate: Pubkey,
+    pub creator_hash: [u8; 32],
+    /// Borsh-serialized Bubblegum `MetadataArgs` of the asset.
+    pub metadata_args: &'a [u8],
+}
+
+/// Verify that `owner` holds a compressed NFT of the verified `collection`,
+/// and return its asset id.
+///
+/// - `merkle_tree` must be an Account Compression tree whose authority is
+///   the Bubblegum tree config, so leaves (and their collection flag) were
+///   written by Bubblegum.
+/// - The leaf hash is rebuilt from `leaf` and `owner`, and checked with a CPI
+///   to `verify_leaf`; `proof_nodes` are the proof path, in order.
+pub fn verify_cnft_collection<'info>(
+    compression_program: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    proof_nodes: &[AccountInfo<'info>],
+    leaf: &CnftLeaf,
+    owner: &Pubkey,
+    collection: &Pubkey,
+) -> Result<Pubkey> {
+    // ConcurrentMerkleTreeHeader: account_type, version, max_buffer_size, max_depth, authority
+    const TREE_AUTHORITY_OFFSET: usize = 1 + 1 + 4 + 4;
+    const LEAF_SCHEMA_V1: u8 = 1;
+    const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+    require_keys_eq!(
+        compression_program.key(),
+        ACCOUNT_COMPRESSION_PROGRAM_ID,
+        PromoError::InvalidCnftProof
+    );
+    require_keys_eq!(
+        *merkle_tree.owner,
+        ACCOUNT_COMPRESSION_PROGRAM_ID,
+        PromoError::InvalidCnftProof
+    );
+    let (tree_config, _) =
+        Pubkey::find_program_address(&[merkle_tree.key.as_ref()], &BUBBLEGUM_PROGRAM_ID);
+    {
+        let data = merkle_tree.try_borrow_data()?;
+        let authority = data
+            .get(TREE_AUTHORITY_OFFSET..TREE_AUTHORITY_OFFSET + 32)
+            .ok_or(PromoError::InvalidCnftProof)?;
+        require!(authority == tree_config.as_ref(), PromoError::InvalidCnftProof);
+    }
+
+    let (seller_fee_basis_points, asset_collection) =
+        metadata_args_collection(leaf.metadata_args).ok_or(PromoError::InvalidCnftProof)?;
+    require!(
+        asset_collection == Some((true, *collection)),
+        PromoError::NotCollectionMember
+    );
+
+    let (asset_id, _) = Pubkey::find_program_address(
+        &[b"asset", merkle_tree.key.as_ref(), &leaf.nonce.to_le_bytes()],
+        &BUBBLEGUM_PROGRAM_ID,
+    );
+    let metadata_args_hash = keccak::hashv(&[leaf.metadata_args]).to_bytes();
+    let data_hash =
+        keccak::hashv(&[&metadata_args_hash, &seller_fee_basis_points.to_le_bytes()]).to_bytes();
+    let leaf_hash = keccak::hashv(&[
+        &[LEAF_SCHEMA_V1],
+        asset_id.as_ref(),
+        owner.as_ref(),
+        leaf.delegate.as_ref(),
+        &leaf.nonce.to_le_bytes(),
+        &data_hash,
+        &leaf.creator_hash,
+    ])
+    .to_bytes();
+
+    let index = u32::try_from(leaf.nonce).map_err(|_| PromoError::InvalidCnftProof)?;
+    let mut data = VERIFY_LEAF_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(&leaf.root);
+    data.extend_from_slice(&leaf_hash);
+    data.extend_from_slice(&index.to_le_bytes());
+
+    let mut accounts = vec![AccountMeta::new_readonly(merkle_tree.key(), false)];
+    accounts.extend(
+        proof_nodes
+            .iter()
+            .map(|node| AccountMeta::new_readonly(node.key(), false)),
+    );
+    let mut infos = vec![merkle_tree.clone()];
+    infos.extend(proof_nodes.iter().cloned());
+
+    invoke(
+        &Instruction {
+            program_id: ACCOUNT_COMPRESSION_PROGRAM_ID,
+            accounts,
+            data,
+        },
+        &infos,
+    )
+    .map_err(|_| PromoError::InvalidCnftProof)?;
+
+    Ok(asset_id)
+}
+
+/// Read `seller_fee_basis_points` and the `collection` field of a Bubblegum
+/// `MetadataArgs`. Returns `None` if the layout can't be parsed.
+fn metadata_args_collection(data: &[u8]) -> Option<(u16, Option<(bool, Pubkey)>)> {
+    let mut data = data;
+    // name, symbol, uri
+    for _ in 0..3 {
+        let len = take_u32(&mut data)?;
+        take(&mut data, len)?;
+    }
+    let seller_fee_basis_points = u16::from_le_bytes(take(&mut data, 2)?.try_into().ok()?);
+    // primary_sale_happened + is_mutable
+    take(&mut data, 2)?;
+    // edition_nonce, token_standard
+    skip_option(&mut data, 1)?;
+    skip_option(&mut data, 1)?;
+    // collection: Option<Collection { verified, key }>
+    let collection = if take(&mut data, 1)?[0] != 0 {
+        let verified = take(&mut data, 1)?[0] != 0;
+        Some((verified, Pubkey::try_from(take(&mut data, 32)?).ok()?))
+    } else {
+        None
+    };
+    Some((seller_fee_basis_points, collection))
+}
+
+/// Solana Name Service program.
+pub const NAME_SERVICE_PROGRAM_ID: Pubkey = pubkey!("namesLPneVptA9Z5rqUDD9tMTWEJwofgaYwp8cawRkX");
+
+/// Verify that the SNS name registry account `domain` is owned by `owner`
+/// and is a child of `parent`.
+///
+/// Registry header layout: `parent_name (32) | owner (32) | class (32)`.
+pub fn verify_sns_domain(domain: &AccountInfo, owner: &Pubkey, parent: &Pubkey) -> Result<()> {
+    require_keys_eq!(
+        *domain.owner,
+        NAME_SERVICE_PROGRAM_ID,
+        PromoError::SnsDomainRequired
+    );
+
+    let data = domain.try_borrow_data()?;
+    require!(data.len() >= 96, PromoError::SnsDomainRequired);
+    require!(
+        &data[..32] == parent.as_ref() && &data[32..64] == owner.as_ref(),
+        PromoError::SnsDomainRequired
+    );
+
+    Ok(())
+}
+
+/// CPI into `program`'s `check_eligibility(wallet, campaign)`; any error from
+/// the hook aborts the issuance.
+///
+/// Only `state` is passed, read-only and unsigned, so the hook can't act on
+/// behalf of the recipient or the campaign.
+pub fn check_eligibility_hook<'info>(
+    program: &AccountInfo<'info>,
+    state: Option<&AccountInfo<'info>>,
+    wallet: &Pubkey,
+    campaign: &Pubkey,
+) -> Result<()> {
+    const CHECK_ELIGIBILITY_DISCRIMINATOR: [u8; 8] = [36, 15, 52, 116, 158, 218, 253, 125];
+
+    let mut data = CHECK_ELIGIBILITY_DISCRIMINATOR.to_vec();
+    data.extend_from_slice(wallet.as_ref());
+    data.extend_from_slice(campaign.as_ref());
+
+    let accounts = state
+        .iter()
+        .map(|state| AccountMeta::new_readonly(state.key(), false))
+        .collect();
+    let mut infos = vec![program.clone()];
+    infos.extend(state.cloned());
+
+    invoke(
+        &Instruction {
+            program_id: program.key(),
+            accounts,
+            data,
+        },
+        &infos,
+    )?;
+
+    Ok(())
+}
+
+/// Enforce `campaign`'s KYC / age requirement for `wallet`, if any.
+///
+/// The attestation must be owned by `kyc_attestation_program`, live at
+/// `["attestation", credential, schema, wallet]` (so issuer, schema and
+/// subject are bound by its address) and not be expired. Layout (Solana
+/// Attestation Service): `discriminator (1) | nonce | credential | schema |
+/// data (vec) | signer | expiry (i64, 0 = never)`.
+pub fn verify_kyc_attestation(
+    campaign: &Campaign,
+    attestation: Option<&AccountInfo>,
+    wallet: &Pubkey,
+    now: i64,
+) -> Result<()> {
+    if campaign.kyc_attestation_program == Pubkey::default() {
+        return Ok(());
+    }
+    let attestation = attestation.ok_or(PromoError::KycAttestationRequired)?;
+    require_keys_eq!(
+        *attestation.owner,
+        campaign.kyc_attestation_program,
+        PromoError::KycAttestationRequired
+    );
+    let (expected, _) = Pubkey::find_program_address(
+        &[
+            b"attestation",
+            campaign.kyc_credential.as_ref(),
+            campaign.kyc_schema.as_ref(),
+            wallet.as_ref(),
+        ],
+        &campaign.kyc_attestation_program,
+    );
+    require_keys_eq!(attestation.key(), expected, PromoError::KycAttestationRequired);
+
+    let data = attestation.try_borrow_data()?;
+    let expiry = attestation_expiry(&data).ok_or(PromoError::KycAttestationRequired)?;
+    require!(expiry == 0 || now <= expiry, PromoError::KycAttestationRequired);
+
+    Ok(())
+}
+
+fn attestation_expiry(data: &[u8]) -> Option<i64> {
+    let mut data = data;
+    // discriminator + nonce + credential + schema
+    take(&mut data, 1 + 32 * 3)?;
+    let len = take_u32(&mut data)?;
+    take(&mut data, len)?;
+    // signer
+    take(&mut data, 32)?;
+    Some(i64::from_le_bytes(take(&mut data, 8)?.try_into().ok()?))
+}
+
+/// Native stake program.
+pub const STAKE_PROGRAM_ID: Pubkey = pubkey!("Stake11111111111111111111111111111111111111");
+
+/// Verify that `stake_account` is an active delegation withdrawable by
+/// `owner`, of at least `min_lamports`, activated in an earlier epoch.
+///
+/// `StakeStateV2::Stake` layout (bincode): `tag (u32) | rent_exempt_reserve |
+/// staker | withdrawer | lockup (48) | voter | stake | activation_epoch |
+/// deactivation_epoch | ...`.
+pub fn verify_stake(
+    stake_account: &AccountInfo,
+    owner: &Pubkey,
+    min_lamports: u64,
+) -> Result<()> {
+    const STAKE_STATE_STAKE: u32 = 2;
+    const WITHDRAWER_OFFSET: usize = 4 + 8 + 32;
+    const DELEGATION_OFFSET: usize = WITHDRAWER_OFFSET + 32 + 48 + 32;
+
+    fn read_u64(data: &[u8], at: usize) -> u64 {
+        u64::from_le_bytes(data[at..at + 8].try_into().unwrap())
+    }
+
+    require_keys_eq!(
+        *stake_account.owner,
+        STAKE_PROGRAM_ID,
+        PromoError::StakeAccountRequired
+    );
+    let data = stake_account.try_borrow_data()?;
+    require!(
+        data.len() >= DELEGATION_OFFSET + 24
+            && u32::from_le_bytes(data[..4].try_into().unwrap()) == STAKE_STATE_STAKE
+            && &data[WITHDRAWER_OFFSET..WITHDRAWER_OFFSET + 32] == owner.as_ref(),
+        PromoError::StakeAccountRequired
+    );
+
+    let stake = read_u64(&data, DELEGATION_OFFSET);
+    let activation_epoch = read_u64(&data, DELEGATION_OFFSET + 8);
+    let deactivation_epoch = read_u64(&data, DELEGATION_OFFSET + 16);
+    let current_epoch = Clock::get()?.epoch;
+    require!(
+        deactivation_epoch == u64::MAX
+            && stake >= min_lamports
+            && activation_epoch < current_epoch,
+        PromoError::InsufficientStake
+    );
+
+    Ok(())
+}
+
+/// Proofs a coupon recipient may need to present, depending on the
+/// campaign's eligibility rules (see `check_recipient_eligibility`).
+pub struct EligibilityProofs<'a, 'info> {
+    pub blocked_wallet: &'a AccountInfo<'info>,
+    pub segment: Option<&'a Account<'info, Segment>>,
+    pub segment_proof: &'a [[u8; 32]],
+    pub category_receipts: &'a [AccountInfo<'info>],
+    pub achievement: Option<&'a Account<'info, AchievementProgress>>,
+    pub prerequisite_proof: Option<&'a AccountInfo<'info>>,
+    pub history: Option<&'a AccountInfo<'info>>,
+    pub nft_token_account: Option<&'a Account<'info, TokenAccount>>,
+    pub nft_metadata: Option<&'a AccountInfo<'info>>,
+    pub gate_token_account: Option<&'a Account<'info, TokenAccount>>,
+    pub instructions_sysvar: Option<&'a AccountInfo<'info>>,
+    pub sns_domain: Option<&'a AccountInfo<'info>>,
+    pub eligibility_program: Option<&'a AccountInfo<'info>>,
+    pub eligibility_state: Option<&'a AccountInfo<'info>>,
+    pub kyc_attestation: Option<&'a AccountInfo<'info>>,
+    pub whitelist_entry: Option<&'a Account<'info, WhitelistEntry>>,
+    pub stake_account: Option<&'a AccountInfo<'info>>,
+    pub wallet_registration: Option<&'a Account<'info, WalletRegistration>>,
+    /// Whether the caller already verified a cNFT of `required_cnft_collection`
+    /// (only `claim_coupon_with_cnft` can).
+    pub cnft_verified: bool,
+}
+
+/// Enforce every campaign eligibility rule for `recipient` of a new coupon.
+///
+/// Shared by `mint_coupon` (merchant-issued) and `claim_coupon` (self-claimed).
+pub fn check_recipient_eligibility(
+    campaign: &Account<Campaign>,
+    recipient: &Pubkey,
+    proofs: &EligibilityProofs,
+) -> Result<()> {
+    // Enforce targeting logic:
+    // - If requires_wallet == true, only the configured target_wallet can receive coupons.
+    if campaign.requires_wallet {
+        require_keys_eq!(
+            *recipient,
+            campaign.target_wallet,
+            PromoError::NotEligibleForCampaign
+        );
+        require!(
+            campaign.target_claim_deadline == 0
+                || Clock::get()?.unix_timestamp <= campaign.target_claim_deadline,
+            PromoError::ClaimDeadlinePassed
+        );
+    }
+
+    // Excluded wallets can never receive coupons from this campaign
+    require!(
+        !campaign.excluded_wallets.contains(recipient),
+        PromoError::WalletExcluded
+    );
+    require_not_blocked(proofs.blocked_wallet)?;
+
+    // Whitelisted campaigns: recipient has an entry, within its claim window.
+    if campaign.requires_whitelist {
+        let entry = proofs.whitelist_entry.ok_or(PromoError::NotWhitelisted)?;
+        require_keys_eq!(entry.campaign, campaign.key(), PromoError::NotWhitelisted);
+        require_keys_eq!(entry.wallet, *recipient, PromoError::NotWhitelisted);
+        require!(
+            entry.claim_deadline == 0 || Clock::get()?.unix_timestamp <= entry.claim_deadline,
+            PromoError::ClaimDeadlinePassed
+        );
+    }
+
+    // Enforce segment targeting: the recipient must be part of the referenced audience.
+    if campaign.segment != Pubkey::default() {
+        let segment = proofs.segment.ok_or(PromoError::SegmentRequired)?;
+        require_keys_eq!(segment.key(), campaign.segment, PromoError::InvalidSegment);
+        require!(
+            segment.contains(recipient, proofs.segment_proof),
+            PromoError::NotEligibleForCampaign
+        );
+    }
+
+    // Enforce behavioral targeting: enough prior redemptions in the required category.
+    if campaign.min_category_redemptions > 0 {
+        let redemptions = count_category_receipts(
+            proofs.category_receipts,
+            recipient,
+            campaign.required_category_code,
+        )?;
+        require!(
+            redemptions >= campaign.min_category_redemptions as u32,
+            PromoError::NotEligibleForCampaign
+        );
+    }
+
+    // Exclusive campaigns: recipient unlocked the required achievement.
+    if campaign.required_achievement != Pubkey::default() {
+        let progress = proofs.achievement.ok_or(PromoError::AchievementRequired)?;
+        require_keys_eq!(
+            progress.achievement,
+            campaign.required_achievement,
+            PromoError::AchievementRequired
+        );
+        require_keys_eq!(progress.user, *recipient, PromoError::AchievementRequired);
+        require!(progress.is_unlocked(), PromoError::AchievementRequired);
+    }
+
+    // Enforce sequel retargeting: recipient took part in the prerequisite campaign.
+    if campaign.prerequisite_campaign != Pubkey::default() {
+        let proof = proofs
+            .prerequisite_proof
+            .ok_or(PromoError::PrerequisiteProofRequired)?;
+        verify_campaign_participation(proof, &campaign.prerequisite_campaign, recipient)?;
+    }
+
+    // Enforce new-customer acquisition: no prior redemption with this merchant.
+    if campaign.first_purchase_only {
+        let history = proofs.history.ok_or(PromoError::FirstPurchaseOnly)?;
+        require!(
+            prior_redemptions(history)? == 0,
+            PromoError::FirstPurchaseOnly
+        );
+    }
+
+    // Loyalty tier: enough prior redemptions with this merchant.
+    if campaign.min_prior_redemptions > 0 {
+        let history = proofs.history.ok_or(PromoError::LoyaltyTierNotReached)?;
+        require!(
+            prior_redemptions(history)? >= campaign.min_prior_redemptions,
+            PromoError::LoyaltyTierNotReached
+        );
+    }
+
+    // Token-gated campaigns: recipient holds an NFT of the required collection.
+    if campaign.required_collection != Pubkey::default() {
+        let token_account = proofs
+            .nft_token_account
+            .ok_or(PromoError::CollectionNftRequired)?;
+        let metadata = proofs.nft_metadata.ok_or(PromoError::CollectionNftRequired)?;
+        verify_collection_nft(
+            token_account,
+            metadata,
+            recipient,
+            &campaign.required_collection,
+        )?;
+    }
+
+    // Token-gated campaigns: recipient holds enough of the gate mint.
+    if campaign.gate_mint != Pubkey::default() {
+        let token_account = proofs
+            .gate_token_account
+            .ok_or(PromoError::GateTokenAccountRequired)?;
+        require_keys_eq!(
+            token_account.mint,
+            campaign.gate_mint,
+            PromoError::GateTokenAccountRequired
+        );
+        require_keys_eq!(
+            token_account.owner,
+            *recipient,
+            PromoError::GateTokenAccountRequired
+        );
+        require!(
+            token_account.amount >= campaign.gate_min_amount,
+            PromoError::InsufficientGateBalance
+        );
+    }
+
+    // Compressed-NFT-gated campaigns: verified by `claim_coupon_with_cnft`.
+    if campaign.required_cnft_collection != Pubkey::default() {
+        require!(proofs.cnft_verified, PromoError::CnftClaimOnly);
+    }
+
+    // Community campaigns: recipient owns a domain under the parent SNS domain.
+    if campaign.sns_parent_domain != Pubkey::default() {
+        let domain = proofs.sns_domain.ok_or(PromoError::SnsDomainRequired)?;
+        verify_sns_domain(domain, recipient, &campaign.sns_parent_domain)?;
+    }
+
+    // Regulated verticals: valid age / KYC attestation.
+    verify_kyc_attestation(
+        campaign,
+        proofs.kyc_attestation,
+        recipient,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // Sybil gate: a real stake delegation (each stake qualifies one wallet,
+    // see `record_stake_claim`) or a wallet registered long enough ago.
+    if campaign.sybil_min_stake_lamports > 0 || campaign.sybil_min_wallet_age_epochs > 0 {
+        match (proofs.stake_account, proofs.wallet_registration) {
+            (Some(stake_account), _) if campaign.sybil_min_stake_lamports > 0 => {
+                verify_stake(stake_account, recipient, campaign.sybil_min_stake_lamports)?;
+            }
+            (_, Some(registration)) if campaign.sybil_min_wallet_age_epochs > 0 => {
+                require_keys_eq!(
+                    registration.wallet,
+                    *recipient,
+                    PromoError::StakeAccountRequired
+                );
+                let age = Clock::get()?
+                    .epoch
+                    .saturating_sub(registration.registered_epoch);
+                require!(
+                    age >= campaign.sybil_min_wallet_age_epochs,
+                    PromoError::WalletRegistrationTooRecent
+                );
+            }
+            _ => return err!(PromoError::StakeAccountRequired),
+        }
+    }
+
+    // Third-party eligibility hook: its check_eligibility must succeed.
+    if campaign.eligibility_program != Pubkey::default() {
+        let program = proofs
+            .eligibility_program
+            .ok_or(PromoError::EligibilityProgramRequired)?;
+        require_keys_eq!(
+            program.key(),
+            campaign.eligibility_program,
+            PromoError::EligibilityProgramRequired
+        );
+        check_eligibility_hook(program, proofs.eligibility_state, recipient, &campaign.key())?;
+    }
+
+    // Region targeting: the attestor signed (recipient, region) in this transaction.
+    if campaign.region_attestor != Pubkey::default() {
+        let instructions_sysvar = proofs
+            .instructions_sysvar
+            .ok_or(PromoError::RegionAttestationRequired)?;
+        let mut message = recipient.to_bytes().to_vec();
+        message.extend_from_slice(&campaign.region_code.to_le_bytes());
+        require!(
+            verify_ed25519_signature(instructions_sysvar, &campaign.region_attestor, &message)?,
+            PromoError::RegionAttestationRequired
+        );
+    }
+
+    Ok(())
+}
+
+/// Count a new coupon for `wallet` against `campaign.max_coupons_per_wallet`.
+///
+/// No-op while the campaign has no per-wallet limit. The caller is responsible
+/// for checking the counter PDA address (seeds constraint).
+pub fn record_wallet_coupon(
+    campaign: &Account<Campaign>,
+    count: Option<&mut Account<WalletCouponCount>>,
+    wallet: &Pubkey,
+) -> Result<()> {
+    if campaign.max_coupons_per_wallet == 0 {
+        return Ok(());
+    }
+    let count = count.ok_or(PromoError::WalletCouponCountRequired)?;
+    require!(
+        count.received < campaign.max_coupons_per_wallet,
+        PromoError::WalletCouponLimitReached
+    );
+
+    count.campaign = campaign.key();
+    count.wallet = *wallet;
+    count.received += 1;
+
+    Ok(())
+}
+
+/// Count a new coupon for `wallet` in the campaign category against
+/// `GlobalConfig.category_claims_per_epoch`.
+///
+/// No-op while the admin has not set a cap. The caller is responsible for
+/// checking the counter PDA address (seeds constraint).
+pub fn record_category_claim(
+    config: &GlobalConfig,
+    campaign: &Campaign,
+    count: Option<&mut Account<CategoryClaimCount>>,
+    wallet: &Pubkey,
+) -> Result<()> {
+    if config.category_claims_per_epoch == 0 {
+        return Ok(());
+    }
+    let count = count.ok_or(PromoError::CategoryClaimCountRequired)?;
+
+    let epoch = Clock::get()?.epoch;
+    if count.epoch != epoch {
+        count.epoch = epoch;
+        count.claimed = 0;
+    }
+    require!(
+        count.claimed < config.category_claims_per_epoch,
+        PromoError::CategoryClaimCapReached
+    );
+
+    count.wallet = *wallet;
+    count.category_code = campaign.category_code;
+    count.claimed += 1;
+
+    Ok(())
+}
+
+/// Mark the stake account that passed the sybil gate as used in `campaign`
+/// (`[b"stake_claim", campaign, stake_account]`), so one stake, whose
+/// withdrawer can be re-assigned, qualifies a single wallet per campaign.
+///
+/// No-op when the gate has no stake path or was passed on wallet age.
+pub fn record_stake_claim<'info>(
+    campaign: &Account<Campaign>,
+    stake_account: Option<&AccountInfo<'info>>,
+    stake_claim: Option<&AccountInfo<'info>>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if campaign.sybil_min_stake_lamports == 0 {
+        return Ok(());
+    }
+    let Some(stake_account) = stake_account else {
+        return Ok(());
+    };
+    let stake_claim = stake_claim.ok_or(PromoError::StakeAccountRequired)?;
+
+    let campaign_key = campaign.key();
+    let (expected_key, bump) = Pubkey::find_program_address(
+        &[b"stake_claim", campaign_key.as_ref(), stake_account.key.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(stake_claim.key(), expected_key, PromoError::StakeAccountRequired);
+    require!(
+        *stake_claim.owner != crate::ID,
+        PromoError::StakeAlreadyClaimed
+    );
+
+    create_program_account(
+        payer,
+        stake_claim,
+        0,
+        &[
+            b"stake_claim",
+            campaign_key.as_ref(),
+            stake_account.key.as_ref(),
+            &[bump],
+        ],
+        system_program,
+    )
+}
+
+/// Self-service claims only take `campaign.next_coupon_index`: an index
+/// below it may belong to a redeemed coupon whose receipt already exists (the
+/// new coupon could never be redeemed), and one above it would push the
+/// counter out of reach of the other issuers.
+pub fn require_next_coupon_index(campaign: &Campaign, coupon_index: u64) -> Result<()> {
+    require!(
+        coupon_index == campaign.next_coupon_index,
+        PromoError::CouponIndexNotNext
+    );
+    Ok(())
+}
+
+/// Count a self-service claim against `campaign.claims_per_slot_limit`.
+pub fn throttle_claim(campaign: &mut Campaign) -> Result<()> {
+    if campaign.claims_per_slot_limit == 0 {
+        return Ok(());
+    }
+
+    let slot = Clock::get()?.slot;
+    if campaign.claim_slot != slot {
+        campaign.claim_slot = slot;
+        campaign.claims_in_slot = 0;
+    }
+    require!(
+        campaign.claims_in_slot < campaign.claims_per_slot_limit,
+        PromoError::ClaimRateLimited
+    );
+    campaign.claims_in_slot += 1;
+
+    Ok(())
+}
+
+/// Create coupon `coupon_index` of `campaign` for `owner`, paying the mint
+/// cost from the vault to the platform treasury (unless the mint fee is
+/// disabled) and updating the campaign / vault counters.
+///
+/// Shared by `mint_coupon` and `claim_coupon`; eligibility must be checked
+/// by the caller.
+pub fn issue_coupon<'info>(
+    campaign: &mut Account<'info, Campaign>,
+    vault: &mut Account<'info, Vault>,
+    coupon: &mut Coupon,
+    coupon_index: u64,
+    owner: Pubkey,
+    platform_treasury: &AccountInfo<'info>,
+    config: &GlobalConfig,
+) -> Result<()> {
+    // Ensure we do not exceed the total number of coupons configured for this campaign
+    require!(
+        campaign.minted_coupons < campaign.total_coupons,
+        PromoError::NoCouponsLeft
+    );
+
+    // Draft campaigns don't issue coupons yet, cancelled / frozen ones anymore
+    require!(!campaign.draft, PromoError::CampaignDraft);
+    require!(!campaign.frozen, PromoError::CampaignFrozen);
+    require!(campaign.cancelled_at == 0, PromoError::CampaignCancelled);
+
+    // No coupons after expiration, nor once an "until funds last" vault ran dry
+    let now = Clock::get()?.unix_timestamp;
+    require!(!campaign.is_expired(now), PromoError::CampaignExpired);
+
+    let mint_cost = campaign.mint_cost_lamports;
+    require!(mint_cost > 0, PromoError::InvalidMintCost);
+
+    // Mint fee is only collected while the admin has not declared a fee holiday
+    if !config.mint_fee_disabled {
+        // Check if vault has enough lamports for mint cost (real SOL check)
+        let vault_lamports = **vault.to_account_info().lamports.borrow();
+        require!(
+            vault_lamports >= mint_cost,
+            PromoError::InsufficientVaultBalance
+        );
+
+        // Transfer real lamports from vault PDA to platform treasury.
+        transfer_lamports(&vault.to_account_info(), platform_treasury, mint_cost)?;
+
+        // Update vault analytics (logical mint spending)
+        vault.total_mint_spent = vault
+            .total_mint_spent
+            .checked_add(mint_cost)
+            .ok_or(PromoError::Overflow)?;
+    }
+
+    // Initialize coupon fields
+    coupon.campaign = campaign.key();
+    coupon.coupon_index = coupon_index;
+    coupon.owner = owner;
+    coupon.used = false;
+    coupon.listed = false;
+    coupon.sale_price_lamports = 0;
+    coupon.reserved_buyer = Pubkey::default();
+    coupon.held_until = 0;
+    coupon.not_redeemable_before = 0;
+    coupon.period = campaign.period;
+    coupon.metadata = CouponMetadata::default();
+    coupon.serial = [0u8; 32];
+    coupon.remaining_value = campaign.coupon_value_lamports;
+    coupon.reserved_by = Pubkey::default();
+    coupon.reserved_until_slot = 0;
+    coupon.transfer_count = 0;
+    coupon.last_transfer_ts = 0;
+    coupon.pending_owner = Pubkey::default();
+
+    // Update campaign minted count
+    campaign.minted_coupons = campaign
+        .minted_coupons
+        .checked_add(1)
+        .ok_or(PromoError::Overflow)?;
+
+    campaign.outstanding_coupons = campaign
+        .outstanding_coupons
+        .checked_add(1)
+        .ok_or(PromoError::Overflow)?;
+
+    // Keep the counter above every index in use, whichever path supplied it
+    campaign.next_coupon_index = campaign
+        .next_coupon_index
+        .max(coupon_index.checked_add(1).ok_or(PromoError::Overflow)?);
+
+    check_budget_exhaustion(campaign.key(), campaign, &vault.to_account_info(), now)?;
+    check_low_balance(campaign.key(), campaign, vault)?;
+
+    Ok(())
+}
+
+/// Checks shared by every redemption path (`redeem_coupon`,
+/// `redeem_coupons_stacked`, `redeem_partial`): a live, unfrozen campaign
+/// inside its schedule and accepting `product_code`, and a coupon of its
+/// current period owned by `user`, unused, unfrozen, unlisted, unlocked and
+/// not reserved by another checkout. Excluded wallets cannot redeem, even
+/// with a coupon bought or received P2P.
+///
+/// `first_purchase_only` is left to the caller (what counts as a prior
+/// purchase differs per path).
+pub fn validate_redemption(
+    campaign_key: &Pubkey,
+    campaign: &Campaign,
+    coupon: &Coupon,
+    user: &Pubkey,
+    product_code: u16,
+    terminal: Option<Pubkey>,
+    clock: &Clock,
+) -> Result<()> {
+    require!(!campaign.frozen, PromoError::CampaignFrozen);
+    require!(
+        !campaign.is_expired(clock.unix_timestamp),
+        PromoError::CampaignExpired
+    );
+    require!(
+        is_within_schedule(
+            clock.unix_timestamp,
+            campaign.redeem_days_mask,
+            campaign.redeem_hours_mask,
+            campaign.schedule_utc_offset_mins,
+        ),
+        PromoError::OutsideRedemptionSchedule
+    );
+    require!(
+        campaign.accepts_product(product_code),
+        PromoError::InvalidProductForCoupon
+    );
+    require!(
+        campaign.used_coupons < campaign.total_coupons,
+        PromoError::NoCouponsLeft
+    );
+
+    require_keys_eq!(coupon.campaign, *campaign_key, PromoError::InvalidCouponCampaign);
+    require_keys_eq!(coupon.owner, *user, PromoError::NotCouponOwner);
+    require!(!coupon.used, PromoError::CouponAlreadyUsed);
+    require!(!coupon.frozen, PromoError::CouponFrozen);
+    require!(coupon.period == campaign.period, PromoError::CouponFromPreviousPeriod);
+    require!(!coupon.listed, PromoError::CouponListed);
+    require!(
+        clock.unix_timestamp >= coupon.not_redeemable_before,
+        PromoError::GiftNotUnlocked
+    );
+    coupon.check_reservation(clock.slot, terminal)?;
+
+    require!(
+        !campaign.excluded_wallets.contains(user),
+        PromoError::WalletExcluded
+    );
+
+    Ok(())
+}
+
+/// Discount granted on `purchase_amount`: the discount in effect (phase plus
+/// the bonus of `streak`, or the fixed amount), capped by
+/// `max_discount_lamports`, by the budget taper and by what is left of the
+/// wallet's `max_discount_per_user` allowance.
+pub fn redemption_discount(
+    campaign: &Campaign,
+    vault: &AccountInfo,
+    user_stats: &UserStats,
+    purchase_amount: u64,
+    streak: u16,
+    now: i64,
+) -> Result<u64> {
+    let streak_bonus_bps = (campaign.streak_bonus_bps as u64)
+        .saturating_mul(streak.saturating_sub(1) as u64)
+        .min(campaign.max_streak_bonus_bps as u64);
+    let discount_bps = (campaign.discount_bps_at(now) as u64 + streak_bonus_bps).min(10_000);
+
+    let mut discount_value = match campaign.discount_kind {
+        DiscountKind::Percentage => apply_bps(purchase_amount, discount_bps, campaign.rounding_mode)?,
+        DiscountKind::FixedLamports(amount) => amount.min(purchase_amount),
+    };
+    discount_value = discount_value.min(campaign.max_discount_lamports);
+
+    // Budget taper: the cap shrinks with the remaining vault budget
+    if campaign.budget_taper_bps > 0 {
+        let taper_cap = apply_bps(
+            vault_spendable(vault)?,
+            campaign.budget_taper_bps as u64,
+            campaign.rounding_mode,
+        )?;
+        discount_value = discount_value.min(taper_cap);
+    }
+
+    // Cap the cumulative discount per wallet, so a single buyer of many
+    // secondary-market coupons can't capture the whole campaign budget
+    if campaign.max_discount_per_user > 0 {
+        let remaining = campaign
+            .max_discount_per_user
+            .saturating_sub(user_stats.total_discount_lamports);
+        require!(remaining > 0, PromoError::UserDiscountCapReached);
+        discount_value = discount_value.min(remaining);
+    }
+
+    // Never grant more than what is left of today's budget
+    if campaign.daily_budget_lamports > 0 {
+        let remaining = daily_budget_remaining(campaign, now);
+        require!(remaining > 0, PromoError::DailyBudgetExhausted);
+        discount_value = discount_value.min(remaining);
+    }
+
+    Ok(discount_value)
+}
+
+/// Discount still available under the campaign's daily budget on the
+/// merchant-local day of `now` (only meaningful if `daily_budget_lamports` is set).
+pub fn daily_budget_remaining(campaign: &Campaign, now: i64) -> u64 {
+    let today = local_day_index(now, campaign.schedule_utc_offset_mins);
+    let spent = if campaign.budget_day == today {
+        campaign.budget_day_spent
+    } else {
+        0
+    };
+    campaign.daily_budget_lamports.saturating_sub(spent)
+}
+
+/// Pay the service fee on `discount_value` (none during a fee holiday) from
+/// the vault to the platform treasury, and return it.
+pub fn charge_service_fee<'info>(
+    config: &GlobalConfig,
+    campaign: &Campaign,
+    vault: &mut Account<'info, Vault>,
+    platform_treasury: &AccountInfo<'info>,
+    discount_value: u64,
+) -> Result<u64> {
+    if config.redemption_fee_disabled {
+        return Ok(0);
+    }
+    let service_fee_value = apply_bps(
+        discount_value,
+        campaign.service_fee_bps as u64,
+        campaign.rounding_mode,
+    )?;
+    pay_service_fee(vault, platform_treasury, service_fee_value)?;
+
+    Ok(service_fee_value)
+}
+
+/// Pay `service_fee_value` from the vault to the platform treasury.
+pub fn pay_service_fee<'info>(
+    vault: &mut Account<'info, Vault>,
+    platform_treasury: &AccountInfo<'info>,
+    service_fee_value: u64,
+) -> Result<()> {
+    if service_fee_value == 0 {
+        return Ok(());
+    }
+
+    let vault_lamports = **vault.to_account_info().lamports.borrow();
+    require!(
+        vault_lamports >= service_fee_value,
+        PromoError::InsufficientVaultBalance
+    );
+    transfer_lamports(&vault.to_account_info(), platform_treasury, service_fee_value)?;
+
+    vault.total_service_spent = vault
+        .total_service_spent
+        .checked_add(service_fee_value)
+        .ok_or(PromoError::Overflow)?;
+
+    Ok(())
+}
+
+/// Mark `coupon` as used (rebating `gas_rebate_lamports` to `user` if the
+/// vault can still afford it) and count it; the caller burns it. Redeeming
+/// the last coupon completes the campaign.
+pub fn consume_coupon<'info>(
+    campaign_key: Pubkey,
+    campaign: &mut Campaign,
+    vault: &AccountInfo<'info>,
+    coupon: &mut Coupon,
+    user: &AccountInfo<'info>,
+    now: i64,
+) -> Result<()> {
+    // Vault-funded gas rebate, so small coupons are still worth redeeming
+    let gas_rebate = campaign.gas_rebate_lamports;
+    if gas_rebate > 0 && vault_spendable(vault)? >= gas_rebate {
+        transfer_lamports(vault, user, gas_rebate)?;
+    }
+
+    coupon.used = true;
+    coupon.listed = false;
+    coupon.sale_price_lamports = 0;
+    coupon.reserved_buyer = Pubkey::default();
+
+    campaign.used_coupons = campaign
+        .used_coupons
+        .checked_add(1)
+        .ok_or(PromoError::Overflow)?;
+    campaign.outstanding_coupons = campaign.outstanding_coupons.saturating_sub(1);
+
+    // Last coupon redeemed: the campaign is complete and can be closed early
+    if campaign.used_coupons == campaign.total_coupons {
+        campaign.completed_at = now;
+        emit!(CampaignCompleted {
+            campaign: campaign_key,
+            partner: campaign.partner,
+            partner_share_bps: campaign.partner_share_bps,
+            total_coupons: campaign.total_coupons,
+            completed_at: now,
+        });
+    }
+
+    Ok(())
+}
+
+/// Event emitted when the last coupon of a campaign is redeemed; the vault
+/// can be closed right away.
+#[event]
+pub struct CampaignCompleted {
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub total_coupons: u32,
+    pub completed_at: i64,
+}
+
+/// Account a redemption granting `discount_value` on `purchase_amount` in
+/// the campaign: daily budget pacing, daily redemption limit (both on
+/// merchant-local days) and analytics.
+pub fn record_campaign_redemption(
+    campaign_key: Pubkey,
+    campaign: &mut Campaign,
+    purchase_amount: u64,
+    discount_value: u64,
+    now: i64,
+) -> Result<()> {
+    let today = local_day_index(now, campaign.schedule_utc_offset_mins);
+
+    // Daily budget pacing: reset the window on a new day, then account the
+    // discount (already capped at the day's remaining budget by `redemption_discount`)
+    if campaign.daily_budget_lamports > 0 {
+        if campaign.budget_day != today {
+            campaign.budget_day = today;
+            campaign.budget_day_spent = 0;
+        }
+        require!(
+            campaign.budget_day_spent < campaign.daily_budget_lamports
+                && discount_value <= campaign.daily_budget_lamports - campaign.budget_day_spent,
+            PromoError::DailyBudgetExhausted
+        );
+
+        campaign.budget_day_spent = campaign
+            .budget_day_spent
+            .checked_add(discount_value)
+            .ok_or(PromoError::Overflow)?;
+
+        if campaign.budget_day_spent >= campaign.daily_budget_lamports {
+            emit!(DailyBudgetReached {
+                campaign: campaign_key,
+                partner: campaign.partner,
+                partner_share_bps: campaign.partner_share_bps,
+                day: today,
+                daily_budget_lamports: campaign.daily_budget_lamports,
+                spent_lamports: campaign.budget_day_spent,
+            });
+        }
+    }
+
+    // Daily redemption rate limit (same day window as the budget pacing)
+    if campaign.max_redemptions_per_day > 0 {
+        if campaign.redemption_day != today {
+            campaign.redemption_day = today;
+            campaign.redemptions_today = 0;
+        }
+        require!(
+            campaign.redemptions_today < campaign.max_redemptions_per_day,
+            PromoError::DailyRedemptionLimitReached
+        );
+        campaign.redemptions_today += 1;
+    }
+
+    campaign.total_purchase_amount = campaign
+        .total_purchase_amount
+        .checked_add(purchase_amount)
+        .ok_or(PromoError::Overflow)?;
+    campaign.total_discount_lamports = campaign
+        .total_discount_lamports
+        .checked_add(discount_value)
+        .ok_or(PromoError::Overflow)?;
+    campaign.last_redeem_timestamp = now;
+
+    Ok(())
+}
+
+/// Event emitted when a campaign's daily budget is used up; redemptions
+/// resume on the next merchant-local day.
+#[event]
+pub struct DailyBudgetReached {
+    pub campaign: Pubkey,
+    pub partner: Pubkey,
+    pub partner_share_bps: u16,
+    pub day: i64,
+    pub daily_budget_lamports: u64,
+    pub spent_lamports: u64,
+}
+
+/// Fill the receipt of a redeemed coupon (it survives the coupon burn) and
+/// chain it into the campaign's receipt-set hash.
+#[allow(clippy::too_many_arguments)]
+pub fn write_receipt(
+    receipt: &mut RedemptionReceipt,
+    receipt_key: &Pubkey,
+    campaign_key: Pubkey,
+    campaign: &mut Campaign,
+    coupon: &Coupon,
+    user: Pubkey,
+    product_code: u16,
+    purchase_amount: u64,
+    discount_value: u64,
+    now: i64,
+) {
+    receipt.user = user;
+    receipt.merchant = campaign.merchant;
+    receipt.campaign = campaign_key;
+    receipt.coupon_index = coupon.coupon_index;
+    receipt.category_code = campaign.category_code;
+    receipt.product_code = product_code;
+    receipt.purchase_amount = purchase_amount;
+    receipt.discount_value = discount_value;
+    receipt.redeemed_at = now;
+    receipt.sequence = campaign.used_coupons;
+    receipt.period = coupon.period;
+
+    campaign.receipts_hash = hashv(&[&campaign.receipts_hash, receipt_key.as_ref()]).to_bytes();
+}
+
+/// Count `redeemed` coupons of `user` in their `CustomerHistory` at
+/// `merchant` and move the streak to `streak` (see `next_streak`).
+pub fn record_customer_history(
+    history: &mut CustomerHistory,
+    merchant: Pubkey,
+    user: Pubkey,
+    redeemed: u32,
+    (streak, streak_period): (u16, i64),
+    now: i64,
+) -> Result<()> {
+    if history.redemption_count == 0 {
+        history.merchant = merchant;
+        history.user = user;
+        history.first_redeemed_at = now;
+    }
+    history.redemption_count = history
+        .redemption_count
+        .checked_add(redeemed)
+        .ok_or(PromoError::Overflow)?;
+    history.last_redeemed_at = now;
+    history.streak = streak;
+    history.streak_period = streak_period;
+
+    Ok(())
+}
+
+/// Count a redeemed coupon and its discount in the user's `UserStats` of the campaign.
+pub fn record_user_stats(
+    user_stats: &mut UserStats,
+    campaign_key: Pubkey,
+    user: Pubkey,
+    discount_value: u64,
+) -> Result<()> {
+    user_stats.campaign = campaign_key;
+    user_stats.user = user;
+    user_stats.redeemed_coupons = user_stats
+        .redeemed_coupons
+        .checked_add(1)
+        .ok_or(PromoError::Overflow)?;
+    user_stats.total_discount_lamports = user_stats
+        .total_discount_lamports
+        .checked_add(discount_value)
+        .ok_or(PromoError::Overflow)?;
+
+    Ok(())
+}
+
+/// Create a rent-exempt account owned by this program at a PDA.
+///
+/// Anyone can send lamports to the PDA before it is created, so a pre-funded
+/// address is topped up to the rent-exempt minimum, then allocated and
+/// assigned instead (`create_account` refuses accounts holding lamports).
+pub fn create_program_account<'info>(
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    space: usize,
+    signer_seeds: &[&[u8]],
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let lamports = Rent::get()?.minimum_balance(space);
+    let signer = &[signer_seeds];
+
+    let current_lamports = account.lamports();
+    if current_lamports == 0 {
+        let cpi_accounts = system_program::CreateAccount {
+            from: payer.to_account_info(),
+            to: account.clone(),
+        };
+        let cpi_ctx =
+            CpiContext::new_with_signer(system_program.to_account_info(), cpi_accounts, signer);
+        return system_program::create_account(cpi_ctx, lamports, space as u64, &crate::ID);
+    }
+
+    if current_lamports < lamports {
+        let cpi_accounts = system_program::Transfer {
+            from: payer.to_account_info(),
+            to: account.clone(),
+        };
+        let cpi_ctx = CpiContext::new(system_program.to_account_info(), cpi_accounts);
+        system_program::transfer(cpi_ctx, lamports - current_lamports)?;
+    }
+
+    let cpi_accounts = system_program::Allocate {
+        account_to_allocate: account.clone(),
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(system_program.to_account_info(), cpi_accounts, signer);
+    system_program::allocate(cpi_ctx, space as u64)?;
+
+    let cpi_accounts = system_program::Assign {
+        account_to_assign: account.clone(),
+    };
+    let cpi_ctx =
+        CpiContext::new_with_signer(system_program.to_account_info(), cpi_accounts, signer);
+    system_program::assign(cpi_ctx, &crate::ID)
+}